@@ -4,6 +4,11 @@ use crate::ff::ffmpegbin::FFmpegBinList;
  *  Licensed under the Apache 2.0 License. See LICENSE.txt in the project root for license information.
  *--------------------------------------------------------------------------------------------*/
 use crate::ff::{ffcommand, ffdb};
+use crate::model::ff::{
+    marker::{FFMarker, OutFFInsertMarker, MAX_TIMEOUT_MS},
+    probe::{FFProbeRequest, InFFProbe, OutFFProbe, ProbeScope},
+};
+use crate::model::state;
 use crate::{db::dbredis, model::server::Server};
 
 use crate::{ff, new_io_error};
@@ -22,10 +27,12 @@ impl Core {
         redis: dbredis::DBRedis,
         ffmpegs: Option<FFmpegBinList>,
         server_info: Server,
+        probe_scope: ProbeScope,
     ) -> Self {
         Self {
-            ff_core: ffmpegs
-                .map(|ffmpegs| ff::ffcore::FFCore::new(ffoutput, ffmpegs, server_info.clone())),
+            ff_core: ffmpegs.map(|ffmpegs| {
+                ff::ffcore::FFCore::new(ffoutput, ffmpegs, server_info.clone(), probe_scope)
+            }),
             redis: redis.clone(),
             ffdb: ffdb::FFDb::new(redis.clone()),
             server_info,
@@ -41,7 +48,7 @@ impl Core {
     }
 
     pub async fn get_ff_cmd(&self, server_uid: &str, uid: &str) -> std::io::Result<String> {
-        let config = self.ffdb.get_ff_config(server_uid, uid).await?;
+        let config = self.ffdb.get_ff_config(server_uid, uid).await?.redacted();
         let gpu = self.server_info.get_gpu(&config.gpu_uid);
         if gpu.is_none() {
             return Err(new_io_error!(std::io::ErrorKind::NotFound, "GPU not found"));
@@ -49,9 +56,96 @@ impl Core {
         let cmd = ffcommand::FFCommand::new(
             gpu.as_ref().unwrap(),
             &config,
+            &config.input,
+            &self.server_info.name,
             std::path::Path::new("/"),
             false,
+            false,
         );
         Ok(cmd.to_string())
     }
+
+    /// Probe an input from the server of the config, which must have the transcoder
+    /// module enabled
+    pub async fn probe(&self, probe: &InFFProbe) -> std::io::Result<OutFFProbe> {
+        probe.validate()?;
+        let request = FFProbeRequest {
+            uid: uuid::Uuid::new_v4().to_string(),
+            probe: probe.clone(),
+        };
+        self.ffdb.put_ff_probe(&probe.server_uid, &request).await?;
+
+        // the crop detection takes up to another timeout, and the server a moment to answer
+        let nb_timeouts = match probe.detect_crop {
+            true => 2,
+            false => 1,
+        };
+        let timeout = std::time::Duration::from_millis(probe.timeout_ms() * nb_timeouts + 1000);
+        let deadline = std::time::Instant::now() + timeout;
+        while std::time::Instant::now() < deadline {
+            tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+            let Ok(ack) = self.ffdb.get_ff_probe_ack(&request.uid).await else {
+                continue;
+            };
+            return ack
+                .result
+                .map_err(|reason| new_io_error!(std::io::ErrorKind::Other, format!("{}", reason)));
+        }
+        Err(new_io_error!(
+            std::io::ErrorKind::TimedOut,
+            "The server has not answered before the timeout"
+        ))
+    }
+
+    /// Send a marker to the running encoder of a config and wait until it is inserted.
+    /// The insertion is acknowledged by the "Marker <uid> inserted at <time> ms" line of the
+    /// standard error, only printed by the experimental patches of ffmpeg/README.md and
+    /// checked by ffmpeg/7.0/experimental/check.sh
+    pub async fn insert_marker(
+        &self,
+        server_uid: &str,
+        marker: &FFMarker,
+        timeout_ms: u64,
+    ) -> std::io::Result<OutFFInsertMarker> {
+        let config = self
+            .ffdb
+            .get_ff_config(server_uid, &marker.config_uid)
+            .await?;
+        if config.state != state::State::Active {
+            return Err(new_io_error!(
+                std::io::ErrorKind::InvalidInput,
+                "The config is not active"
+            ));
+        }
+        if config.video_adaptation_set.is_empty() {
+            return Err(new_io_error!(
+                std::io::ErrorKind::InvalidInput,
+                "The markers are inserted at the keyframes of the video representations"
+            ));
+        }
+
+        self.ffdb.put_ff_marker(server_uid, marker).await?;
+        let timeout = std::time::Duration::from_millis(timeout_ms.min(MAX_TIMEOUT_MS));
+        let deadline = std::time::Instant::now() + timeout;
+        while std::time::Instant::now() < deadline {
+            tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+            let Ok(ack) = self.ffdb.get_ff_marker_ack(&marker.uid).await else {
+                continue;
+            };
+            return match ack.result {
+                Ok(media_time_ms) => Ok(OutFFInsertMarker {
+                    marker_uid: ack.marker_uid,
+                    media_time_ms,
+                }),
+                Err(reason) => Err(new_io_error!(
+                    std::io::ErrorKind::Other,
+                    format!("The marker has not been inserted, {}", reason)
+                )),
+            };
+        }
+        Err(new_io_error!(
+            std::io::ErrorKind::TimedOut,
+            "The marker has not been inserted before the timeout"
+        ))
+    }
 }
@@ -22,7 +22,7 @@ impl Connection {
         Ok(Connection { con })
     }
 
-    pub fn subscribe(&mut self, channels: &Vec<String>) -> std::io::Result<Subscriber> {
+    pub fn subscribe(&mut self, channels: &Vec<String>) -> std::io::Result<Subscriber<'_>> {
         let mut pubsub = self.con.as_pubsub();
         for channel in channels {
             tracing::info!("Subscribe to {}", channel);
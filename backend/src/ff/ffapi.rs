@@ -18,12 +18,20 @@ pub async fn update(
     data: web::Json<model::ff::InFFUpdate>,
     core: web::Data<core::Core>,
 ) -> actix_web::Result<impl Responder> {
-    core.ffdb
-        .put_ff_config(data.config.clone())
+    let mut config = data.config.clone();
+    if let Ok(stored) = core
+        .ffdb
+        .get_ff_config(&config.server_uid, &config.uid)
         .await
-        .map_err(|e| {
-            actix_web::error::ErrorInternalServerError(format!("Fail to update config: {}", e))
-        })?;
+    {
+        config.restore_secrets(&stored);
+    }
+    config
+        .validate()
+        .map_err(|e| actix_web::error::ErrorBadRequest(format!("Invalid config: {}", e)))?;
+    core.ffdb.put_ff_config(config).await.map_err(|e| {
+        actix_web::error::ErrorInternalServerError(format!("Fail to update config: {}", e))
+    })?;
     Ok(web::Json(true))
 }
 
@@ -76,6 +84,7 @@ pub async fn get_all_configs(core: web::Data<core::Core>) -> actix_web::Result<i
     let configs = core.ffdb.get_all_ff_configs().await.map_err(|e| {
         actix_web::error::ErrorInternalServerError(format!("Fail to get all configs: {}", e))
     })?;
+    let configs = configs.iter().map(|config| config.redacted()).collect();
     Ok(web::Json(model::ff::OutGetAllFFConfig { configs }))
 }
 
@@ -156,6 +165,47 @@ pub async fn create(
     Ok(web::Json(uid))
 }
 
+#[utoipa::path(
+    tags = ["FF"],
+    responses(
+        (status = 200, description = "OK", body = model::ff::probe::OutFFProbe),
+    )
+)]
+#[post("/probe")]
+pub async fn probe(
+    data: web::Json<model::ff::probe::InFFProbe>,
+    core: web::Data<core::Core>,
+) -> actix_web::Result<impl Responder> {
+    data.validate()
+        .map_err(|e| actix_web::error::ErrorBadRequest(format!("Invalid probe: {}", e)))?;
+    let ret = core.probe(&data).await.map_err(|e| {
+        actix_web::error::ErrorInternalServerError(format!("Fail to probe input: {}", e))
+    })?;
+    Ok(web::Json(ret))
+}
+
+#[utoipa::path(
+    tags = ["FF"],
+    responses(
+        (status = 200, description = "OK", body = model::ff::marker::OutFFInsertMarker),
+    )
+)]
+#[post("/insert_marker")]
+pub async fn insert_marker(
+    data: web::Json<model::ff::marker::InFFInsertMarker>,
+    core: web::Data<core::Core>,
+) -> actix_web::Result<impl Responder> {
+    let marker = model::ff::marker::FFMarker::new(&data)
+        .map_err(|e| actix_web::error::ErrorBadRequest(format!("Invalid marker: {}", e)))?;
+    let ret = core
+        .insert_marker(&data.id.server_uid, &marker, data.timeout_ms)
+        .await
+        .map_err(|e| {
+            actix_web::error::ErrorInternalServerError(format!("Fail to insert marker: {}", e))
+        })?;
+    Ok(web::Json(ret))
+}
+
 pub fn service() -> impl HttpServiceFactory + OpenApiFactory + 'static {
     utoipa_actix_web::scope("/ff")
         .service(create_default_video_representation)
@@ -167,4 +217,6 @@ pub fn service() -> impl HttpServiceFactory + OpenApiFactory + 'static {
         .service(remove)
         .service(get_all_configs)
         .service(get_all_status)
+        .service(probe)
+        .service(insert_marker)
 }
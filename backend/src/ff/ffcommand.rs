@@ -1,7 +1,11 @@
 use crate::model::{
     ff::{
+        audioadaptationset::AudioAdaptationSet,
         ffconfig::{self, Acceleration},
-        videoadaptationset::{Colorspace, VideoAdaptationSet},
+        input::Input,
+        subtitle::SubtitleTrack,
+        videoadaptationset::VideoAdaptationSet,
+        videofilter::{DrawTextContext, VideoFilter},
     },
     server::Gpu,
 };
@@ -28,11 +32,15 @@ impl std::fmt::Display for FFCommand {
 }
 
 impl FFCommand {
+    #[allow(clippy::vec_init_then_push)]
     pub fn new(
         gpu: &Gpu,
         config: &ffconfig::FFConfig,
+        input: &Input,
+        server_name: &str,
         base_output: &std::path::Path,
         ast_delay_us_supported: bool,
+        markers_supported: bool,
     ) -> Self {
         let output = base_output.join(config.output.strip_prefix("/").unwrap_or(&config.output));
         let output_dash = output.join("manifest.mpd");
@@ -78,23 +86,8 @@ impl FFCommand {
         args.push("-err_detect".to_string());
         args.push("ignore_err".to_string());
 
-        let input = config.input.to_string();
-        let input_url = url::Url::parse(&input);
-        if let Ok(input_url) = input_url {
-            if input_url.scheme() == "file"
-                || input_url.scheme() == "http"
-                || input_url.scheme() == "https"
-            {
-                args.push("-re".to_string());
-            }
-        } else {
-            args.push("-re".to_string());
-            args.push("-stream_loop".to_string());
-            args.push("-1".to_string())
-        }
-
-        args.push("-i".to_string());
-        args.push(input);
+        SubtitleTrack::ff_decoder_options(&config.stream_selection, &config.subtitles, &mut args);
+        input.ff_input(&mut args);
 
         args.push("-metadata".to_string());
         args.push(metadata);
@@ -102,69 +95,120 @@ impl FFCommand {
         args.push("-flags".to_string());
         args.push("+global_header ".to_string());
 
-        args.push("-c:a".to_string());
-        args.push(config.audio_adaptation_set.encoder.to_string());
-        args.push("-ar".to_string());
-        args.push(config.audio_adaptation_set.sample_rate.to_string());
-        args.push("-b:a".to_string());
-        args.push(config.audio_adaptation_set.bitrate.to_string());
-        args.push("-metadata:s:a:0".to_string());
-        args.push(format!("role={}", config.audio_adaptation_set.role));
-
-        args.push("-profile:a".to_string());
-        args.push(config.audio_adaptation_set.profile.to_string());
+        AudioAdaptationSet::ff_audio_encode(&config.audio_adaptation_set, &mut args);
 
         if !config.video_adaptation_set.is_empty() {
-            if config.colorspace != Colorspace::Auto {
-                args.push("-color_primaries".to_string());
-                args.push(config.colorspace.to_string());
-                args.push("-color_trc".to_string());
-                args.push(config.colorspace.to_string());
-                args.push("-colorspace".to_string());
-                args.push(config.colorspace.to_string());
+            args.push("-filter_complex".to_string());
+            let mut filter_complex = format!("[{}]", config.stream_selection.ff_video());
+
+            let filters = VideoFilter::ff_filters(
+                &config.video_filters(),
+                gpu.acceleration,
+                config.colorspace.is_hdr(),
+            );
+            if !filters.is_empty() {
+                filter_complex.push_str(&filters);
+                filter_complex.push(',');
             }
 
-            args.push("-filter_complex".to_string());
-            let mut filter_complex = String::new();
-
-            if config.deinterlace.enable {
-                match gpu.acceleration {
-                    Acceleration::Vaapi => {
-                        filter_complex.push_str("deinterlace_vaapi=mode=default:rate=field,");
-                    }
-                    Acceleration::Cuda => {
-                        filter_complex.push_str("bwdif_cuda=mode=send_field:parity=auto,");
-                    }
-                    _ => {
-                        filter_complex.push_str("bwdif=mode=1:parity=-1:deint=0,");
-                    }
-                }
+            if let Some(burn_in) = SubtitleTrack::ff_burn_in(
+                &config.stream_selection,
+                &config.subtitles,
+                gpu.acceleration,
+                config.colorspace.is_hdr(),
+            ) {
+                filter_complex.push_str(&burn_in);
+                filter_complex.push(',');
+            }
+
+            if let Some(thumbnails) = config.thumbnails.as_ref() {
+                filter_complex.push_str(&format!(
+                    "split=2[vsrc][tsrc];[tsrc]{}[thumbnails];[vsrc]",
+                    thumbnails.ff_filter(gpu.acceleration)
+                ));
             }
 
             filter_complex.push_str(&VideoAdaptationSet::ff_split_filter(
                 gpu.acceleration,
                 &config.video_adaptation_set,
+                config.colorspace,
+                &DrawTextContext {
+                    config_name: &config.name,
+                    server_name,
+                },
             ));
             args.push(filter_complex);
         }
 
         args.push("-noautoscale".to_string());
 
-        VideoAdaptationSet::ff_video_encode(gpu, config, &config.video_adaptation_set, &mut args);
+        VideoAdaptationSet::ff_video_encode(
+            gpu,
+            config,
+            &config.video_adaptation_set,
+            markers_supported,
+            &mut args,
+        );
         VideoAdaptationSet::ff_map(&config.video_adaptation_set, &mut args);
 
-        args.push("-map".to_string());
-        args.push("0:a:0".to_string());
+        AudioAdaptationSet::ff_map(
+            &config.stream_selection,
+            &config.audio_adaptation_set,
+            &mut args,
+        );
 
-        args.push("-adaptation_sets".to_string());
+        SubtitleTrack::ff_subtitle_encode(&config.subtitles, &mut args);
+        SubtitleTrack::ff_map(&config.stream_selection, &config.subtitles, &mut args);
 
-        let vas =
-            VideoAdaptationSet::ff_adaptation_sets(segment_duration, &config.video_adaptation_set);
+        if let Some(scte35) = config.scte35.as_ref() {
+            scte35.ff_map(&config.stream_selection, &mut args);
+        }
+
+        args.push("-adaptation_sets".to_string());
 
-        args.push(format!(
-            "{vas}id={},seg_duration={segment_duration},streams=a",
-            config.video_adaptation_set.len()
-        ));
+        let cc_descriptor = config
+            .closed_captions
+            .as_ref()
+            .and_then(|closed_captions| closed_captions.ff_descriptor());
+        let vas = VideoAdaptationSet::ff_adaptation_sets(
+            segment_duration,
+            cc_descriptor.as_deref(),
+            &config.video_adaptation_set,
+        );
+        let nb_video_sets = config
+            .video_adaptation_set
+            .iter()
+            .filter(|x| !x.representations.is_empty())
+            .count();
+        let nb_video_streams: usize = config
+            .video_adaptation_set
+            .iter()
+            .map(|x| x.representations.len())
+            .sum();
+        let aas = AudioAdaptationSet::ff_adaptation_sets(
+            segment_duration,
+            nb_video_sets,
+            nb_video_streams,
+            &config.audio_adaptation_set,
+        );
+        let sas = SubtitleTrack::ff_adaptation_sets(
+            segment_duration,
+            nb_video_sets
+                + config
+                    .audio_adaptation_set
+                    .iter()
+                    .filter(|x| !x.representations.is_empty())
+                    .count(),
+            nb_video_streams
+                + config
+                    .audio_adaptation_set
+                    .iter()
+                    .map(|x| x.representations.len())
+                    .sum::<usize>(),
+            &config.subtitles,
+        );
+
+        args.push(format!("{vas}{aas}{sas}").trim_end().to_string());
 
         args.push("-window_size".to_string());
         args.push(config.window_size.to_string());
@@ -187,6 +231,12 @@ impl FFCommand {
         if config.enable_hls {
             args.push("-hls_playlist".to_string());
             args.push("1".to_string());
+            if let Some(closed_captions) = config.closed_captions.as_ref() {
+                closed_captions.ff_hls_closed_captions(&mut args);
+            }
+            if let Some(scte35) = config.scte35.as_ref() {
+                scte35.ff_hls_tags(&mut args);
+            }
         }
 
         args.push("-update_period".to_string());
@@ -227,6 +277,10 @@ impl FFCommand {
         }
         args.push(format_options);
 
+        if let Some(thumbnails) = config.thumbnails.as_ref() {
+            thumbnails.ff_dash_options(&mut args);
+        }
+
         args.push("-mpd_profile".to_string());
         args.push("dvb_dash".to_string());
 
@@ -234,6 +288,10 @@ impl FFCommand {
         args.push("dash".to_string());
         args.push(output_dash.to_str().unwrap().to_string());
 
+        if let Some(thumbnails) = config.thumbnails.as_ref() {
+            thumbnails.ff_output("thumbnails", &output, &mut args);
+        }
+
         Self { output, args }
     }
 
@@ -3,9 +3,16 @@ use std::{
     sync::{Arc, Mutex},
 };
 
-use crate::model::server::Server;
+use crate::model::{
+    ff::{
+        marker::FFMarker,
+        probe::{InFFProbe, OutFFProbe, ProbeScope},
+    },
+    server::Server,
+};
+use crate::new_io_error;
 
-use super::{ffdb, ffmpegbin::FFmpegBinList, ffprocess};
+use super::{ffdb, ffmpegbin::FFmpegBinList, ffprobe, ffprocess};
 
 #[derive(Clone)]
 pub struct FFCore {
@@ -13,15 +20,22 @@ pub struct FFCore {
     pub output: std::path::PathBuf,
     pub ffmpegs: FFmpegBinList,
     pub server_info: Server,
+    pub probe_scope: ProbeScope,
 }
 
 impl FFCore {
-    pub fn new(output: &std::path::Path, ffmpegs: FFmpegBinList, server_info: Server) -> Self {
+    pub fn new(
+        output: &std::path::Path,
+        ffmpegs: FFmpegBinList,
+        server_info: Server,
+        probe_scope: ProbeScope,
+    ) -> Self {
         Self {
             cores: Arc::new(Mutex::new(HashMap::new())),
             output: output.to_path_buf(),
             ffmpegs,
             server_info,
+            probe_scope,
         }
     }
 
@@ -77,4 +91,51 @@ impl FFCore {
     pub async fn remove_ff_core(&self, config_uid: &str) {
         self.cores.lock().unwrap().remove(config_uid);
     }
+
+    pub async fn insert_marker(&self, marker: FFMarker) -> std::io::Result<()> {
+        let core = self
+            .cores
+            .lock()
+            .unwrap()
+            .get(&marker.config_uid)
+            .map(|core| core.get());
+
+        match core {
+            Some(core) => {
+                core.insert_marker(marker).await;
+                Ok(())
+            }
+            None => Err(new_io_error!(
+                std::io::ErrorKind::NotFound,
+                "the config has no Live Encoder on its server"
+            )),
+        }
+    }
+
+    /// Probe an input from this server
+    pub async fn probe(&self, probe: &InFFProbe) -> std::io::Result<OutFFProbe> {
+        probe.validate()?;
+        probe.input.validate_probe(&self.probe_scope)?;
+        let timeout_ms = probe.timeout_ms();
+
+        let ffprobe = self
+            .ffmpegs
+            .ffprobe()
+            .ok_or_else(|| new_io_error!(std::io::ErrorKind::NotFound, "ffprobe not found"))?;
+        let mut out = ffprobe::FFProbe::probe(ffprobe, &probe.input, timeout_ms).await?;
+
+        let video = out
+            .streams
+            .iter()
+            .find(|stream| stream.codec_type == "video")
+            .and_then(|stream| stream.width.zip(stream.height));
+        if let (true, Some(ffmpeg), Some((width, height))) =
+            (probe.detect_crop, self.ffmpegs.ffmpeg(), video)
+        {
+            out.crop =
+                ffprobe::FFProbe::detect_crop(ffmpeg, &probe.input, width, height, timeout_ms)
+                    .await?;
+        }
+        Ok(out)
+    }
 }
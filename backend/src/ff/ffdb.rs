@@ -2,7 +2,11 @@ use redis::{aio::ConnectionManager, AsyncCommands};
 
 use crate::{
     db::dbredis::DBRedis,
-    model::{self, ff::ffconfig, state},
+    model::{
+        self,
+        ff::{ffconfig, marker, probe},
+        state,
+    },
     new_io_error, utils,
 };
 
@@ -143,4 +147,91 @@ impl FFDb {
     ) -> std::io::Result<ffconfig::FFConfig> {
         Self::get_ff_config_internal(server_uid, uid, &mut self.redis.con).await
     }
+
+    fn marker_id(server_uid: &str, config_uid: &str, uid: &str) -> String {
+        format!("ff:marker:{}:{}:{}", server_uid, config_uid, uid)
+    }
+
+    /// The marker is picked up by the server of the config, see FFEvent
+    pub async fn put_ff_marker(
+        &self,
+        server_uid: &str,
+        marker: &marker::FFMarker,
+    ) -> std::io::Result<()> {
+        let marker_id = Self::marker_id(server_uid, &marker.config_uid, &marker.uid);
+        let value = serde_json::to_string(marker)?;
+        let expired_seconds = (marker::MAX_TIMEOUT_MS / 1000) as i64;
+        DBRedis::set_expires(
+            &marker_id,
+            &value,
+            &mut self.redis.con.clone(),
+            expired_seconds,
+        )
+        .await
+    }
+
+    pub async fn get_ff_marker(
+        &self,
+        server_uid: &str,
+        config_uid: &str,
+        uid: &str,
+    ) -> std::io::Result<marker::FFMarker> {
+        let mut con = self.redis.con.clone();
+        DBRedis::get(&Self::marker_id(server_uid, config_uid, uid), &mut con).await
+    }
+
+    pub async fn set_ff_marker_ack(&self, ack: &marker::FFMarkerAck) -> std::io::Result<()> {
+        let key = format!("ff:marker_ack:{}", ack.marker_uid);
+        let value = serde_json::to_string(ack)?;
+        DBRedis::set_expires(&key, &value, &mut self.redis.con.clone(), 60).await
+    }
+
+    pub async fn get_ff_marker_ack(
+        &self,
+        marker_uid: &str,
+    ) -> std::io::Result<marker::FFMarkerAck> {
+        let mut con = self.redis.con.clone();
+        DBRedis::get(&format!("ff:marker_ack:{}", marker_uid), &mut con).await
+    }
+
+    fn probe_id(server_uid: &str, uid: &str) -> String {
+        format!("ff:probe:{}:{}", server_uid, uid)
+    }
+
+    /// The probe is picked up by the server, see FFEvent
+    pub async fn put_ff_probe(
+        &self,
+        server_uid: &str,
+        request: &probe::FFProbeRequest,
+    ) -> std::io::Result<()> {
+        let value = serde_json::to_string(request)?;
+        let expired_seconds = (probe::MAX_TIMEOUT_MS / 1000) as i64;
+        DBRedis::set_expires(
+            &Self::probe_id(server_uid, &request.uid),
+            &value,
+            &mut self.redis.con.clone(),
+            expired_seconds,
+        )
+        .await
+    }
+
+    pub async fn get_ff_probe(
+        &self,
+        server_uid: &str,
+        uid: &str,
+    ) -> std::io::Result<probe::FFProbeRequest> {
+        let mut con = self.redis.con.clone();
+        DBRedis::get(&Self::probe_id(server_uid, uid), &mut con).await
+    }
+
+    pub async fn set_ff_probe_ack(&self, ack: &probe::FFProbeAck) -> std::io::Result<()> {
+        let key = format!("ff:probe_ack:{}", ack.uid);
+        let value = serde_json::to_string(ack)?;
+        DBRedis::set_expires(&key, &value, &mut self.redis.con.clone(), 60).await
+    }
+
+    pub async fn get_ff_probe_ack(&self, uid: &str) -> std::io::Result<probe::FFProbeAck> {
+        let mut con = self.redis.con.clone();
+        DBRedis::get(&format!("ff:probe_ack:{}", uid), &mut con).await
+    }
 }
@@ -1,4 +1,5 @@
 use crate::db::subscribermanager::{self, EventHandler, SubscriberManager};
+use crate::model::ff::{marker::FFMarkerAck, probe::FFProbeAck};
 use async_trait::async_trait;
 
 use super::{ffcore::FFCore, ffdb::FFDb};
@@ -14,10 +15,11 @@ struct FFEventHandler {
 
 impl FFEvent {
     pub fn new(ffcore: FFCore, ffdb: &FFDb) -> std::io::Result<Self> {
-        let channels = vec![format!(
-            "__keyspace@0__:ff:config:{}:*",
-            &ffcore.server_info.uid
-        )];
+        let channels = vec![
+            format!("__keyspace@0__:ff:config:{}:*", &ffcore.server_info.uid),
+            format!("__keyspace@0__:ff:marker:{}:*", &ffcore.server_info.uid),
+            format!("__keyspace@0__:ff:probe:{}:*", &ffcore.server_info.uid),
+        ];
 
         let redis_con = ffdb.con_url().to_owned();
         let handler = Box::new(FFEventHandler::new(ffcore, ffdb.clone()));
@@ -36,7 +38,7 @@ impl FFEventHandler {
     }
 
     async fn handle_config(&mut self, evt: &subscribermanager::Event) {
-        let config_uid = evt.channel.split(':').last();
+        let config_uid = evt.channel.split(':').next_back();
         if config_uid.is_none() {
             tracing::error!("Invalid event channel: {}", evt.channel);
             return;
@@ -55,11 +57,84 @@ impl FFEventHandler {
             tracing::info!("Invalid event payload: {}", evt.payload);
         }
     }
+
+    /// ex: __keyspace@0__:ff:marker:{server_uid}:{config_uid}:{marker_uid}
+    async fn handle_marker(&mut self, evt: &subscribermanager::Event) {
+        // the markers expire once delivered
+        if evt.payload != "set" {
+            return;
+        }
+
+        let mut ids = evt.channel.rsplit(':');
+        let (Some(marker_uid), Some(config_uid), Some(server_uid)) =
+            (ids.next(), ids.next(), ids.next())
+        else {
+            tracing::error!("Invalid event channel: {}", evt.channel);
+            return;
+        };
+
+        match self
+            .ffdb
+            .get_ff_marker(server_uid, config_uid, marker_uid)
+            .await
+        {
+            Ok(marker) => {
+                if let Err(e) = self.ffcore.insert_marker(marker).await {
+                    let ack = FFMarkerAck::rejected(marker_uid, &e.to_string());
+                    self.ffdb.set_ff_marker_ack(&ack).await.ok();
+                }
+            }
+            Err(e) => tracing::error!("Fail to get marker {}: {}", marker_uid, e),
+        }
+    }
+
+    /// ex: __keyspace@0__:ff:probe:{server_uid}:{probe_uid}
+    async fn handle_probe(&mut self, evt: &subscribermanager::Event) {
+        if evt.payload != "set" {
+            return;
+        }
+
+        let mut ids = evt.channel.rsplit(':');
+        let (Some(probe_uid), Some(server_uid)) = (ids.next(), ids.next()) else {
+            tracing::error!("Invalid event channel: {}", evt.channel);
+            return;
+        };
+
+        let request = match self.ffdb.get_ff_probe(server_uid, probe_uid).await {
+            Ok(request) => request,
+            Err(e) => {
+                tracing::error!("Fail to get probe {}: {}", probe_uid, e);
+                return;
+            }
+        };
+
+        // the probe does not hold the events of the configs
+        let ffcore = self.ffcore.clone();
+        let ffdb = self.ffdb.clone();
+        tokio::spawn(async move {
+            let ack = FFProbeAck {
+                uid: request.uid,
+                result: ffcore
+                    .probe(&request.probe)
+                    .await
+                    .map_err(|e| e.to_string()),
+            };
+            if let Err(e) = ffdb.set_ff_probe_ack(&ack).await {
+                tracing::error!("Fail to answer probe {}: {}", ack.uid, e);
+            }
+        });
+    }
 }
 
 #[async_trait]
 impl EventHandler for FFEventHandler {
     async fn on_event(&mut self, evt: &subscribermanager::Event) {
-        self.handle_config(evt).await;
+        if evt.channel.starts_with("__keyspace@0__:ff:marker:") {
+            self.handle_marker(evt).await;
+        } else if evt.channel.starts_with("__keyspace@0__:ff:probe:") {
+            self.handle_probe(evt).await;
+        } else {
+            self.handle_config(evt).await;
+        }
     }
 }
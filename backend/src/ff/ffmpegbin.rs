@@ -3,24 +3,64 @@ use tokio::process::Command;
 use crate::model::ff::{
     audioadaptationset::AudioEncoder,
     ffconfig::{self, Acceleration},
+    subtitle::{SubtitleFormat, SubtitleSource},
     videoadaptationset::VideoEncoder,
 };
 
 #[derive(Clone)]
 pub struct FFmpegBin {
     pub ffmpeg: String,
+    pub ffprobe: String,
     pub supported_audio_codecs: Vec<AudioEncoder>,
     pub supported_video_codecs: Vec<(Acceleration, VideoEncoder)>,
+    /// Accelerations having a tone-mapping filter
+    pub supported_tonemap: Vec<Acceleration>,
     pub ast_delay_us_supported: bool,
+    /// The dash muxer signals the thumbnails
+    pub thumbnails_supported: bool,
+    /// The dash muxer signals the closed captions in the HLS playlist
+    pub hls_closed_captions_supported: bool,
+    /// The dash muxer writes the subtitle adaptation sets and HLS renditions
+    pub subtitles_supported: bool,
+    /// Teletext decoder, libzvbi
+    pub teletext_supported: bool,
+    /// The dash muxer writes the SCTE-35 cues and ffmpeg forces keyframes at the splice points,
+    /// experimental patches, only with `enable_scte35`
+    pub scte35_supported: bool,
+    /// ffmpeg reads markers from the standard input and the dash muxer writes them,
+    /// experimental patches, only with `enable_scte35`
+    pub markers_supported: bool,
 }
 
 impl FFmpegBin {
-    pub async fn new(ffmpeg: String) -> std::io::Result<Self> {
+    /// `enable_scte35` opts in the SCTE-35 and markers features of the experimental patches
+    pub async fn new(ffmpeg: String, enable_scte35: bool) -> std::io::Result<Self> {
         let codecs = Self::get_encoder_codecs(&ffmpeg).await?;
         let dash_muxer_opts = Self::get_dash_muxer_options(&ffmpeg).await?;
+        let filters = Self::get_filters(&ffmpeg).await?;
+        let decoders = Self::get_decoder_codecs(&ffmpeg).await?;
         let mut supported_audio_codecs = Vec::new();
         let mut supported_video_codecs = Vec::new();
+        let mut supported_tonemap = Vec::new();
+        // options of the dash muxer patched by ffmpeg/7.0, see ffmpeg/README.md:
+        // -ast_delay_us, offset of the availabilityStartTime of the MPD
         let ast_delay_us_supported = dash_muxer_opts.contains("-ast_delay_us");
+        // -thumbnail_duration, -thumbnail_tiles and -thumbnail_size, the JPEG tiles of the
+        // image2 output as a thumbnail adaptation set and an HLS image playlist
+        let thumbnails_supported = dash_muxer_opts.contains("-thumbnail_duration");
+        // -hls_closed_captions, CLOSED-CAPTIONS renditions of the HLS playlist,
+        // INSTREAM-ID:LANGUAGE separated by spaces
+        let hls_closed_captions_supported = dash_muxer_opts.contains("-hls_closed_captions");
+        // -hls_subtitles_group, GROUP-ID of the SUBTITLES renditions of the text streams
+        let subtitles_supported = dash_muxer_opts.contains("-hls_subtitles_group");
+        let teletext_supported = Self::has_codec("libzvbi_teletextdec", &decoders);
+        // experimental patches:
+        // -scte35_hls_tags, daterange or cue_out tags of the splice points of the data stream,
+        // the keyframes at the splice points are forced with -force_key_frames scte35
+        let scte35_supported = enable_scte35 && dash_muxer_opts.contains("-scte35_hls_tags");
+        // -write_markers, events of the markers read by the key command
+        // "M<id> <scheme> <duration ms> <hex data>", forced with -force_key_frames markers
+        let markers_supported = enable_scte35 && dash_muxer_opts.contains("-write_markers");
 
         if Self::has_codec("AAC (Advanced Audio Coding)", &codecs) {
             supported_audio_codecs.push(AudioEncoder::Aac);
@@ -30,6 +70,16 @@ impl FFmpegBin {
             supported_audio_codecs.push(AudioEncoder::FDKAac);
         }
 
+        if Self::has_codec("ATSC A/52A (AC-3)", &codecs) {
+            supported_audio_codecs.push(AudioEncoder::Ac3);
+        }
+
+        if Self::has_codec("ATSC A/52 E-AC-3", &codecs) {
+            supported_audio_codecs.push(AudioEncoder::Eac3);
+        }
+
+        supported_audio_codecs.push(AudioEncoder::Copy);
+
         if Self::has_codec("libx264", &codecs) {
             supported_video_codecs.push((Acceleration::Software, VideoEncoder::H264));
         }
@@ -54,20 +104,64 @@ impl FFmpegBin {
             supported_video_codecs.push((Acceleration::Cuda, VideoEncoder::HEVC));
         }
 
+        if Self::has_codec("libsvtav1", &codecs) {
+            supported_video_codecs.push((Acceleration::Software, VideoEncoder::AV1));
+        }
+
+        if Self::has_codec("av1_vaapi", &codecs) {
+            supported_video_codecs.push((Acceleration::Vaapi, VideoEncoder::AV1));
+        }
+
+        if Self::has_codec("av1_nvenc", &codecs) {
+            supported_video_codecs.push((Acceleration::Cuda, VideoEncoder::AV1));
+        }
+
+        if Self::has_filter("zscale", &filters) && Self::has_filter("tonemap", &filters) {
+            supported_tonemap.push(Acceleration::Software);
+            // the CUDA frames are tone-mapped in software
+            if Self::has_filter("hwupload_cuda", &filters) {
+                supported_tonemap.push(Acceleration::Cuda);
+            }
+        }
+
+        if Self::has_filter("tonemap_vaapi", &filters) {
+            supported_tonemap.push(Acceleration::Vaapi);
+        }
+
+        let ffprobe = Self::ffprobe_path(&ffmpeg);
+
         Ok(Self {
             ffmpeg,
+            ffprobe,
             supported_audio_codecs,
             supported_video_codecs,
+            supported_tonemap,
             ast_delay_us_supported,
+            thumbnails_supported,
+            hls_closed_captions_supported,
+            subtitles_supported,
+            teletext_supported,
+            scte35_supported,
+            markers_supported,
         })
     }
 
+    /// ffprobe is expected in the same folder, ex: /usr/local/bin/ffmpeg-gpl => /usr/local/bin/ffprobe-gpl
+    fn ffprobe_path(ffmpeg: &str) -> String {
+        let path = std::path::Path::new(ffmpeg);
+        let name = path
+            .file_name()
+            .map(|name| name.to_string_lossy().replacen("ffmpeg", "ffprobe", 1))
+            .filter(|name| name.contains("ffprobe"))
+            .unwrap_or_else(|| "ffprobe".to_string());
+        path.with_file_name(name).to_string_lossy().into_owned()
+    }
+
     pub fn is_config_supported(&self, config: &ffconfig::FFConfig) -> bool {
-        if !self
-            .supported_audio_codecs
-            .contains(&config.audio_adaptation_set.encoder)
-        {
-            return false;
+        for adaptation in &config.audio_adaptation_set {
+            if !self.supported_audio_codecs.contains(&adaptation.encoder) {
+                return false;
+            }
         }
 
         for adaptation in &config.video_adaptation_set {
@@ -77,6 +171,39 @@ impl FFmpegBin {
             {
                 return false;
             }
+
+            if adaptation.tonemap.is_some()
+                && !self.supported_tonemap.contains(&config.acceleration)
+            {
+                return false;
+            }
+        }
+
+        if config.thumbnails.is_some() && !self.thumbnails_supported {
+            return false;
+        }
+
+        if config.enable_hls
+            && config.closed_captions.is_some()
+            && !self.hls_closed_captions_supported
+        {
+            return false;
+        }
+
+        if config.scte35.is_some() && !self.scte35_supported {
+            return false;
+        }
+
+        for subtitle in &config.subtitles {
+            if subtitle.format != SubtitleFormat::BurnIn && !self.subtitles_supported {
+                return false;
+            }
+
+            if let SubtitleSource::Teletext { .. } = subtitle.kind {
+                if !self.teletext_supported {
+                    return false;
+                }
+            }
         }
 
         true
@@ -100,6 +227,20 @@ impl FFmpegBin {
         Ok(String::from_utf8_lossy(&opts.stdout).to_string())
     }
 
+    async fn get_filters(ffmpeg: &str) -> std::io::Result<String> {
+        let filters = Command::new(ffmpeg).arg("-filters").output().await;
+        if filters.is_err() {
+            tracing::error!("Wrong FFMPEG binary {} ?", ffmpeg);
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                "Failed to execute ffmpeg",
+            ));
+        }
+
+        let filters = filters.unwrap();
+        Ok(String::from_utf8_lossy(&filters.stdout).to_string())
+    }
+
     async fn get_encoder_codecs(ffmpeg: &str) -> std::io::Result<String> {
         let codecs = Command::new(ffmpeg).arg("-encoders").output().await;
         if codecs.is_err() {
@@ -115,6 +256,27 @@ impl FFmpegBin {
         Ok(codecs)
     }
 
+    async fn get_decoder_codecs(ffmpeg: &str) -> std::io::Result<String> {
+        let codecs = Command::new(ffmpeg).arg("-decoders").output().await;
+        if codecs.is_err() {
+            tracing::error!("Wrong FFMPEG binary {} ?", ffmpeg);
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                "Failed to execute ffmpeg",
+            ));
+        }
+
+        let codecs = codecs.unwrap();
+        Ok(String::from_utf8_lossy(&codecs.stdout).to_string())
+    }
+
+    /// ex: " ... zscale            V->V       Apply resizing, colorspace and bit depth conversion."
+    fn has_filter(filter: &str, list_of_filters: &str) -> bool {
+        list_of_filters
+            .lines()
+            .any(|line| line.split_whitespace().nth(1) == Some(filter))
+    }
+
     fn has_codec(codec: &str, list_of_codecs: &str) -> bool {
         for line in list_of_codecs.lines() {
             if line.contains(codec) {
@@ -132,11 +294,11 @@ pub struct FFmpegBinList {
 }
 
 impl FFmpegBinList {
-    pub async fn new(ffmpegs: &Vec<String>) -> std::io::Result<Self> {
+    pub async fn new(ffmpegs: &Vec<String>, enable_scte35: bool) -> std::io::Result<Self> {
         let mut ffmpeg_bins = Vec::new();
 
         for ffmpeg in ffmpegs {
-            let ffmpeg_bin = FFmpegBin::new(ffmpeg.clone()).await?;
+            let ffmpeg_bin = FFmpegBin::new(ffmpeg.clone(), enable_scte35).await?;
             ffmpeg_bins.push(ffmpeg_bin);
         }
 
@@ -145,13 +307,18 @@ impl FFmpegBinList {
         })
     }
 
-    pub fn find(&self, config: &ffconfig::FFConfig) -> Option<&FFmpegBin> {
-        for ffmpeg in &self.ffmpegs {
-            if ffmpeg.is_config_supported(config) {
-                return Some(ffmpeg);
-            }
-        }
+    pub fn ffmpeg(&self) -> Option<&str> {
+        self.ffmpegs.first().map(|ffmpeg| ffmpeg.ffmpeg.as_str())
+    }
+
+    /// ffprobe installed next to the first ffmpeg binary
+    pub fn ffprobe(&self) -> Option<&str> {
+        self.ffmpegs.first().map(|ffmpeg| ffmpeg.ffprobe.as_str())
+    }
 
-        None
+    pub fn find(&self, config: &ffconfig::FFConfig) -> Option<&FFmpegBin> {
+        self.ffmpegs
+            .iter()
+            .find(|ffmpeg| ffmpeg.is_config_supported(config))
     }
 }
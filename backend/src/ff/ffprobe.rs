@@ -0,0 +1,282 @@
+use std::collections::HashMap;
+
+use serde::Deserialize;
+use tokio::process::Command;
+
+use crate::model::ff::{
+    input::Input,
+    probe::{OutFFProbe, ProbeProgram, ProbeStream},
+    videofilter::VideoFilterCrop,
+};
+use crate::new_io_error;
+
+/// Subset of the json output of ffprobe
+#[derive(Deserialize)]
+struct FFProbeOutput {
+    #[serde(default)]
+    programs: Vec<FFProbeProgram>,
+    #[serde(default)]
+    streams: Vec<FFProbeStream>,
+    format: Option<FFProbeFormat>,
+}
+
+#[derive(Deserialize)]
+struct FFProbeFormat {
+    format_name: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct FFProbeProgram {
+    program_num: u32,
+    pmt_pid: Option<u32>,
+    pcr_pid: Option<u32>,
+    #[serde(default)]
+    tags: HashMap<String, String>,
+    #[serde(default)]
+    streams: Vec<FFProbeStream>,
+}
+
+#[derive(Deserialize)]
+struct FFProbeStream {
+    index: u32,
+    id: Option<String>,
+    codec_type: Option<String>,
+    codec_name: Option<String>,
+    profile: Option<String>,
+    bit_rate: Option<String>,
+    width: Option<u32>,
+    height: Option<u32>,
+    pix_fmt: Option<String>,
+    r_frame_rate: Option<String>,
+    field_order: Option<String>,
+    color_primaries: Option<String>,
+    color_transfer: Option<String>,
+    sample_rate: Option<String>,
+    channels: Option<u32>,
+    channel_layout: Option<String>,
+    #[serde(default)]
+    tags: HashMap<String, String>,
+}
+
+impl From<FFProbeStream> for ProbeStream {
+    fn from(stream: FFProbeStream) -> Self {
+        let framerate = stream.r_frame_rate.as_ref().and_then(|r| {
+            let (num, den) = r.split_once('/')?;
+            let num: u32 = num.parse().ok()?;
+            let den: u32 = den.parse().ok()?;
+            match num > 0 && den > 0 {
+                true => Some((num, den)),
+                false => None,
+            }
+        });
+        let interlaced = stream
+            .field_order
+            .as_ref()
+            .is_some_and(|f| f != "progressive" && f != "unknown");
+
+        ProbeStream {
+            index: stream.index,
+            pid: stream
+                .id
+                .as_ref()
+                .and_then(|id| u32::from_str_radix(id.trim_start_matches("0x"), 16).ok()),
+            codec_type: stream.codec_type.unwrap_or_default(),
+            codec_name: stream.codec_name,
+            profile: stream.profile,
+            bitrate: stream.bit_rate.and_then(|b| b.parse().ok()),
+            width: stream.width,
+            height: stream.height,
+            pix_fmt: stream.pix_fmt,
+            framerate_num: framerate.map(|f| f.0),
+            framerate_den: framerate.map(|f| f.1),
+            field_order: stream.field_order,
+            interlaced,
+            color_primaries: stream.color_primaries,
+            color_transfer: stream.color_transfer,
+            language: stream.tags.get("language").cloned(),
+            sample_rate: stream.sample_rate.and_then(|s| s.parse().ok()),
+            channels: stream.channels,
+            channel_layout: stream.channel_layout,
+        }
+    }
+}
+
+pub struct FFProbe;
+
+impl FFProbe {
+    pub async fn probe(
+        ffprobe: &str,
+        input: &Input,
+        timeout_ms: u64,
+    ) -> std::io::Result<OutFFProbe> {
+        let args = vec![
+            "-v".to_string(),
+            "error".to_string(),
+            "-print_format".to_string(),
+            "json".to_string(),
+            "-show_format".to_string(),
+            "-show_programs".to_string(),
+            "-show_streams".to_string(),
+        ];
+        let probe = Self::run(ffprobe, input, args, timeout_ms).await?;
+
+        Ok(OutFFProbe {
+            format_name: probe.format.and_then(|f| f.format_name),
+            programs: probe
+                .programs
+                .into_iter()
+                .map(|program| ProbeProgram {
+                    program_num: program.program_num,
+                    pmt_pid: program.pmt_pid,
+                    pcr_pid: program.pcr_pid,
+                    service_name: program.tags.get("service_name").cloned(),
+                    service_provider: program.tags.get("service_provider").cloned(),
+                    streams: program.streams.iter().map(|s| s.index).collect(),
+                })
+                .collect(),
+            streams: probe.streams.into_iter().map(ProbeStream::from).collect(),
+            crop: None,
+        })
+    }
+
+    /// Codec of the first stream matching `stream_specifier`, None if no stream matches
+    pub async fn codec_name(
+        ffprobe: &str,
+        input: &Input,
+        stream_specifier: &str,
+        timeout_ms: u64,
+    ) -> std::io::Result<Option<String>> {
+        let args = vec![
+            "-v".to_string(),
+            "error".to_string(),
+            "-print_format".to_string(),
+            "json".to_string(),
+            "-select_streams".to_string(),
+            stream_specifier.to_string(),
+            "-show_entries".to_string(),
+            "stream=index,codec_name".to_string(),
+        ];
+        let probe = Self::run(ffprobe, input, args, timeout_ms).await?;
+        Ok(probe.streams.into_iter().find_map(|s| s.codec_name))
+    }
+
+    /// Run ffprobe on `input` with the output options `args`
+    async fn run(
+        ffprobe: &str,
+        input: &Input,
+        mut args: Vec<String>,
+        timeout_ms: u64,
+    ) -> std::io::Result<FFProbeOutput> {
+        input.ff_input_options(&mut args);
+        args.push("-i".to_string());
+        args.push(input.url());
+
+        let output = Command::new(ffprobe)
+            .args(&args)
+            .stdout(std::process::Stdio::piped())
+            .stderr(std::process::Stdio::piped())
+            .kill_on_drop(true)
+            .output();
+
+        let output = tokio::time::timeout(std::time::Duration::from_millis(timeout_ms), output)
+            .await
+            .map_err(|_| {
+                new_io_error!(
+                    std::io::ErrorKind::TimedOut,
+                    format!("ffprobe did not answer within {} ms", timeout_ms)
+                )
+            })??;
+
+        if !output.status.success() {
+            return Err(new_io_error!(
+                std::io::ErrorKind::Other,
+                format!(
+                    "ffprobe failed: {}",
+                    String::from_utf8_lossy(&output.stderr).trim()
+                )
+            ));
+        }
+
+        serde_json::from_slice(&output.stdout).map_err(|e| {
+            new_io_error!(
+                std::io::ErrorKind::InvalidData,
+                format!("Fail to parse ffprobe output: {}", e)
+            )
+        })
+    }
+
+    /// Black bars of the first video stream, measured by cropdetect on the first
+    /// frames. `width` and `height` are the size of the stream.
+    pub async fn detect_crop(
+        ffmpeg: &str,
+        input: &Input,
+        width: u32,
+        height: u32,
+        timeout_ms: u64,
+    ) -> std::io::Result<Option<VideoFilterCrop>> {
+        let mut args = vec!["-hide_banner".to_string(), "-nostats".to_string()];
+        input.ff_input_options(&mut args);
+        args.push("-i".to_string());
+        args.push(input.url());
+        for arg in [
+            "-map",
+            "0:v:0",
+            "-frames:v",
+            "100",
+            "-vf",
+            "cropdetect=round=2",
+            "-f",
+            "null",
+            "-",
+        ] {
+            args.push(arg.to_string());
+        }
+
+        let output = Command::new(ffmpeg)
+            .args(&args)
+            .stdout(std::process::Stdio::null())
+            .stderr(std::process::Stdio::piped())
+            .kill_on_drop(true)
+            .output();
+
+        let output = tokio::time::timeout(std::time::Duration::from_millis(timeout_ms), output)
+            .await
+            .map_err(|_| {
+                new_io_error!(
+                    std::io::ErrorKind::TimedOut,
+                    format!("cropdetect did not answer within {} ms", timeout_ms)
+                )
+            })??;
+
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        Ok(stderr
+            .lines()
+            .rev()
+            .find_map(|line| Self::parse_cropdetect(line, width, height)))
+    }
+
+    /// ex: [Parsed_cropdetect_0 @ 0x55d0] x1:0 x2:1919 y1:138 y2:941 w:1920 h:800 x:0 y:140 pts:... crop=1920:800:0:140
+    fn parse_cropdetect(line: &str, width: u32, height: u32) -> Option<VideoFilterCrop> {
+        if !line.contains("Parsed_cropdetect") {
+            return None;
+        }
+        let (_, crop) = line.rsplit_once("crop=")?;
+        let values: Vec<u32> = crop
+            .trim()
+            .split(':')
+            .map(|v| v.parse().ok())
+            .collect::<Option<_>>()?;
+        if let [w, h, x, y] = values[..] {
+            if x + w > width || y + h > height {
+                return None;
+            }
+            return Some(VideoFilterCrop {
+                top: y,
+                bottom: height - y - h,
+                left: x,
+                right: width - x - w,
+            });
+        }
+        None
+    }
+}
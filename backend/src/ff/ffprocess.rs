@@ -1,11 +1,19 @@
 use std::sync::Arc;
 
+use crate::model::ff::audioadaptationset::{AudioEncoder, Audioprofile, PASSTHROUGH_CODECS};
+use crate::model::ff::ffconfig::FFConfig;
+use crate::model::ff::ffstatusvalue::{DetectedFieldOrder, LoudnessValue};
+use crate::model::ff::input::Input;
+use crate::model::ff::inputfailover::{InputFailover, InputSwitchReason};
+use crate::model::ff::marker::{FFMarker, FFMarkerAck};
+use crate::model::ff::probe;
+use crate::model::ff::thumbnails::Thumbnails;
 use crate::model::server::Server;
 use crate::model::{self, state};
 use crate::new_io_error;
 use sysinfo::{ProcessRefreshKind, ProcessesToUpdate};
-use tokio::io::AsyncBufReadExt;
-use tokio::process::{ChildStderr, ChildStdout, Command};
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt};
+use tokio::process::{ChildStderr, ChildStdin, ChildStdout, Command};
 use tokio::sync::mpsc;
 use tokio::task::JoinHandle;
 
@@ -13,13 +21,21 @@ use super::ffcommand::FFCommand;
 use super::ffdb::FFDb;
 use super::fflogs::FFLogs;
 use super::ffmpegbin::FFmpegBinList;
+use super::ffprobe::FFProbe;
 use super::ffprocesschildwrapper::FFProcessChildWapper;
 use super::ffstatus::FFStatus;
 
+/// Maximum time to probe the source audio stream of a passthrough before the start
+const PASSTHROUGH_PROBE_TIMEOUT_MS: u64 = 10_000;
+
 enum Message {
     Refresh,
     Quit,
     UpdateStatus,
+    EndOfStream,
+    InsertMarker(FFMarker),
+    /// Result of the probe of the primary input done before returning to it
+    PrimaryProbed(Result<(), String>),
 }
 
 pub struct FFProcessHolder {
@@ -37,6 +53,11 @@ struct FFProcessActor {
     receiver: mpsc::Receiver<Message>,
     sender: mpsc::Sender<Message>,
     process: Option<FFProcessChildWapper>,
+    /// Key commands of ffmpeg, the markers are written there
+    stdin: Option<ChildStdin>,
+    markers_supported: bool,
+    /// Output folder of the thumbnail tiles, removed when ffmpeg is stopped
+    thumbnails_output: Option<std::path::PathBuf>,
     std_out_handle: Option<JoinHandle<()>>,
     std_err_handle: Option<JoinHandle<()>>,
     sys_info_handle: Option<JoinHandle<()>>,
@@ -45,6 +66,13 @@ struct FFProcessActor {
     ffmpegs: FFmpegBinList,
     status: Arc<FFStatus>,
     logs: FFLogs,
+    input_failover: InputFailover,
+    nb_inputs: usize,
+    active_input: usize,
+    nb_end_of_stream: u32,
+    running_since: Option<std::time::Instant>,
+    waiting_since: Option<std::time::Instant>,
+    primary_probe_handle: Option<JoinHandle<()>>,
 }
 
 impl FFProcessHolder {
@@ -100,12 +128,17 @@ impl FFProcess {
         self.sender.send(Message::Refresh).await.ok();
     }
 
+    pub async fn insert_marker(&self, marker: FFMarker) {
+        self.sender.send(Message::InsertMarker(marker)).await.ok();
+    }
+
     fn quit(&self) {
         self.sender.try_send(Message::Quit).ok();
     }
 }
 
 impl FFProcessActor {
+    #[allow(clippy::too_many_arguments)]
     fn new(
         server_info: Server,
         config_uid: String,
@@ -137,6 +170,9 @@ impl FFProcessActor {
             receiver,
             sender,
             process: None,
+            stdin: None,
+            markers_supported: false,
+            thumbnails_output: None,
             std_out_handle: None,
             std_err_handle: None,
             sys_info_handle: None,
@@ -145,6 +181,13 @@ impl FFProcessActor {
             ffmpegs,
             status,
             logs,
+            input_failover: InputFailover::default(),
+            nb_inputs: 1,
+            active_input: 0,
+            nb_end_of_stream: 0,
+            running_since: None,
+            waiting_since: None,
+            primary_probe_handle: None,
         }
     }
 
@@ -158,9 +201,16 @@ impl FFProcessActor {
         if let Some(sys_info_handle) = self.sys_info_handle.take() {
             sys_info_handle.abort();
         }
+        if let Some(primary_probe_handle) = self.primary_probe_handle.take() {
+            primary_probe_handle.abort();
+        }
+        self.stdin = None;
         if let Some(process) = self.process.take() {
             process.kill().await;
         }
+        if let Some(output) = self.thumbnails_output.take() {
+            Thumbnails::remove_tiles(&output);
+        }
 
         if self.status.get_state() != model::corestate::CoreState::Stopped {
             self.logs.push("[info] Live Encoder is stopped").await;
@@ -181,19 +231,27 @@ impl FFProcessActor {
                 "Config not found"
             ));
         }
-        let config = config.unwrap();
+        let mut config = config.unwrap();
+        self.input_failover = config.input_failover.clone();
+        self.nb_inputs = config.nb_inputs();
         if config.state == state::State::Inactive {
             self.status.clear_restart();
+            self.status.clear_active_input();
+            self.reset_input_failover(0);
             return Ok(());
         }
 
-        if config.input.uri.is_empty() {
-            self.logs.push("[error] Input URI is empty").await;
+        if self.active_input >= self.nb_inputs {
+            self.switch_input(0, InputSwitchReason::ReturnToPrimary)
+                .await;
+        }
+
+        if let Err(e) = config.validate() {
+            self.logs
+                .push(&format!("[error] Invalid config: {}", e))
+                .await;
             self.status.set_state(model::corestate::CoreState::Error);
-            return Err(new_io_error!(
-                std::io::ErrorKind::InvalidInput,
-                "Input URI is empty"
-            ));
+            return Err(e);
         }
 
         let gpu = self.server_info.get_gpu(&config.gpu_uid);
@@ -215,13 +273,35 @@ impl FFProcessActor {
             ));
         }
         let ffmpeg = ffmpeg.unwrap();
+        self.markers_supported = ffmpeg.markers_supported;
+        Self::check_audio_passthrough(
+            &mut self.logs,
+            &mut config,
+            self.active_input,
+            &ffmpeg.ffprobe,
+        )
+        .await;
+        let input = config.get_input(self.active_input);
         let command = FFCommand::new(
             gpu.as_ref().unwrap(),
             &config,
+            input,
+            &self.server_info.name,
             &self.base_output,
             ffmpeg.ast_delay_us_supported,
+            ffmpeg.markers_supported,
         );
-        tracing::info!("Starting ffmpeg: {}", command);
+        let redacted = config.redacted();
+        let redacted_command = FFCommand::new(
+            gpu.as_ref().unwrap(),
+            &redacted,
+            redacted.get_input(self.active_input),
+            &self.server_info.name,
+            &self.base_output,
+            ffmpeg.ast_delay_us_supported,
+            ffmpeg.markers_supported,
+        );
+        tracing::info!("Starting ffmpeg: {}", redacted_command);
         self.logs.push("[info] Starting Live Encoder").await;
 
         if let Err(e) = std::fs::create_dir_all(command.output_folder()) {
@@ -234,6 +314,7 @@ impl FFProcessActor {
 
         let mut process = match Command::new(&ffmpeg.ffmpeg)
             .args(command.args())
+            .stdin(std::process::Stdio::piped())
             .stdout(std::process::Stdio::piped())
             .stderr(std::process::Stdio::piped())
             .kill_on_drop(false)
@@ -248,6 +329,10 @@ impl FFProcessActor {
         };
 
         self.status.set_state(model::corestate::CoreState::Waiting);
+        self.stdin = process.stdin.take();
+        if config.thumbnails.is_some() {
+            self.thumbnails_output = Some(command.output_folder().to_path_buf());
+        }
         if let Some(stdout) = process.stdout.take() {
             self.run_std_out(stdout);
         }
@@ -266,16 +351,67 @@ impl FFProcessActor {
 
     fn run_std_err(&mut self, stderr: ChildStderr) {
         let mut logs = self.logs.clone();
+        let status = self.status.clone();
+        let ffdb = self.ffdb.clone();
         let std_err_handle = tokio::spawn(async move {
             let reader: tokio::io::BufReader<ChildStderr> = tokio::io::BufReader::new(stderr);
             let mut lines = reader.lines();
             while let Ok(Some(line)) = lines.next_line().await {
-                logs.push(&line).await;
+                if line.contains("Parsed_ebur128") {
+                    // the loudness meter logs ten measures per second
+                    if let Some(loudness) = Self::parse_ebur128(&line) {
+                        status.set_loudness(&loudness);
+                        continue;
+                    }
+                }
+                if line.contains("Parsed_metadata") {
+                    // the detected field order is logged for every frame, only its changes are kept
+                    if let Some(field_order) = Self::parse_idet(&line) {
+                        if status.set_field_order(field_order) {
+                            logs.push(&format!("[info] Detected field order: {}", field_order))
+                                .await;
+                        }
+                    }
+                    continue;
+                }
+                if line.contains("Marker ") {
+                    // acknowledge the marker to the insert_marker API
+                    if let Some(ack) = FFMarkerAck::parse_inserted(&line) {
+                        ffdb.set_ff_marker_ack(&ack).await.ok();
+                    }
+                }
+                logs.push(&Input::redact_log(&line)).await;
             }
         });
         self.std_err_handle = Some(std_err_handle);
     }
 
+    /// ex: [Parsed_metadata_1 @ 0x55d0] [info] lavfi.idet.multiple.current_frame=tff
+    fn parse_idet(line: &str) -> Option<DetectedFieldOrder> {
+        let (_, value) = line.split_once("lavfi.idet.multiple.current_frame=")?;
+        DetectedFieldOrder::from_idet(value.trim())
+    }
+
+    /// ex: [Parsed_ebur128_3 @ 0x55d0] [info] t: 10.0999  TARGET:-23 LUFS    M: -23.5 S: -23.1     I: -23.0 LUFS       LRA:   2.1 LU
+    fn parse_ebur128(line: &str) -> Option<LoudnessValue> {
+        let value = |key: &str| -> Option<f64> {
+            let (_, value) = line.split_once(key)?;
+            let value: f64 = value.split_whitespace().next()?.parse().ok()?;
+            Some(value.max(-200.0))
+        };
+
+        if !line.contains("TARGET:") {
+            return None;
+        }
+
+        Some(LoudnessValue {
+            momentary: value(" M:")?,
+            short_term: value(" S:")?,
+            integrated: value(" I:")?,
+            lra: value(" LRA:")?,
+        })
+    }
+
     fn run_sys_info(&mut self, pid: u32) {
         assert!(self.sys_info_handle.is_none());
         let status = self.status.clone();
@@ -359,7 +495,7 @@ impl FFProcessActor {
                     status.set_state(model::corestate::CoreState::Error);
                     tokio::time::sleep(std::time::Duration::from_secs(3)).await;
                     status.inc_restart();
-                    sender.send(Message::Refresh).await.ok();
+                    sender.send(Message::EndOfStream).await.ok();
                     is_running = false;
                 }
                 tracing::debug!("{}", line);
@@ -369,16 +505,248 @@ impl FFProcessActor {
             status.set_state(model::corestate::CoreState::Error);
             tokio::time::sleep(std::time::Duration::from_secs(3)).await;
             status.inc_restart();
-            sender.send(Message::Refresh).await.ok();
+            sender.send(Message::EndOfStream).await.ok();
         });
         self.std_out_handle = Some(std_out_handle);
     }
 
+    async fn insert_marker(&mut self, marker: FFMarker) {
+        let error = match self.stdin.as_mut() {
+            _ if !self.markers_supported => Some("FFMPEG does not support markers".to_string()),
+            None => Some("the Live Encoder is stopped".to_string()),
+            Some(stdin) => match stdin.write_all(marker.ff_command().as_bytes()).await {
+                Ok(_) => {
+                    stdin.flush().await.ok();
+                    None
+                }
+                Err(e) => Some(format!("fail to send it to FFMPEG: {}", e)),
+            },
+        };
+
+        let Some(error) = error else {
+            self.logs
+                .push(&format!(
+                    "[info] Inserting {} marker {}",
+                    marker.marker_type, marker.uid
+                ))
+                .await;
+            return;
+        };
+        self.logs
+            .push(&format!(
+                "[warning] Marker {} ignored, {}",
+                marker.uid, error
+            ))
+            .await;
+        // the insert_marker API returns the error without waiting for its timeout
+        self.ffdb
+            .set_ff_marker_ack(&FFMarkerAck::rejected(&marker.uid, &error))
+            .await
+            .ok();
+    }
+
     async fn update_status(&mut self) {
         let status = self.status.get_status();
         self.ffdb.set_ff_status(status, 10).await.ok();
     }
 
+    fn input_name(index: usize) -> String {
+        match index {
+            0 => "primary input".to_string(),
+            _ => format!("backup input {}", index),
+        }
+    }
+
+    fn next_input(&self) -> usize {
+        (self.active_input + 1) % self.nb_inputs.max(1)
+    }
+
+    fn reset_input_failover(&mut self, active_input: usize) {
+        self.active_input = active_input;
+        self.nb_end_of_stream = 0;
+        self.running_since = None;
+        self.waiting_since = None;
+    }
+
+    /// Select the input used by the next start of ffmpeg
+    async fn switch_input(&mut self, index: usize, reason: InputSwitchReason) {
+        self.logs
+            .push(&format!(
+                "[warning] Switching from {} to {} ({})",
+                Self::input_name(self.active_input),
+                Self::input_name(index),
+                reason
+            ))
+            .await;
+        self.reset_input_failover(index);
+        self.status.set_active_input(index, reason);
+    }
+
+    /// The MPD and the HLS playlists only signal AAC, AC-3 and E-AC-3, the source
+    /// audio stream of a passthrough is probed and transcoded to AAC if it has
+    /// another codec or can not be probed
+    async fn check_audio_passthrough(
+        logs: &mut FFLogs,
+        config: &mut FFConfig,
+        active_input: usize,
+        ffprobe: &str,
+    ) {
+        let input = config.get_input(active_input).clone();
+        for audio in config
+            .audio_adaptation_set
+            .iter_mut()
+            .filter(|a| a.encoder == AudioEncoder::Copy)
+        {
+            let specifier = config
+                .stream_selection
+                .ff_audio_specifier(audio.source.as_ref());
+            let error = match FFProbe::codec_name(
+                ffprobe,
+                &input,
+                &specifier,
+                PASSTHROUGH_PROBE_TIMEOUT_MS,
+            )
+            .await
+            {
+                Ok(Some(codec)) if PASSTHROUGH_CODECS.contains(&codec.as_str()) => continue,
+                Ok(Some(codec)) => format!("{} is not supported", codec),
+                Ok(None) => "no audio stream found".to_string(),
+                Err(e) => format!("probe failed: {}", e),
+            };
+
+            logs.push(&format!(
+                "[warning] Audio passthrough of the stream {}: {}, transcoding to AAC",
+                specifier, error
+            ))
+            .await;
+            audio.encoder = AudioEncoder::Aac;
+            for representation in audio.representations.iter_mut() {
+                representation.profile = Audioprofile::Low;
+            }
+        }
+    }
+
+    async fn on_end_of_stream(&mut self) {
+        self.nb_end_of_stream += 1;
+        let max_end_of_stream = self.input_failover.max_end_of_stream;
+        if self.nb_inputs > 1 && max_end_of_stream > 0 && self.nb_end_of_stream >= max_end_of_stream
+        {
+            self.switch_input(self.next_input(), InputSwitchReason::EndOfStream)
+                .await;
+        }
+        self.stop().await;
+        self.start().await.ok();
+    }
+
+    /// Apply the timeout and return-to-primary rules, called on every status update
+    async fn check_input_failover(&mut self) {
+        if self.process.is_none() || self.nb_inputs < 2 {
+            return;
+        }
+
+        let now = std::time::Instant::now();
+        let switch = match self.status.get_state() {
+            model::corestate::CoreState::Running => {
+                self.nb_end_of_stream = 0;
+                self.waiting_since = None;
+                let running_since = *self.running_since.get_or_insert(now);
+                let delay = std::time::Duration::from_millis(
+                    self.input_failover.return_to_primary_delay_ms,
+                );
+                if self.active_input != 0
+                    && self.input_failover.return_to_primary
+                    && now.duration_since(running_since) >= delay
+                    && self.primary_probe_handle.is_none()
+                {
+                    self.probe_primary().await;
+                }
+                None
+            }
+            _ => {
+                self.running_since = None;
+                let waiting_since = *self.waiting_since.get_or_insert(now);
+                let timeout = self.input_failover.input_timeout_ms;
+                match timeout > 0
+                    && now.duration_since(waiting_since)
+                        >= std::time::Duration::from_millis(timeout)
+                {
+                    true => Some((self.next_input(), InputSwitchReason::InputTimeout)),
+                    false => None,
+                }
+            }
+        };
+
+        if let Some((index, reason)) = switch {
+            self.switch_input(index, reason).await;
+            self.stop().await;
+            self.start().await.ok();
+        }
+    }
+
+    /// Probe the primary input in the background, the encoder keeps running on
+    /// the backup input until the result is received
+    async fn probe_primary(&mut self) {
+        let config = self
+            .ffdb
+            .get_ff_config_mut(&self.server_info.uid, &self.config_uid)
+            .await;
+        let (Ok(config), Some(ffprobe)) = (config, self.ffmpegs.ffprobe()) else {
+            self.logs
+                .push("[warning] Unable to probe the primary input, staying on the backup input")
+                .await;
+            self.running_since = None;
+            return;
+        };
+
+        let ffprobe = ffprobe.to_string();
+        let input = config.input;
+        let timeout_ms = match self.input_failover.input_timeout_ms {
+            0 => probe::MAX_TIMEOUT_MS,
+            timeout_ms => timeout_ms.min(probe::MAX_TIMEOUT_MS),
+        };
+        let sender = self.sender.clone();
+        self.primary_probe_handle = Some(tokio::spawn(async move {
+            let result = FFProbe::probe(&ffprobe, &input, timeout_ms)
+                .await
+                .and_then(|probe| match probe.streams.is_empty() {
+                    true => Err(new_io_error!(
+                        std::io::ErrorKind::InvalidData,
+                        "no stream found"
+                    )),
+                    false => Ok(()),
+                })
+                .map_err(|e| e.to_string());
+            sender.send(Message::PrimaryProbed(result)).await.ok();
+        }));
+    }
+
+    async fn on_primary_probed(&mut self, result: Result<(), String>) {
+        self.primary_probe_handle = None;
+        if self.process.is_none() || self.active_input == 0 {
+            return;
+        }
+
+        match result {
+            Ok(()) => {
+                self.switch_input(0, InputSwitchReason::ReturnToPrimary)
+                    .await;
+                self.stop().await;
+                self.start().await.ok();
+            }
+            Err(e) => {
+                self.logs
+                    .push(&format!(
+                        "[warning] The primary input is still down ({}), staying on {}",
+                        e,
+                        Self::input_name(self.active_input)
+                    ))
+                    .await;
+                // the next probe is done after another return_to_primary_delay_ms
+                self.running_since = None;
+            }
+        }
+    }
+
     async fn run(mut actor: Self) {
         while let Some(msg) = actor.receiver.recv().await {
             match msg {
@@ -391,8 +759,18 @@ impl FFProcessActor {
                     return;
                 }
                 Message::UpdateStatus => {
+                    actor.check_input_failover().await;
                     actor.update_status().await;
                 }
+                Message::EndOfStream => {
+                    actor.on_end_of_stream().await;
+                }
+                Message::InsertMarker(marker) => {
+                    actor.insert_marker(marker).await;
+                }
+                Message::PrimaryProbed(result) => {
+                    actor.on_primary_probed(result).await;
+                }
             }
         }
     }
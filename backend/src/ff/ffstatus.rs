@@ -1,6 +1,16 @@
-use std::sync::atomic::{AtomicU32, AtomicU64, AtomicU8, Ordering};
+use std::sync::atomic::{AtomicI64, AtomicU32, AtomicU64, AtomicU8, Ordering};
 
-use crate::model::{self, corestate::CoreState};
+use crate::model::{
+    self,
+    corestate::CoreState,
+    ff::{
+        ffstatusvalue::{DetectedFieldOrder, LoudnessValue},
+        inputfailover::InputSwitchReason,
+    },
+};
+
+/// Loudness not measured
+const NO_LOUDNESS: i64 = i64::MIN;
 
 #[derive(Debug)]
 pub struct FFStatus {
@@ -14,6 +24,14 @@ pub struct FFStatus {
     pub cpu_usage: AtomicU32,
     pub memory_usage: AtomicU64,
     pub nb_restart: AtomicU32,
+    pub active_input: AtomicU32,
+    pub nb_input_switch: AtomicU32,
+    pub last_input_switch: AtomicU8,
+    pub loudness_momentary: AtomicI64,
+    pub loudness_short_term: AtomicI64,
+    pub loudness_integrated: AtomicI64,
+    pub loudness_range: AtomicI64,
+    pub field_order: AtomicU8,
 }
 
 impl FFStatus {
@@ -29,6 +47,14 @@ impl FFStatus {
             cpu_usage: AtomicU32::new(0),
             memory_usage: AtomicU64::new(0),
             nb_restart: AtomicU32::new(0),
+            active_input: AtomicU32::new(0),
+            nb_input_switch: AtomicU32::new(0),
+            last_input_switch: AtomicU8::new(0),
+            loudness_momentary: AtomicI64::new(NO_LOUDNESS),
+            loudness_short_term: AtomicI64::new(NO_LOUDNESS),
+            loudness_integrated: AtomicI64::new(NO_LOUDNESS),
+            loudness_range: AtomicI64::new(NO_LOUDNESS),
+            field_order: AtomicU8::new(0),
         }
     }
 
@@ -72,6 +98,59 @@ impl FFStatus {
         self.nb_restart.store(0, Ordering::Relaxed);
     }
 
+    pub fn set_active_input(&self, active_input: usize, reason: InputSwitchReason) {
+        self.active_input
+            .store(active_input as u32, Ordering::Relaxed);
+        self.last_input_switch
+            .store(reason as u8, Ordering::Relaxed);
+        self.nb_input_switch.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn clear_active_input(&self) {
+        self.active_input.store(0, Ordering::Relaxed);
+        self.last_input_switch.store(0, Ordering::Relaxed);
+        self.nb_input_switch.store(0, Ordering::Relaxed);
+    }
+
+    pub fn set_loudness(&self, loudness: &LoudnessValue) {
+        self.loudness_momentary
+            .store((loudness.momentary * 1000.0) as i64, Ordering::Relaxed);
+        self.loudness_short_term
+            .store((loudness.short_term * 1000.0) as i64, Ordering::Relaxed);
+        self.loudness_integrated
+            .store((loudness.integrated * 1000.0) as i64, Ordering::Relaxed);
+        self.loudness_range
+            .store((loudness.lra * 1000.0) as i64, Ordering::Relaxed);
+    }
+
+    pub fn clear_loudness(&self) {
+        self.loudness_momentary
+            .store(NO_LOUDNESS, Ordering::Relaxed);
+        self.loudness_short_term
+            .store(NO_LOUDNESS, Ordering::Relaxed);
+        self.loudness_integrated
+            .store(NO_LOUDNESS, Ordering::Relaxed);
+        self.loudness_range.store(NO_LOUDNESS, Ordering::Relaxed);
+    }
+
+    fn get_loudness(&self) -> Option<LoudnessValue> {
+        let integrated = self.loudness_integrated.load(Ordering::Relaxed);
+        if integrated == NO_LOUDNESS {
+            return None;
+        }
+        Some(LoudnessValue {
+            momentary: self.loudness_momentary.load(Ordering::Relaxed) as f64 / 1000.0,
+            short_term: self.loudness_short_term.load(Ordering::Relaxed) as f64 / 1000.0,
+            integrated: integrated as f64 / 1000.0,
+            lra: self.loudness_range.load(Ordering::Relaxed) as f64 / 1000.0,
+        })
+    }
+
+    /// Returns true if the field order changed
+    pub fn set_field_order(&self, field_order: DetectedFieldOrder) -> bool {
+        self.field_order.swap(field_order as u8, Ordering::Relaxed) != field_order as u8
+    }
+
     pub fn set_state(&self, state: model::corestate::CoreState) {
         if state == model::corestate::CoreState::Stopped {
             self.set_speed(0.0);
@@ -81,6 +160,8 @@ impl FFStatus {
             self.set_out_time_ms(0);
             self.set_drop_frames(0);
             self.set_duplicate_frames(0);
+            self.clear_loudness();
+            self.field_order.store(0, Ordering::Relaxed);
         }
 
         self.state.store(state as u8, Ordering::Relaxed);
@@ -98,6 +179,13 @@ impl FFStatus {
             cpu_usage: self.cpu_usage.load(Ordering::Relaxed),
             memory_usage: self.memory_usage.load(Ordering::Relaxed),
             nb_restart: self.nb_restart.load(Ordering::Relaxed),
+            active_input: self.active_input.load(Ordering::Relaxed),
+            nb_input_switch: self.nb_input_switch.load(Ordering::Relaxed),
+            last_input_switch: InputSwitchReason::from_u8(
+                self.last_input_switch.load(Ordering::Relaxed),
+            ),
+            loudness: self.get_loudness(),
+            field_order: DetectedFieldOrder::from_u8(self.field_order.load(Ordering::Relaxed)),
         }
     }
 }
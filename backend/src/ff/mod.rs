@@ -8,4 +8,5 @@ pub mod ffcommand;
 pub mod ffdb;
 pub mod ffevent;
 pub mod ffcore;
-pub mod ffapi;
\ No newline at end of file
+pub mod ffapi;
+pub mod ffprobe;
\ No newline at end of file
@@ -19,6 +19,7 @@ use std::hash::{DefaultHasher, Hash, Hasher};
 
 use crate::model::{
     corestate::CoreState,
+    ff::probe::ProbeScope,
     server::{self, ServerCapability},
 };
 use actix_files::Files;
@@ -103,9 +104,30 @@ struct Args {
     #[arg(long)]
     disable_transcoder: Option<bool>,
 
+    /// Directory of the media files which can be probed, can be repeated.
+    /// This can also be set through the `MEDIA_ROOT` environment variable, a list of
+    /// directories separated by ':'.
+    /// If not provided, no file can be probed.
+    #[arg(long)]
+    media_root: Vec<std::path::PathBuf>,
+
+    /// Host of the network inputs which can be probed, can be repeated, `*` allows every host.
+    /// This can also be set through the `PROBE_HOSTS` environment variable, a list of
+    /// hosts separated by ','.
+    /// If not provided, no network input can be probed.
+    #[arg(long)]
+    probe_host: Vec<String>,
+
     /// Disable the User Interface
     #[arg(long)]
     disable_ui: Option<bool>,
+
+    /// Enable the SCTE-35 passthrough and the insertion of markers, they require an FFMPEG
+    /// built with the experimental patches of ffmpeg/README.md.
+    /// This can also be set through the `ENABLE_SCTE35` environment variable.
+    /// If not provided, they are disabled.
+    #[arg(long)]
+    enable_scte35: Option<bool>,
 }
 
 fn get_env(key: &str) -> Option<String> {
@@ -127,7 +149,7 @@ async fn main() -> std::io::Result<()> {
     let port: u16 = args
         .bind_addr
         .split(':')
-        .last()
+        .next_back()
         .unwrap_or("80")
         .parse()
         .unwrap_or(80);
@@ -175,9 +197,17 @@ async fn main() -> std::io::Result<()> {
         })
         .unwrap_or(false);
 
+    let enable_scte35 = args
+        .enable_scte35
+        .or_else(|| match get_env("ENABLE_SCTE35") {
+            Some(value) => value.parse::<bool>().ok(),
+            None => None,
+        })
+        .unwrap_or(false);
+
     let mut ffmpegs: Option<FFmpegBinList> = None;
     if !disable_transcoder {
-        let ret = FFmpegBinList::new(&args.ffmpeg)
+        let ret = FFmpegBinList::new(&args.ffmpeg, enable_scte35)
             .await
             .expect("FFMpeg binary is not valid");
         ffmpegs = Some(ret);
@@ -256,11 +286,28 @@ async fn main() -> std::io::Result<()> {
 
     tracing::info!("Live Output Directory: {:?}", live_output_dir);
 
+    let media_roots = match args.media_root.is_empty() {
+        true => get_env("MEDIA_ROOT")
+            .map(|roots| env::split_paths(&roots).collect())
+            .unwrap_or_default(),
+        false => args.media_root.clone(),
+    };
+    let probe_hosts = match args.probe_host.is_empty() {
+        true => get_env("PROBE_HOSTS")
+            .map(|hosts| hosts.split(',').map(|h| h.trim().to_string()).collect())
+            .unwrap_or_default(),
+        false => args.probe_host.clone(),
+    };
+
     let core = core::Core::new(
         &live_output_dir,
         redis.clone(),
         ffmpegs,
         server_info.clone(),
+        ProbeScope {
+            media_roots,
+            hosts: probe_hosts,
+        },
     );
 
     redis.set_server(server_info).await.ok();
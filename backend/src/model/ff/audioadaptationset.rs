@@ -1,12 +1,44 @@
 use std::fmt::Display;
 
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
 use utoipa::ToSchema;
 
+use super::{
+    audiofilter::AudioFilter,
+    streamselection::{self, StreamSelection, StreamSpecifier},
+};
+use crate::new_io_error;
+
 #[derive(Clone, Deserialize, Serialize, Debug, Copy, PartialEq, ToSchema)]
 pub enum AudioEncoder {
     Aac,
     FDKAac,
+    /// Dolby Digital
+    Ac3,
+    /// Dolby Digital Plus
+    Eac3,
+    /// Passthrough of the source audio stream, AAC, AC-3 or E-AC-3 only,
+    /// another codec is transcoded to AAC. Not available with the listener inputs.
+    Copy,
+}
+
+/// Codecs of the source audio streams supported by the passthrough,
+/// the only codecs signalled by the MPD and the HLS playlists
+pub const PASSTHROUGH_CODECS: [&str; 3] = ["aac", "ac3", "eac3"];
+
+impl AudioEncoder {
+    pub fn is_aac(&self) -> bool {
+        matches!(self, AudioEncoder::Aac | AudioEncoder::FDKAac)
+    }
+
+    /// Max bitrate accepted by the encoder, in bit/s
+    fn max_bitrate(&self) -> u32 {
+        match self {
+            AudioEncoder::Ac3 => 640000,
+            AudioEncoder::Eac3 => 6144000,
+            _ => u32::MAX,
+        }
+    }
 }
 
 impl Display for AudioEncoder {
@@ -14,10 +46,35 @@ impl Display for AudioEncoder {
         match self {
             AudioEncoder::Aac => write!(f, "aac"),
             AudioEncoder::FDKAac => write!(f, "libfdk_aac"),
+            AudioEncoder::Ac3 => write!(f, "ac3"),
+            AudioEncoder::Eac3 => write!(f, "eac3"),
+            AudioEncoder::Copy => write!(f, "copy"),
+        }
+    }
+}
+
+#[derive(Default, Clone, Deserialize, Serialize, Debug, Copy, PartialEq, Eq, ToSchema)]
+pub enum ChannelLayout {
+    /// Channel layout of the source audio stream
+    #[default]
+    Source,
+    Mono,
+    Stereo,
+    Surround51,
+}
+
+impl Display for ChannelLayout {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ChannelLayout::Source => write!(f, "source"),
+            ChannelLayout::Mono => write!(f, "mono"),
+            ChannelLayout::Stereo => write!(f, "stereo"),
+            ChannelLayout::Surround51 => write!(f, "5.1"),
         }
     }
 }
 
+#[allow(clippy::upper_case_acronyms)]
 #[derive(Default, Clone, Deserialize, Serialize, Debug, ToSchema)]
 pub enum Audioprofile {
     #[default]
@@ -36,37 +93,298 @@ impl Display for Audioprofile {
     }
 }
 
-#[derive(Clone, Deserialize, Serialize, Debug, ToSchema)]
+/// DASH role, urn:mpeg:dash:role:2011
+#[derive(Clone, Deserialize, Serialize, Debug, Copy, PartialEq, Eq, ToSchema)]
 pub enum Role {
     Main,
+    Alternate,
+    Supplementary,
+    Commentary,
+    Dub,
+    /// Audio description for the visually impaired
+    Description,
 }
 
 impl Display for Role {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Role::Main => write!(f, "main"),
+            Role::Alternate => write!(f, "alternate"),
+            Role::Supplementary => write!(f, "supplementary"),
+            Role::Commentary => write!(f, "commentary"),
+            Role::Dub => write!(f, "dub"),
+            Role::Description => write!(f, "description"),
         }
     }
 }
 
 #[derive(Clone, Deserialize, Serialize, Debug, ToSchema)]
-pub struct AudioAdaptationSet {
-    pub encoder: AudioEncoder,
+pub struct AudioRepresentation {
     pub bitrate: u32,
     pub sample_rate: u32,
-    pub role: Role,
+    /// AAC profile
     #[serde(default)]
     pub profile: Audioprofile,
 }
 
+#[derive(Clone, Deserialize, Serialize, Debug, ToSchema)]
+#[serde(from = "AudioAdaptationSetValue")]
+pub struct AudioAdaptationSet {
+    pub encoder: AudioEncoder,
+    pub representations: Vec<AudioRepresentation>,
+    pub role: Role,
+    /// Ignored by the passthrough
+    pub channel_layout: ChannelLayout,
+    /// Source audio stream, the default audio stream of the config if not set
+    pub source: Option<StreamSpecifier>,
+    /// ISO-639 language code
+    pub language: Option<String>,
+    pub filter: AudioFilter,
+}
+
+/// Configs stored before the support of several audio representations
+/// have the bitrate, sample rate and profile in the adaptation set
+#[derive(Deserialize)]
+struct AudioAdaptationSetValue {
+    encoder: AudioEncoder,
+    #[serde(default)]
+    representations: Vec<AudioRepresentation>,
+    role: Role,
+    #[serde(default)]
+    channel_layout: ChannelLayout,
+    #[serde(default)]
+    source: Option<StreamSpecifier>,
+    #[serde(default)]
+    language: Option<String>,
+    #[serde(default)]
+    filter: AudioFilter,
+    bitrate: Option<u32>,
+    sample_rate: Option<u32>,
+    #[serde(default)]
+    profile: Audioprofile,
+}
+
+impl From<AudioAdaptationSetValue> for AudioAdaptationSet {
+    fn from(value: AudioAdaptationSetValue) -> Self {
+        let mut representations = value.representations;
+        if let (true, Some(bitrate), Some(sample_rate)) =
+            (representations.is_empty(), value.bitrate, value.sample_rate)
+        {
+            representations.push(AudioRepresentation {
+                bitrate,
+                sample_rate,
+                profile: value.profile,
+            });
+        }
+
+        Self {
+            encoder: value.encoder,
+            representations,
+            role: value.role,
+            channel_layout: value.channel_layout,
+            source: value.source,
+            language: value.language,
+            filter: value.filter,
+        }
+    }
+}
+
 impl AudioAdaptationSet {
     pub fn new() -> Self {
         Self {
             encoder: AudioEncoder::FDKAac,
-            bitrate: 128000,
-            sample_rate: 48000,
+            representations: vec![AudioRepresentation {
+                bitrate: 128000,
+                sample_rate: 48000,
+                profile: Audioprofile::Low,
+            }],
             role: Role::Main,
-            profile: Audioprofile::Low,
+            channel_layout: ChannelLayout::Source,
+            source: None,
+            language: None,
+            filter: AudioFilter::default(),
+        }
+    }
+
+    pub fn validate(&self) -> std::io::Result<()> {
+        if let Some(source) = self.source.as_ref() {
+            source.validate()?;
+        }
+        if let Some(language) = self.language.as_ref() {
+            streamselection::validate_language(language)?;
+        }
+        self.filter.validate()?;
+        if self.encoder == AudioEncoder::Copy && !self.filter.is_empty() {
+            return Err(new_io_error!(
+                std::io::ErrorKind::InvalidInput,
+                "Audio filters are not available with the passthrough"
+            ));
+        }
+        if self.representations.is_empty() {
+            return Err(new_io_error!(
+                std::io::ErrorKind::InvalidInput,
+                "Audio adaptation set without representation"
+            ));
+        }
+        if self.encoder == AudioEncoder::Copy && self.representations.len() > 1 {
+            return Err(new_io_error!(
+                std::io::ErrorKind::InvalidInput,
+                "Audio passthrough with several representations"
+            ));
+        }
+        for representation in &self.representations {
+            if self.encoder == AudioEncoder::Aac
+                && !matches!(representation.profile, Audioprofile::Low)
+            {
+                return Err(new_io_error!(
+                    std::io::ErrorKind::InvalidInput,
+                    "HE-AAC profiles require the FDKAac encoder"
+                ));
+            }
+            if representation.bitrate > self.encoder.max_bitrate() {
+                return Err(new_io_error!(
+                    std::io::ErrorKind::InvalidInput,
+                    format!(
+                        "Audio bitrate {} above the maximum of {} for {}",
+                        representation.bitrate,
+                        self.encoder.max_bitrate(),
+                        self.encoder
+                    )
+                ));
+            }
+            if matches!(self.encoder, AudioEncoder::Ac3 | AudioEncoder::Eac3)
+                && ![32000, 44100, 48000].contains(&representation.sample_rate)
+            {
+                return Err(new_io_error!(
+                    std::io::ErrorKind::InvalidInput,
+                    format!(
+                        "Audio sample rate {} not supported by {}",
+                        representation.sample_rate, self.encoder
+                    )
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// DVB-DASH signalling of the audio description
+    fn ff_descriptor(&self) -> Option<&str> {
+        match self.role {
+            Role::Description => Some(
+                "<Accessibility schemeIdUri=\"urn:tva:metadata:cs:AudioPurposeCS:2007\" value=\"1\"/>",
+            ),
+            _ => None,
         }
     }
+
+    /// `first_id` is the id of the first audio adaptation set and `first_stream`
+    /// the index of the first audio output stream
+    pub fn ff_adaptation_sets(
+        segment_duration: f64,
+        first_id: usize,
+        first_stream: usize,
+        sets: &[AudioAdaptationSet],
+    ) -> String {
+        let mut index = first_stream;
+        let mut adaptation_sets_id = first_id;
+        let mut adaptation_sets = String::new();
+        for adaptation in sets.iter() {
+            if !adaptation.representations.is_empty() {
+                adaptation_sets.push_str(&format!("id={},", adaptation_sets_id));
+                if let Some(descriptor) = adaptation.ff_descriptor() {
+                    adaptation_sets.push_str(&format!("descriptor={},", descriptor));
+                }
+                adaptation_sets.push_str(&format!("seg_duration={},", segment_duration));
+                adaptation_sets.push_str("streams=");
+                for _ in 0..adaptation.representations.len() {
+                    adaptation_sets.push_str(&format!("{index},"));
+                    index += 1;
+                }
+
+                adaptation_sets.pop();
+                adaptation_sets.push(' ');
+                adaptation_sets_id += 1;
+            }
+        }
+        adaptation_sets
+    }
+
+    /// The loudness of the first representation with loudnorm is measured
+    pub fn ff_audio_encode(sets: &[AudioAdaptationSet], args: &mut Vec<String>) {
+        let mut index = 0;
+        let mut measured = false;
+        for adaptation in sets.iter() {
+            for representation in adaptation.representations.iter() {
+                args.push(format!("-c:a:{index}"));
+                args.push(adaptation.encoder.to_string());
+
+                let measure = !measured && adaptation.filter.loudnorm.is_some();
+                if let Some(filter) = adaptation
+                    .filter
+                    .ff_filter(adaptation.channel_layout, measure)
+                {
+                    args.push(format!("-filter:a:{index}"));
+                    args.push(filter);
+                    measured |= measure;
+                }
+
+                if adaptation.encoder != AudioEncoder::Copy {
+                    args.push(format!("-ar:a:{index}"));
+                    args.push(representation.sample_rate.to_string());
+                    args.push(format!("-b:a:{index}"));
+                    args.push(representation.bitrate.to_string());
+                    if adaptation.channel_layout != ChannelLayout::Source {
+                        args.push(format!("-ch_layout:a:{index}"));
+                        args.push(adaptation.channel_layout.to_string());
+                    }
+                }
+
+                if adaptation.encoder.is_aac() {
+                    args.push(format!("-profile:a:{index}"));
+                    args.push(representation.profile.to_string());
+                }
+
+                args.push(format!("-metadata:s:a:{index}"));
+                args.push(format!("role={}", adaptation.role));
+                if let Some(language) = adaptation.language.as_ref() {
+                    args.push(format!("-metadata:s:a:{index}"));
+                    args.push(format!("language={}", language));
+                }
+                index += 1;
+            }
+        }
+    }
+
+    pub fn ff_map(
+        selection: &StreamSelection,
+        sets: &[AudioAdaptationSet],
+        args: &mut Vec<String>,
+    ) {
+        for adaptation in sets.iter() {
+            for _ in adaptation.representations.iter() {
+                args.push("-map".to_string());
+                args.push(selection.ff_audio(adaptation.source.as_ref()));
+            }
+        }
+    }
+}
+
+/// Configs stored before the support of several audio adaptation sets have a single one
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum AudioAdaptationSets {
+    Many(Vec<AudioAdaptationSet>),
+    One(AudioAdaptationSet),
+}
+
+pub fn deserialize_audio_adaptation_sets<'de, D>(
+    deserializer: D,
+) -> Result<Vec<AudioAdaptationSet>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Ok(match AudioAdaptationSets::deserialize(deserializer)? {
+        AudioAdaptationSets::Many(sets) => sets,
+        AudioAdaptationSets::One(set) => vec![set],
+    })
 }
@@ -0,0 +1,119 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::new_io_error;
+
+use super::audioadaptationset::ChannelLayout;
+
+/// EBU R128 loudness normalization
+#[derive(Clone, Deserialize, Serialize, Debug, ToSchema)]
+#[serde(default)]
+pub struct AudioFilterLoudnorm {
+    /// Integrated loudness target, in LUFS
+    pub integrated: f64,
+    /// Loudness range target, in LU
+    pub lra: f64,
+    /// Maximum true peak, in dBTP
+    pub true_peak: f64,
+}
+
+/// EBU R128 targets
+impl Default for AudioFilterLoudnorm {
+    fn default() -> Self {
+        Self {
+            integrated: -23.0,
+            lra: 7.0,
+            true_peak: -1.0,
+        }
+    }
+}
+
+impl AudioFilterLoudnorm {
+    pub fn validate(&self) -> std::io::Result<()> {
+        if !(-70.0..=-5.0).contains(&self.integrated)
+            || !(1.0..=50.0).contains(&self.lra)
+            || !(-9.0..=0.0).contains(&self.true_peak)
+        {
+            return Err(new_io_error!(
+                std::io::ErrorKind::InvalidInput,
+                format!(
+                    "Invalid loudnorm target I={} LRA={} TP={}",
+                    self.integrated, self.lra, self.true_peak
+                )
+            ));
+        }
+        Ok(())
+    }
+
+    pub fn ff_loudnorm(&self) -> String {
+        format!(
+            "loudnorm=I={}:LRA={}:TP={}",
+            self.integrated, self.lra, self.true_peak
+        )
+    }
+}
+
+#[derive(Default, Clone, Deserialize, Serialize, Debug, ToSchema)]
+pub struct AudioFilter {
+    pub loudnorm: Option<AudioFilterLoudnorm>,
+    /// Gain applied before the loudness normalization, in dB
+    pub volume_db: Option<f64>,
+    /// Audio delay relative to the video, in ms. Negative values advance the audio.
+    #[serde(default)]
+    pub delay_ms: i32,
+}
+
+impl AudioFilter {
+    pub fn is_empty(&self) -> bool {
+        self.loudnorm.is_none() && self.volume_db.is_none() && self.delay_ms == 0
+    }
+
+    pub fn validate(&self) -> std::io::Result<()> {
+        if let Some(loudnorm) = self.loudnorm.as_ref() {
+            loudnorm.validate()?;
+        }
+        if self.volume_db.is_some_and(|v| !(-60.0..=60.0).contains(&v)) {
+            return Err(new_io_error!(
+                std::io::ErrorKind::InvalidInput,
+                "Audio volume must be between -60 and 60 dB"
+            ));
+        }
+        if !(-10000..=10000).contains(&self.delay_ms) {
+            return Err(new_io_error!(
+                std::io::ErrorKind::InvalidInput,
+                "Audio delay must be between -10000 and 10000 ms"
+            ));
+        }
+        Ok(())
+    }
+
+    /// Filter chain of an audio output stream, `measure` appends an EBU R128 meter
+    pub fn ff_filter(&self, channel_layout: ChannelLayout, measure: bool) -> Option<String> {
+        let mut filters = Vec::new();
+
+        if channel_layout != ChannelLayout::Source && !self.is_empty() {
+            filters.push(format!("aformat=channel_layouts={}", channel_layout));
+        }
+
+        if self.delay_ms != 0 {
+            filters.push(format!("asetpts=PTS{:+}/TB", self.delay_ms as f64 / 1000.0));
+        }
+
+        if let Some(volume_db) = self.volume_db {
+            filters.push(format!("volume={}dB", volume_db));
+        }
+
+        if let Some(loudnorm) = self.loudnorm.as_ref() {
+            filters.push(loudnorm.ff_loudnorm());
+        }
+
+        if measure {
+            filters.push("ebur128".to_string());
+        }
+
+        match filters.is_empty() {
+            true => None,
+            false => Some(filters.join(",")),
+        }
+    }
+}
@@ -0,0 +1,116 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::new_io_error;
+
+use super::{ffconfig::Acceleration, streamselection, videoadaptationset::VideoEncoder};
+
+#[derive(Clone, Deserialize, Serialize, Debug, Copy, PartialEq, Eq, ToSchema)]
+pub enum CaptionStandard {
+    Cea608,
+    Cea708,
+}
+
+#[derive(Clone, Deserialize, Serialize, Debug, ToSchema)]
+pub struct CaptionChannel {
+    /// CC1 to CC4 for CEA-608, caption service 1 to 63 for CEA-708
+    pub channel: u32,
+    /// ISO-639 language code
+    pub language: String,
+}
+
+/// A53 closed captions of the source, carried in the SEI of every video representation
+#[derive(Clone, Deserialize, Serialize, Debug, ToSchema)]
+pub struct ClosedCaptions {
+    /// Standard of the captions of the source, selects the numbering of the channels
+    pub standard: CaptionStandard,
+    /// Channels signalled in the MPD and the HLS playlist
+    pub channels: Vec<CaptionChannel>,
+}
+
+impl ClosedCaptions {
+    pub fn validate(&self) -> std::io::Result<()> {
+        let max_channel = match self.standard {
+            CaptionStandard::Cea608 => 4,
+            CaptionStandard::Cea708 => 63,
+        };
+        for channel in &self.channels {
+            if !(1..=max_channel).contains(&channel.channel) {
+                return Err(new_io_error!(
+                    std::io::ErrorKind::InvalidInput,
+                    format!(
+                        "Invalid closed caption channel {} for {:?}",
+                        channel.channel, self.standard
+                    )
+                ));
+            }
+            streamselection::validate_language(&channel.language)?;
+        }
+        Ok(())
+    }
+
+    /// Accessibility descriptor of the video adaptation sets, SCTE 214-1
+    pub fn ff_descriptor(&self) -> Option<String> {
+        if self.channels.is_empty() {
+            return None;
+        }
+        let (scheme, channels) = match self.standard {
+            CaptionStandard::Cea608 => (
+                "urn:scte:dash:cc:cea-608:2015",
+                self.channels
+                    .iter()
+                    .map(|c| format!("CC{}={}", c.channel, c.language))
+                    .collect::<Vec<_>>(),
+            ),
+            CaptionStandard::Cea708 => (
+                "urn:scte:dash:cc:cea-708:2015",
+                self.channels
+                    .iter()
+                    .map(|c| format!("{}=lang:{}", c.channel, c.language))
+                    .collect::<Vec<_>>(),
+            ),
+        };
+        Some(format!(
+            "<Accessibility schemeIdUri=\"{}\" value=\"{}\"/>",
+            scheme,
+            channels.join(";")
+        ))
+    }
+
+    /// One CLOSED-CAPTIONS rendition of the HLS playlist per channel
+    pub fn ff_hls_closed_captions(&self, args: &mut Vec<String>) {
+        if self.channels.is_empty() {
+            return;
+        }
+        let renditions: Vec<String> = self
+            .channels
+            .iter()
+            .map(|c| match self.standard {
+                CaptionStandard::Cea608 => format!("CC{}:{}", c.channel, c.language),
+                CaptionStandard::Cea708 => format!("SERVICE{}:{}", c.channel, c.language),
+            })
+            .collect();
+        args.push("-hls_closed_captions".to_string());
+        args.push(renditions.join(" "));
+    }
+
+    /// Insert the A53 captions of the frames in the SEI of the stream `index`
+    pub fn ff_encode(
+        acceleration: Acceleration,
+        encoder: VideoEncoder,
+        index: usize,
+        args: &mut Vec<String>,
+    ) {
+        match (acceleration, encoder) {
+            (_, VideoEncoder::AV1) => {}
+            (Acceleration::Vaapi, _) => {
+                args.push(format!("-sei:v:{index}"));
+                args.push("+a53_cc".to_string());
+            }
+            (Acceleration::Software | Acceleration::Cuda, _) => {
+                args.push(format!("-a53cc:v:{index}"));
+                args.push("1".to_string());
+            }
+        }
+    }
+}
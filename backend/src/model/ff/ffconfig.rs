@@ -2,12 +2,21 @@ use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
 use crate::model::{server::Server, state::State};
+use crate::new_io_error;
 
 use super::{
-    audioadaptationset::AudioAdaptationSet,
+    audioadaptationset::{self, AudioAdaptationSet, AudioEncoder},
+    closedcaptions::ClosedCaptions,
+    hdr::HdrMetadata,
+    input::{self, Input},
+    inputfailover::InputFailover,
+    scte35::Scte35,
     srt::Srt,
+    streamselection::StreamSelection,
+    subtitle::{SubtitleFormat, SubtitleTrack},
+    thumbnails::Thumbnails,
     videoadaptationset::{Colorspace, VideoAdaptationSet},
-    videofilter::VideoFilterDeinterlace,
+    videofilter::{Deinterlace, VideoFilter, VideoFilterDeinterlace},
 };
 
 #[derive(Default, Clone, Deserialize, Serialize, Debug, Copy, PartialEq, Eq, ToSchema)]
@@ -24,13 +33,37 @@ pub struct FFConfig {
     pub server_uid: String,
     pub name: String,
     pub state: State,
-    pub input: Srt,
+    #[serde(deserialize_with = "input::deserialize_input")]
+    pub input: Input,
+    #[serde(default, deserialize_with = "input::deserialize_inputs")]
+    pub backup_inputs: Vec<Input>,
+    #[serde(default)]
+    pub input_failover: InputFailover,
+    #[serde(default)]
+    pub stream_selection: StreamSelection,
     pub output: String,
     pub segment_duration_ms: u32,
     pub colorspace: Colorspace,
+    /// HDR static metadata of the BT2020PQ and BT2020HLG colorspaces
+    #[serde(default)]
+    pub hdr_metadata: HdrMetadata,
+    /// Deinterlacing with the default options, before the video filters.
+    /// Ignored if the video filters have a deinterlacing.
     pub deinterlace: VideoFilterDeinterlace,
+    /// Filters applied in order to the source, before the scaling
+    #[serde(default)]
+    pub video_filters: Vec<VideoFilter>,
     pub video_adaptation_set: Vec<VideoAdaptationSet>,
-    pub audio_adaptation_set: AudioAdaptationSet,
+    #[serde(deserialize_with = "audioadaptationset::deserialize_audio_adaptation_sets")]
+    pub audio_adaptation_set: Vec<AudioAdaptationSet>,
+    #[serde(default)]
+    pub thumbnails: Option<Thumbnails>,
+    #[serde(default)]
+    pub closed_captions: Option<ClosedCaptions>,
+    #[serde(default)]
+    pub subtitles: Vec<SubtitleTrack>,
+    #[serde(default)]
+    pub scte35: Option<Scte35>,
     pub mpd_type: MPDType,
     pub enable_hls: bool,
     pub media_seg_name: String,
@@ -64,13 +97,22 @@ impl FFConfig {
             name,
             server_uid: server_info.uid.clone(),
             state: State::Inactive,
-            input: Srt::new(),
+            input: Input::Srt(Srt::new()),
+            backup_inputs: Vec::new(),
+            input_failover: InputFailover::default(),
+            stream_selection: StreamSelection::default(),
             output: output.to_string_lossy().into_owned(),
             segment_duration_ms: 2000,
             colorspace: Colorspace::Auto,
+            hdr_metadata: HdrMetadata::default(),
             deinterlace: VideoFilterDeinterlace::new(),
+            video_filters: Vec::new(),
             video_adaptation_set: vec![VideoAdaptationSet::new()],
-            audio_adaptation_set: AudioAdaptationSet::new(),
+            audio_adaptation_set: vec![AudioAdaptationSet::new()],
+            thumbnails: None,
+            closed_captions: None,
+            subtitles: Vec::new(),
+            scte35: None,
             mpd_type: MPDType::Template,
             enable_hls: true,
             media_seg_name: "$RepresentationID$-$Number%05d$.$ext$".to_string(),
@@ -84,4 +126,129 @@ impl FFConfig {
             encryption_kid: None,
         }
     }
+
+    /// Number of inputs, the primary input included
+    pub fn nb_inputs(&self) -> usize {
+        1 + self.backup_inputs.len()
+    }
+
+    /// Input at `index` in the failover order: 0 is the primary input, then the backup inputs
+    pub fn get_input(&self, index: usize) -> &Input {
+        match index {
+            0 => &self.input,
+            _ => self.backup_inputs.get(index - 1).unwrap_or(&self.input),
+        }
+    }
+
+    /// Video filters, the deinterlacing of the `deinterlace` shortcut included
+    pub fn video_filters(&self) -> Vec<VideoFilter> {
+        let mut filters = self.video_filters.clone();
+        let has_deinterlace = filters
+            .iter()
+            .any(|f| matches!(f, VideoFilter::Deinterlace(_)));
+        if self.deinterlace.enable && !has_deinterlace {
+            let position = filters
+                .iter()
+                .position(|f| !matches!(f, VideoFilter::FieldOrder(_)))
+                .unwrap_or(filters.len());
+            let deinterlace = Deinterlace {
+                auto: self.deinterlace.auto,
+                ..Default::default()
+            };
+            filters.insert(position, VideoFilter::Deinterlace(deinterlace));
+        }
+        filters
+    }
+
+    pub fn validate(&self) -> std::io::Result<()> {
+        self.input.validate()?;
+        for input in &self.backup_inputs {
+            input.validate()?;
+        }
+        self.stream_selection.validate()?;
+        for filter in &self.video_filters {
+            filter.validate(self.acceleration)?;
+        }
+        for video in &self.video_adaptation_set {
+            video.validate(self)?;
+        }
+        self.hdr_metadata.validate()?;
+        if !self.hdr_metadata.is_empty() && self.acceleration != Acceleration::Software {
+            return Err(new_io_error!(
+                std::io::ErrorKind::InvalidInput,
+                "HDR metadata can only be set with the software encoders, the hardware encoders pass the metadata of the source through"
+            ));
+        }
+        if !self.hdr_metadata.is_empty() && !self.colorspace.is_hdr() {
+            return Err(new_io_error!(
+                std::io::ErrorKind::InvalidInput,
+                "HDR metadata require the BT2020PQ or BT2020HLG colorspace"
+            ));
+        }
+        for audio in &self.audio_adaptation_set {
+            audio.validate()?;
+        }
+        // the codec of the passthrough is probed before the start of the encoder
+        if self
+            .audio_adaptation_set
+            .iter()
+            .any(|a| a.encoder == AudioEncoder::Copy)
+            && (0..self.nb_inputs()).any(|i| self.get_input(i).is_listener())
+        {
+            return Err(new_io_error!(
+                std::io::ErrorKind::InvalidInput,
+                "The audio passthrough is not available with the listener inputs"
+            ));
+        }
+        if let Some(closed_captions) = self.closed_captions.as_ref() {
+            closed_captions.validate()?;
+        }
+        SubtitleTrack::validate_tracks(&self.subtitles)?;
+        if self
+            .subtitles
+            .iter()
+            .any(|s| s.format == SubtitleFormat::BurnIn)
+            && self.video_adaptation_set.is_empty()
+        {
+            return Err(new_io_error!(
+                std::io::ErrorKind::InvalidInput,
+                "Burned in subtitles require a video adaptation set"
+            ));
+        }
+        if let Some(scte35) = self.scte35.as_ref() {
+            let inputs: Vec<&Input> = (0..self.nb_inputs()).map(|i| self.get_input(i)).collect();
+            scte35.validate(&inputs)?;
+        }
+        if let Some(thumbnails) = self.thumbnails.as_ref() {
+            thumbnails.validate()?;
+            if self.video_adaptation_set.is_empty() {
+                return Err(new_io_error!(
+                    std::io::ErrorKind::InvalidInput,
+                    "Thumbnails require a video adaptation set"
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Copy of this config without its secrets, to be returned to the clients
+    pub fn redacted(&self) -> Self {
+        let mut config = self.clone();
+        config.input = self.input.redacted();
+        config.backup_inputs = self.backup_inputs.iter().map(|i| i.redacted()).collect();
+        config
+    }
+
+    /// Put back the secrets of `stored` that have been redacted by the client, the
+    /// inputs are matched by URI as the client may reorder, add or remove them
+    pub fn restore_secrets(&mut self, stored: &FFConfig) {
+        let stored_inputs: Vec<&Input> = std::iter::once(&stored.input)
+            .chain(&stored.backup_inputs)
+            .collect();
+        for input in std::iter::once(&mut self.input).chain(self.backup_inputs.iter_mut()) {
+            for stored in stored_inputs.iter() {
+                input.restore_secrets(stored);
+            }
+        }
+    }
 }
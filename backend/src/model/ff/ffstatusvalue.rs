@@ -1,8 +1,65 @@
+use std::fmt::Display;
+
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
 use crate::model::corestate::CoreState;
 
+use super::inputfailover::InputSwitchReason;
+
+/// EBU R128 loudness measured on the output
+#[derive(Clone, Deserialize, Serialize, Debug, ToSchema)]
+pub struct LoudnessValue {
+    /// Momentary loudness, in LUFS
+    pub momentary: f64,
+    /// Short-term loudness, in LUFS
+    pub short_term: f64,
+    /// Integrated loudness, in LUFS
+    pub integrated: f64,
+    /// Loudness range, in LU
+    pub lra: f64,
+}
+
+/// Field order of the source detected by the automatic deinterlacing
+#[derive(Clone, Deserialize, Serialize, Debug, Copy, PartialEq, Eq, ToSchema)]
+#[repr(u8)]
+pub enum DetectedFieldOrder {
+    Progressive = 1,
+    Tff,
+    Bff,
+}
+
+impl DetectedFieldOrder {
+    pub fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            1 => Some(DetectedFieldOrder::Progressive),
+            2 => Some(DetectedFieldOrder::Tff),
+            3 => Some(DetectedFieldOrder::Bff),
+            _ => None,
+        }
+    }
+
+    /// Value of the lavfi.idet.multiple.current_frame metadata of idet
+    pub fn from_idet(value: &str) -> Option<Self> {
+        match value {
+            "progressive" => Some(DetectedFieldOrder::Progressive),
+            "tff" => Some(DetectedFieldOrder::Tff),
+            "bff" => Some(DetectedFieldOrder::Bff),
+            _ => None,
+        }
+    }
+}
+
+impl Display for DetectedFieldOrder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DetectedFieldOrder::Progressive => write!(f, "progressive"),
+            DetectedFieldOrder::Tff => write!(f, "top field first"),
+            DetectedFieldOrder::Bff => write!(f, "bottom field first"),
+        }
+    }
+}
+
 #[derive(Clone, Deserialize, Serialize, Debug, ToSchema)]
 pub struct FFStatusValue {
     pub config_uid: String,
@@ -15,4 +72,14 @@ pub struct FFStatusValue {
     pub cpu_usage: u32,
     pub memory_usage: u64,
     pub nb_restart: u32,
+    #[serde(default)]
+    pub active_input: u32,
+    #[serde(default)]
+    pub nb_input_switch: u32,
+    #[serde(default)]
+    pub last_input_switch: Option<InputSwitchReason>,
+    #[serde(default)]
+    pub loudness: Option<LoudnessValue>,
+    #[serde(default)]
+    pub field_order: Option<DetectedFieldOrder>,
 }
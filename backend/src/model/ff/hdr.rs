@@ -0,0 +1,111 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::new_io_error;
+
+use super::videoadaptationset::VideoEncoder;
+
+/// HDR static metadata, the metadata of the source is passed through if not set
+#[derive(Default, Clone, Deserialize, Serialize, Debug, ToSchema)]
+pub struct HdrMetadata {
+    /// SMPTE ST 2086 mastering display in the x265 syntax, chromaticities in
+    /// 0.00002 units and luminance in 0.0001 cd/m², ex:
+    /// G(13250,34500)B(7500,3000)R(34000,16000)WP(15635,16450)L(10000000,1)
+    pub mastering_display: Option<String>,
+    /// Maximum content light level, in cd/m²
+    pub max_cll: Option<u32>,
+    /// Maximum frame-average light level, in cd/m²
+    pub max_fall: Option<u32>,
+}
+
+impl HdrMetadata {
+    pub fn is_empty(&self) -> bool {
+        self.mastering_display.is_none() && self.max_cll.is_none() && self.max_fall.is_none()
+    }
+
+    pub fn validate(&self) -> std::io::Result<()> {
+        if let Some(mastering_display) = self.mastering_display.as_ref() {
+            Self::parse_mastering_display(mastering_display)?;
+        }
+        Ok(())
+    }
+
+    /// G(x,y)B(x,y)R(x,y)WP(x,y)L(max,min) => [Gx, Gy, Bx, By, Rx, Ry, WPx, WPy, Lmax, Lmin]
+    fn parse_mastering_display(mastering_display: &str) -> std::io::Result<[u32; 10]> {
+        let invalid = || {
+            new_io_error!(
+                std::io::ErrorKind::InvalidInput,
+                format!("Invalid mastering display: {}", mastering_display)
+            )
+        };
+
+        let mut values = [0; 10];
+        let mut remaining = mastering_display;
+        for (i, key) in ["G", "B", "R", "WP", "L"].iter().enumerate() {
+            let rest = remaining
+                .strip_prefix(key)
+                .and_then(|r| r.strip_prefix('('))
+                .ok_or_else(invalid)?;
+            let (pair, rest) = rest.split_once(')').ok_or_else(invalid)?;
+            let (first, second) = pair.split_once(',').ok_or_else(invalid)?;
+            values[2 * i] = first.trim().parse().map_err(|_| invalid())?;
+            values[2 * i + 1] = second.trim().parse().map_err(|_| invalid())?;
+            remaining = rest;
+        }
+
+        match remaining.is_empty() {
+            true => Ok(values),
+            false => Err(invalid()),
+        }
+    }
+
+    /// Private parameters of the software encoders
+    pub fn ff_params(&self, encoder: VideoEncoder, params: &mut Vec<String>) {
+        let content_light = (self.max_cll.is_some() || self.max_fall.is_some()).then(|| {
+            format!(
+                "{},{}",
+                self.max_cll.unwrap_or(0),
+                self.max_fall.unwrap_or(0)
+            )
+        });
+        let mastering_display = self
+            .mastering_display
+            .as_ref()
+            .and_then(|m| Self::parse_mastering_display(m).ok());
+
+        match encoder {
+            VideoEncoder::HEVC => {
+                if let Some(mastering_display) = self.mastering_display.as_ref() {
+                    params.push(format!("master-display={}", mastering_display));
+                }
+                if let Some(content_light) = content_light {
+                    params.push(format!("max-cll={}", content_light));
+                }
+            }
+            VideoEncoder::AV1 => {
+                if let Some(v) = mastering_display {
+                    let xy = |i: usize| {
+                        format!(
+                            "({:.4},{:.4})",
+                            v[i] as f64 / 50000.0,
+                            v[i + 1] as f64 / 50000.0
+                        )
+                    };
+                    params.push(format!(
+                        "mastering-display=G{}B{}R{}WP{}L({:.4},{:.4})",
+                        xy(0),
+                        xy(2),
+                        xy(4),
+                        xy(6),
+                        v[8] as f64 / 10000.0,
+                        v[9] as f64 / 10000.0
+                    ));
+                }
+                if let Some(content_light) = content_light {
+                    params.push(format!("content-light={}", content_light));
+                }
+            }
+            VideoEncoder::H264 => {}
+        }
+    }
+}
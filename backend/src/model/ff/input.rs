@@ -0,0 +1,626 @@
+use std::{fmt::Display, net::IpAddr};
+
+use serde::{Deserialize, Deserializer, Serialize};
+use utoipa::ToSchema;
+
+use crate::{new_io_error, utils};
+
+use super::{
+    probe::ProbeScope,
+    srt::{SRTMode, Srt, REDACTED},
+};
+
+/// Schemes of the network inputs which can be probed
+const PROBE_SCHEMES: [&str; 6] = ["srt", "rtmp", "rtmps", "rist", "http", "https"];
+
+#[derive(Clone, Deserialize, Serialize, Debug, ToSchema)]
+pub enum Input {
+    Srt(Srt),
+    Rtmp(RtmpInput),
+    Rist(RistInput),
+    Udp(UdpInput),
+    HttpPull(HttpPullInput),
+    File(FileInput),
+    Url(UrlInput),
+}
+
+impl Input {
+    pub fn validate(&self) -> std::io::Result<()> {
+        match self {
+            Input::Srt(srt) => srt.validate(),
+            Input::Rtmp(rtmp) => rtmp.validate(),
+            Input::Rist(rist) => rist.validate(),
+            Input::Udp(udp) => udp.validate(),
+            Input::HttpPull(http) => http.validate(),
+            Input::File(file) => file.validate(),
+            Input::Url(url) => url.validate(),
+        }
+    }
+
+    /// The probes only read the files of the media roots of the server, and the
+    /// network inputs of the allowed hosts in caller mode. The listener inputs would
+    /// take the connection of the sender and the UDP inputs would bind a port used
+    /// by an encoder.
+    pub fn validate_probe(&self, scope: &ProbeScope) -> std::io::Result<()> {
+        if self.is_listener() {
+            return Err(new_io_error!(
+                std::io::ErrorKind::PermissionDenied,
+                "The listener inputs can not be probed"
+            ));
+        }
+
+        let uri = match self {
+            Input::File(file) => return scope.validate_file(&file.path),
+            Input::Udp(_) => {
+                return Err(new_io_error!(
+                    std::io::ErrorKind::PermissionDenied,
+                    "The UDP and RTP inputs can not be probed"
+                ))
+            }
+            Input::Url(url) => match url::Url::parse(&url.uri) {
+                Err(_) => return scope.validate_file(&url.uri),
+                Ok(parsed) if parsed.scheme() == "file" => {
+                    return scope
+                        .validate_file(url.uri.split_once(':').map_or("", |(_, path)| path))
+                }
+                Ok(_) => &url.uri,
+            },
+            Input::Srt(srt) => &srt.uri,
+            Input::Rtmp(rtmp) => &rtmp.uri,
+            Input::Rist(rist) => &rist.uri,
+            Input::HttpPull(http) => &http.uri,
+        };
+
+        let url = parse_url(uri)?;
+        if !PROBE_SCHEMES.contains(&url.scheme()) {
+            return Err(new_io_error!(
+                std::io::ErrorKind::PermissionDenied,
+                format!("The {} protocol can not be probed", url.scheme())
+            ));
+        }
+        scope.validate_host(url.host_str())
+    }
+
+    /// The sender connects to this input, a probe would take its connection
+    pub fn is_listener(&self) -> bool {
+        match self {
+            Input::Srt(srt) => srt.mode != SRTMode::Caller,
+            Input::Rtmp(rtmp) => rtmp.mode == RtmpMode::Listen,
+            // ex: rist://@0.0.0.0:5000
+            Input::Rist(rist) => rist.uri.starts_with("rist://@"),
+            Input::Url(url) => url::Url::parse(&url.uri).is_ok_and(|url| {
+                url.query_pairs().any(|(key, value)| match key.as_ref() {
+                    "mode" => value != "caller",
+                    "listen" => value != "0",
+                    _ => false,
+                })
+            }),
+            Input::Udp(_) | Input::HttpPull(_) | Input::File(_) => false,
+        }
+    }
+
+    /// Push the ffmpeg reading options, the input options and `-i <url>`
+    pub fn ff_input(&self, args: &mut Vec<String>) {
+        match self {
+            Input::HttpPull(http) if http.realtime => {
+                args.push("-re".to_string());
+            }
+            Input::File(file) => {
+                args.push("-re".to_string());
+                if file.loop_input {
+                    args.push("-stream_loop".to_string());
+                    args.push("-1".to_string());
+                }
+            }
+            Input::Url(url) => {
+                if url.realtime {
+                    args.push("-re".to_string());
+                }
+                if url.loop_input {
+                    args.push("-stream_loop".to_string());
+                    args.push("-1".to_string());
+                }
+            }
+            _ => {}
+        }
+        self.ff_input_options(args);
+        args.push("-i".to_string());
+        args.push(self.url());
+    }
+
+    /// Protocol and demuxer options, shared by ffmpeg and ffprobe
+    pub fn ff_input_options(&self, args: &mut Vec<String>) {
+        match self {
+            Input::Rtmp(rtmp) => rtmp.ff_input_options(args),
+            Input::Rist(rist) => rist.ff_input_options(args),
+            Input::HttpPull(http) => http.ff_input_options(args),
+            Input::Srt(_) | Input::Udp(_) | Input::File(_) | Input::Url(_) => {}
+        }
+    }
+
+    pub fn url(&self) -> String {
+        match self {
+            Input::Srt(srt) => srt.to_string(),
+            Input::Rtmp(rtmp) => rtmp.uri.clone(),
+            Input::Rist(rist) => rist.uri.clone(),
+            Input::Udp(udp) => udp.url(),
+            Input::HttpPull(http) => http.uri.clone(),
+            Input::File(file) => file.path.clone(),
+            Input::Url(url) => url.uri.clone(),
+        }
+    }
+
+    /// Copy of this input without its secrets
+    pub fn redacted(&self) -> Self {
+        match self {
+            Input::Srt(srt) => Input::Srt(srt.redacted()),
+            Input::Rist(rist) => Input::Rist(rist.redacted()),
+            _ => self.clone(),
+        }
+    }
+
+    /// Put back the secrets of `stored` that have been redacted in this input,
+    /// if both inputs read the same URI
+    pub fn restore_secrets(&mut self, stored: &Input) {
+        match (self, stored) {
+            (Input::Srt(srt), Input::Srt(stored)) if srt.uri == stored.uri => {
+                srt.restore_secrets(stored)
+            }
+            (Input::Rist(rist), Input::Rist(stored)) if rist.uri == stored.uri => {
+                rist.restore_secrets(stored)
+            }
+            _ => {}
+        }
+    }
+
+    /// Hide the secrets of the input URLs printed by ffmpeg,
+    /// ex: Input #0, mpegts, from 'srt://host:9000?passphrase=...'
+    pub fn redact_log(line: &str) -> String {
+        let mut line = line.to_string();
+        for key in ["passphrase=", "secret="] {
+            let mut start = 0;
+            while let Some(pos) = line[start..].find(key) {
+                let value = start + pos + key.len();
+                let end = line[value..]
+                    .find(|c: char| c == '&' || c == '\'' || c == '"' || c.is_whitespace())
+                    .map_or(line.len(), |end| value + end);
+                line.replace_range(value..end, REDACTED);
+                start = value + REDACTED.len();
+            }
+        }
+        line
+    }
+}
+
+#[derive(Clone, Deserialize, Serialize, Debug, Copy, PartialEq, Eq, ToSchema)]
+pub enum RtmpMode {
+    Pull,
+    Listen,
+}
+
+#[derive(Clone, Deserialize, Serialize, Debug, ToSchema)]
+pub struct RtmpInput {
+    pub uri: String,
+    pub mode: RtmpMode,
+    /// Pull: maximum time without data, Listen: maximum time to wait for the publisher
+    pub timeout_ms: u64,
+}
+
+impl RtmpInput {
+    fn validate(&self) -> std::io::Result<()> {
+        let url = parse_url(&self.uri)?;
+        match (self.mode, url.scheme()) {
+            (RtmpMode::Pull, "rtmp" | "rtmps") | (RtmpMode::Listen, "rtmp") => Ok(()),
+            _ => Err(new_io_error!(
+                std::io::ErrorKind::InvalidInput,
+                format!(
+                    "Unsupported RTMP {:?} URI scheme: {}",
+                    self.mode,
+                    url.scheme()
+                )
+            )),
+        }
+    }
+
+    fn ff_input_options(&self, args: &mut Vec<String>) {
+        match self.mode {
+            RtmpMode::Pull => {
+                args.push("-rw_timeout".to_string());
+                args.push((self.timeout_ms * 1000).to_string());
+            }
+            RtmpMode::Listen => {
+                args.push("-listen".to_string());
+                args.push("1".to_string());
+                args.push("-timeout".to_string());
+                args.push(((self.timeout_ms + 999) / 1000).to_string());
+            }
+        }
+    }
+}
+
+#[derive(Clone, Deserialize, Serialize, Debug, Copy, PartialEq, Eq, ToSchema)]
+pub enum RistProfile {
+    Simple,
+    Main,
+    Advanced,
+}
+
+impl Display for RistProfile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RistProfile::Simple => write!(f, "simple"),
+            RistProfile::Main => write!(f, "main"),
+            RistProfile::Advanced => write!(f, "advanced"),
+        }
+    }
+}
+
+#[derive(Clone, Deserialize, Serialize, Debug, Copy, PartialEq, Eq, ToSchema)]
+pub enum RistEncryption {
+    Aes128,
+    Aes256,
+}
+
+impl Display for RistEncryption {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RistEncryption::Aes128 => write!(f, "128"),
+            RistEncryption::Aes256 => write!(f, "256"),
+        }
+    }
+}
+
+#[derive(Clone, Deserialize, Serialize, Debug, ToSchema)]
+pub struct RistInput {
+    pub uri: String,
+    pub profile: RistProfile,
+    /// Retransmission buffer in milliseconds
+    pub buffer_size_ms: Option<u32>,
+    pub encryption: Option<RistEncryption>,
+    pub secret: Option<String>,
+}
+
+impl RistInput {
+    fn validate(&self) -> std::io::Result<()> {
+        let url = parse_url(&self.uri)?;
+        if url.scheme() != "rist" {
+            return Err(new_io_error!(
+                std::io::ErrorKind::InvalidInput,
+                format!("Unsupported RIST URI scheme: {}", url.scheme())
+            ));
+        }
+
+        if self.encryption.is_some() != self.secret.as_ref().is_some_and(|s| !s.is_empty()) {
+            return Err(new_io_error!(
+                std::io::ErrorKind::InvalidInput,
+                "RIST encryption requires both a secret and a key size"
+            ));
+        }
+
+        Ok(())
+    }
+
+    fn ff_input_options(&self, args: &mut Vec<String>) {
+        args.push("-rist_profile".to_string());
+        args.push(self.profile.to_string());
+        if let Some(buffer_size_ms) = self.buffer_size_ms {
+            args.push("-buffer_size".to_string());
+            args.push(buffer_size_ms.to_string());
+        }
+        if let (Some(encryption), Some(secret)) = (self.encryption, self.secret.as_ref()) {
+            args.push("-encryption".to_string());
+            args.push(encryption.to_string());
+            args.push("-secret".to_string());
+            args.push(secret.clone());
+        }
+    }
+
+    fn redacted(&self) -> Self {
+        let mut rist = self.clone();
+        if rist.secret.is_some() {
+            rist.secret = Some(REDACTED.to_string());
+        }
+        rist
+    }
+
+    fn restore_secrets(&mut self, stored: &RistInput) {
+        if self.secret.as_deref() == Some(REDACTED) {
+            self.secret = stored.secret.clone();
+        }
+    }
+}
+
+#[derive(Clone, Deserialize, Serialize, Debug, Copy, PartialEq, Eq, ToSchema)]
+pub enum UdpProtocol {
+    Udp,
+    Rtp,
+}
+
+impl Display for UdpProtocol {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            UdpProtocol::Udp => write!(f, "udp"),
+            UdpProtocol::Rtp => write!(f, "rtp"),
+        }
+    }
+}
+
+#[derive(Clone, Deserialize, Serialize, Debug, ToSchema)]
+pub struct UdpInput {
+    pub protocol: UdpProtocol,
+    /// Multicast group or local unicast address
+    pub address: String,
+    pub port: u16,
+    /// IP address of the network interface used to join the multicast group
+    pub interface: Option<String>,
+    /// Source addresses for source-specific multicast
+    pub sources: Vec<String>,
+    /// Socket receive buffer size in bytes
+    pub buffer_size: Option<u32>,
+}
+
+impl UdpInput {
+    fn validate(&self) -> std::io::Result<()> {
+        let address = parse_ip(&self.address)?;
+        if self.port == 0 {
+            return Err(new_io_error!(
+                std::io::ErrorKind::InvalidInput,
+                "UDP port cannot be 0"
+            ));
+        }
+
+        if let Some(interface) = self.interface.as_ref() {
+            parse_ip(interface)?;
+        }
+
+        if !self.sources.is_empty() && !address.is_multicast() {
+            return Err(new_io_error!(
+                std::io::ErrorKind::InvalidInput,
+                "Source-specific multicast requires a multicast address"
+            ));
+        }
+
+        for source in &self.sources {
+            parse_ip(source)?;
+        }
+
+        Ok(())
+    }
+
+    fn url(&self) -> String {
+        let address = match parse_ip(&self.address) {
+            Ok(IpAddr::V6(address)) => format!("[{}]", address),
+            _ => self.address.clone(),
+        };
+        // ffmpeg does not url-decode the udp options, the values are validated IP addresses
+        let mut query = Vec::new();
+        if let Some(interface) = self.interface.as_ref() {
+            query.push(format!("localaddr={}", interface));
+        }
+        if !self.sources.is_empty() {
+            query.push(format!("sources={}", self.sources.join(",")));
+        }
+        if let Some(buffer_size) = self.buffer_size {
+            query.push(format!("buffer_size={}", buffer_size));
+        }
+
+        let url = format!("{}://{}:{}", self.protocol, address, self.port);
+        match query.is_empty() {
+            true => url,
+            false => format!("{}?{}", url, query.join("&")),
+        }
+    }
+}
+
+#[derive(Clone, Deserialize, Serialize, Debug, Copy, PartialEq, Eq, ToSchema)]
+pub enum PullFormat {
+    Auto,
+    Hls,
+    Dash,
+}
+
+#[derive(Clone, Deserialize, Serialize, Debug, ToSchema)]
+pub struct HttpPullInput {
+    pub uri: String,
+    pub format: PullFormat,
+    /// Read the input at its native frame rate
+    pub realtime: bool,
+}
+
+impl HttpPullInput {
+    fn validate(&self) -> std::io::Result<()> {
+        let url = parse_url(&self.uri)?;
+        match url.scheme() {
+            "http" | "https" => Ok(()),
+            scheme => Err(new_io_error!(
+                std::io::ErrorKind::InvalidInput,
+                format!("Unsupported HTTP URI scheme: {}", scheme)
+            )),
+        }
+    }
+
+    fn ff_input_options(&self, args: &mut Vec<String>) {
+        match self.format {
+            PullFormat::Auto => {}
+            PullFormat::Hls => {
+                args.push("-f".to_string());
+                args.push("hls".to_string());
+            }
+            PullFormat::Dash => {
+                args.push("-f".to_string());
+                args.push("dash".to_string());
+            }
+        }
+    }
+}
+
+#[derive(Clone, Deserialize, Serialize, Debug, ToSchema)]
+pub struct FileInput {
+    pub path: String,
+    pub loop_input: bool,
+}
+
+impl FileInput {
+    fn validate(&self) -> std::io::Result<()> {
+        utils::to_path_checked(&self.path).map(|_| ())
+    }
+}
+
+/// URL or path passed as is to ffmpeg, the legacy inputs which have no dedicated type
+#[derive(Clone, Deserialize, Serialize, Debug, ToSchema)]
+pub struct UrlInput {
+    pub uri: String,
+    /// Read the input at its native frame rate
+    pub realtime: bool,
+    pub loop_input: bool,
+}
+
+impl UrlInput {
+    fn validate(&self) -> std::io::Result<()> {
+        if self.uri.is_empty() {
+            return Err(new_io_error!(
+                std::io::ErrorKind::InvalidInput,
+                "Input URI is empty"
+            ));
+        }
+        Ok(())
+    }
+}
+
+fn parse_url(uri: &str) -> std::io::Result<url::Url> {
+    if uri.is_empty() {
+        return Err(new_io_error!(
+            std::io::ErrorKind::InvalidInput,
+            "Input URI is empty"
+        ));
+    }
+
+    url::Url::parse(uri).map_err(|e| {
+        new_io_error!(
+            std::io::ErrorKind::InvalidInput,
+            format!("Invalid input URI {}: {}", uri, e)
+        )
+    })
+}
+
+fn parse_ip(address: &str) -> std::io::Result<IpAddr> {
+    address.parse().map_err(|_| {
+        new_io_error!(
+            std::io::ErrorKind::InvalidInput,
+            format!("Invalid IP address: {}", address)
+        )
+    })
+}
+
+/// Configs stored before the introduction of `Input` only have an `Srt` carrying any kind of URI
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum InputValue {
+    Input(Input),
+    Legacy(Srt),
+}
+
+/// The legacy inputs keep the ffmpeg options they were started with: `-re` for
+/// the file and http(s) URLs, `-re -stream_loop -1` for the paths. They are
+/// passed as is to ffmpeg when they do not fit the validation of their type.
+impl From<InputValue> for Input {
+    fn from(value: InputValue) -> Self {
+        let srt = match value {
+            InputValue::Input(input) => return input,
+            InputValue::Legacy(srt) => srt,
+        };
+        if srt.uri.is_empty() {
+            return Input::Srt(srt);
+        }
+
+        let url = url::Url::parse(&srt.uri);
+        let passthrough = UrlInput {
+            uri: srt.uri.clone(),
+            realtime: url.as_ref().map_or(true, |url| {
+                ["file", "http", "https"].contains(&url.scheme())
+            }),
+            loop_input: url.is_err(),
+        };
+
+        let input = match url {
+            Err(_) => Input::File(FileInput {
+                path: srt.uri,
+                loop_input: true,
+            }),
+            Ok(url) => match url.scheme() {
+                "srt" => Input::Srt(srt),
+                "rtmp" | "rtmps" => Input::Rtmp(RtmpInput {
+                    uri: srt.uri,
+                    mode: RtmpMode::Pull,
+                    // no -rw_timeout
+                    timeout_ms: 0,
+                }),
+                "rist" => Input::Rist(RistInput {
+                    uri: srt.uri,
+                    profile: RistProfile::Main,
+                    buffer_size_ms: None,
+                    encryption: None,
+                    secret: None,
+                }),
+                // the other udp options are only kept by the URL
+                "udp" | "rtp"
+                    if url.query_pairs().all(|(key, _)| {
+                        ["localaddr", "sources", "buffer_size"].contains(&&*key)
+                    }) =>
+                {
+                    Input::Udp(UdpInput {
+                        protocol: match url.scheme() {
+                            "rtp" => UdpProtocol::Rtp,
+                            _ => UdpProtocol::Udp,
+                        },
+                        address: url
+                            .host_str()
+                            .unwrap_or_default()
+                            .trim_matches(['[', ']'])
+                            .to_string(),
+                        port: url.port().unwrap_or_default(),
+                        interface: url
+                            .query_pairs()
+                            .find(|(key, _)| key == "localaddr")
+                            .map(|(_, value)| value.into_owned()),
+                        sources: url
+                            .query_pairs()
+                            .find(|(key, _)| key == "sources")
+                            .map(|(_, value)| value.split(',').map(|s| s.to_string()).collect())
+                            .unwrap_or_default(),
+                        buffer_size: url
+                            .query_pairs()
+                            .find(|(key, _)| key == "buffer_size")
+                            .and_then(|(_, value)| value.parse().ok()),
+                    })
+                }
+                "http" | "https" => Input::HttpPull(HttpPullInput {
+                    format: PullFormat::Auto,
+                    uri: srt.uri,
+                    realtime: true,
+                }),
+                _ => Input::Url(passthrough.clone()),
+            },
+        };
+
+        match input.validate() {
+            Ok(()) => input,
+            Err(_) => Input::Url(passthrough),
+        }
+    }
+}
+
+pub fn deserialize_input<'de, D>(deserializer: D) -> Result<Input, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Ok(InputValue::deserialize(deserializer)?.into())
+}
+
+pub fn deserialize_inputs<'de, D>(deserializer: D) -> Result<Vec<Input>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let values = Vec::<InputValue>::deserialize(deserializer)?;
+    Ok(values.into_iter().map(Input::from).collect())
+}
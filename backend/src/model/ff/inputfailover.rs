@@ -0,0 +1,60 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// Rules used to switch between the primary input and the backup inputs.
+/// A value of 0 disables the corresponding rule.
+#[derive(Clone, Deserialize, Serialize, Debug, ToSchema)]
+#[serde(default)]
+pub struct InputFailover {
+    /// Switch to the next input when the encoder has not been running for this duration
+    pub input_timeout_ms: u64,
+    /// Switch to the next input after this number of consecutive "end of the stream" events
+    pub max_end_of_stream: u32,
+    /// Go back to the primary input once a backup input has been running for
+    /// `return_to_primary_delay_ms` and a probe of the primary input succeeds.
+    /// The probe is repeated after the same delay while the primary input is down.
+    pub return_to_primary: bool,
+    pub return_to_primary_delay_ms: u64,
+}
+
+impl Default for InputFailover {
+    fn default() -> Self {
+        Self {
+            input_timeout_ms: 10000,
+            max_end_of_stream: 3,
+            return_to_primary: true,
+            return_to_primary_delay_ms: 300000,
+        }
+    }
+}
+
+#[derive(Clone, Deserialize, Serialize, Debug, Copy, PartialEq, Eq, ToSchema)]
+#[repr(u8)]
+pub enum InputSwitchReason {
+    InputTimeout = 1,
+    EndOfStream,
+    ReturnToPrimary,
+}
+
+impl InputSwitchReason {
+    pub fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            1 => Some(InputSwitchReason::InputTimeout),
+            2 => Some(InputSwitchReason::EndOfStream),
+            3 => Some(InputSwitchReason::ReturnToPrimary),
+            _ => None,
+        }
+    }
+}
+
+impl Display for InputSwitchReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputSwitchReason::InputTimeout => write!(f, "input timeout"),
+            InputSwitchReason::EndOfStream => write!(f, "end of the stream"),
+            InputSwitchReason::ReturnToPrimary => write!(f, "return to primary"),
+        }
+    }
+}
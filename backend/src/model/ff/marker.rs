@@ -0,0 +1,276 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::{model::InConfigID, new_io_error};
+
+/// The key command of the marker is read by ffmpeg in a buffer of 4096 bytes
+const MAX_DATA_SIZE: usize = 1900;
+
+/// Maximum time to wait for a marker, the time it is kept for the server of the config
+pub const MAX_TIMEOUT_MS: u64 = 10_000;
+
+#[derive(Clone, Deserialize, Serialize, Debug, Copy, PartialEq, Eq, ToSchema)]
+pub enum MarkerType {
+    /// Start of an ad break, SCTE-35 splice_insert with out_of_network_indicator
+    SpliceOut,
+    /// End of an ad break, SCTE-35 splice_insert returning to the network
+    SpliceIn,
+    /// ID3 timed metadata
+    TimedMetadata,
+}
+
+impl Display for MarkerType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MarkerType::SpliceOut => write!(f, "splice out"),
+            MarkerType::SpliceIn => write!(f, "splice in"),
+            MarkerType::TimedMetadata => write!(f, "timed metadata"),
+        }
+    }
+}
+
+#[derive(Clone, Deserialize, Serialize, Debug, ToSchema)]
+pub struct InFFInsertMarker {
+    pub id: InConfigID,
+    pub marker_type: MarkerType,
+    /// Duration of the ad break or of the metadata, 0 if unknown
+    #[serde(default)]
+    pub duration_ms: u32,
+    /// Hexadecimal splice_info_section for the splices, generated if empty,
+    /// text of an ID3 TXXX frame for the timed metadata
+    #[serde(default)]
+    pub payload: String,
+    /// Time to wait for the marker to be inserted by the running encoder, 10 s at most
+    pub timeout_ms: u64,
+}
+
+#[derive(Clone, Deserialize, Serialize, Debug, ToSchema)]
+pub struct OutFFInsertMarker {
+    pub marker_uid: String,
+    /// Presentation time of the keyframe carrying the marker, in the timeline of the MPD
+    pub media_time_ms: i64,
+}
+
+/// Answer of the server of the config to a marker, read by the insert_marker API
+#[derive(Clone, Deserialize, Serialize, Debug)]
+pub struct FFMarkerAck {
+    pub marker_uid: String,
+    /// Time of the keyframe carrying the marker, or the reason it has not been inserted
+    pub result: Result<i64, String>,
+}
+
+impl FFMarkerAck {
+    pub fn rejected(marker_uid: &str, reason: &str) -> Self {
+        Self {
+            marker_uid: marker_uid.to_string(),
+            result: Err(reason.to_string()),
+        }
+    }
+
+    /// Line of the dash muxer patched by ffmpeg/7.0/experimental, the format is checked by
+    /// its check.sh, ex: [dash @ 0x55d0] [info] Marker 6f1c...e2 inserted at 123456 ms
+    pub fn parse_inserted(line: &str) -> Option<Self> {
+        let (_, marker) = line.split_once("Marker ")?;
+        let (marker_uid, time) = marker.split_once(" inserted at ")?;
+        let media_time_ms = time.strip_suffix(" ms")?.trim().parse().ok()?;
+        Some(Self {
+            marker_uid: marker_uid.to_string(),
+            result: Ok(media_time_ms),
+        })
+    }
+}
+
+/// Marker sent to the running encoder of a config, inserted at its next keyframe
+/// as an event of the MPD and an `EXT-X-DATERANGE` tag of the HLS media playlists
+#[derive(Clone, Deserialize, Serialize, Debug)]
+pub struct FFMarker {
+    pub uid: String,
+    pub config_uid: String,
+    pub marker_type: MarkerType,
+    pub duration_ms: u32,
+    /// splice_info_section or ID3 tag
+    pub data: Vec<u8>,
+}
+
+impl FFMarker {
+    pub fn new(marker: &InFFInsertMarker) -> std::io::Result<Self> {
+        let uid = uuid::Uuid::new_v4().to_string();
+        let data = match marker.marker_type {
+            MarkerType::SpliceOut | MarkerType::SpliceIn if marker.payload.is_empty() => {
+                Self::splice_insert(
+                    Self::event_id(&uid),
+                    marker.marker_type == MarkerType::SpliceOut,
+                    marker.duration_ms,
+                )
+            }
+            MarkerType::SpliceOut | MarkerType::SpliceIn => {
+                let data = Self::from_hex(&marker.payload)?;
+                if data.first() != Some(&0xFC) {
+                    return Err(new_io_error!(
+                        std::io::ErrorKind::InvalidInput,
+                        "The payload is not a splice_info_section"
+                    ));
+                }
+                data
+            }
+            MarkerType::TimedMetadata => Self::id3_tag(&marker.payload),
+        };
+        if data.len() > MAX_DATA_SIZE {
+            return Err(new_io_error!(
+                std::io::ErrorKind::InvalidInput,
+                format!("The payload exceeds {} bytes", MAX_DATA_SIZE)
+            ));
+        }
+
+        Ok(Self {
+            uid,
+            config_uid: marker.id.config_uid.clone(),
+            marker_type: marker.marker_type,
+            duration_ms: marker.duration_ms,
+            data,
+        })
+    }
+
+    fn scheme(&self) -> &'static str {
+        match self.marker_type {
+            MarkerType::SpliceOut | MarkerType::SpliceIn => "scte35",
+            MarkerType::TimedMetadata => "id3",
+        }
+    }
+
+    /// Line written to the standard input of ffmpeg to insert the marker
+    pub fn ff_command(&self) -> String {
+        let data: String = self.data.iter().map(|b| format!("{:02X}", b)).collect();
+        format!(
+            "M{} {} {} {}\n",
+            self.uid,
+            self.scheme(),
+            self.duration_ms,
+            data
+        )
+    }
+
+    /// Keyframe at the markers received on the standard input
+    pub fn ff_force_key_frames(index: usize, args: &mut Vec<String>) {
+        args.push(format!("-force_key_frames:v:{index}"));
+        args.push("markers".to_string());
+    }
+
+    fn from_hex(payload: &str) -> std::io::Result<Vec<u8>> {
+        let invalid = || {
+            new_io_error!(
+                std::io::ErrorKind::InvalidInput,
+                "The payload is not hexadecimal"
+            )
+        };
+        let payload = payload.trim_start_matches("0x");
+        if payload.is_empty() || payload.len() % 2 != 0 || !payload.is_ascii() {
+            return Err(invalid());
+        }
+        (0..payload.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&payload[i..i + 2], 16).map_err(|_| invalid()))
+            .collect()
+    }
+
+    /// splice_event_id taken from the marker uid
+    fn event_id(uid: &str) -> u32 {
+        u32::from_str_radix(&uid[..8], 16).unwrap_or(1)
+    }
+
+    /// splice_info_section of a splice_insert with the splice_immediate_flag, SCTE 35
+    fn splice_insert(event_id: u32, out_of_network: bool, duration_ms: u32) -> Vec<u8> {
+        let mut command = Vec::new();
+        command.extend_from_slice(&event_id.to_be_bytes());
+        // splice_event_cancel_indicator, reserved
+        command.push(0x7F);
+        // out_of_network_indicator, program_splice_flag, duration_flag, splice_immediate_flag, reserved
+        command.push(
+            (out_of_network as u8) << 7 | 0x40 | ((duration_ms > 0) as u8) << 5 | 0x10 | 0x0F,
+        );
+        if duration_ms > 0 {
+            // break_duration with auto_return, 90 kHz
+            let duration = duration_ms as u64 * 90;
+            command.push(0x80 | 0x7E | ((duration >> 32) & 0x01) as u8);
+            command.extend_from_slice(&(duration as u32).to_be_bytes());
+        }
+        // unique_program_id, avail_num, avails_expected
+        command.extend_from_slice(&[0x00, 0x01, 0x00, 0x00]);
+
+        let mut section = vec![
+            // protocol_version
+            0x00,
+            // encrypted_packet, encryption_algorithm, pts_adjustment
+            0x00,
+            0x00,
+            0x00,
+            0x00,
+            0x00,
+            // cw_index
+            0xFF,
+            // tier, splice_command_length
+            0xFF,
+            0xF0 | (command.len() >> 8) as u8,
+            command.len() as u8,
+            // splice_command_type
+            0x05,
+        ];
+        section.extend_from_slice(&command);
+        // descriptor_loop_length
+        section.extend_from_slice(&[0x00, 0x00]);
+
+        // section_length includes the CRC_32
+        let section_length = section.len() + 4;
+        let mut data = vec![
+            0xFC,
+            0x30 | (section_length >> 8) as u8,
+            section_length as u8,
+        ];
+        data.extend_from_slice(&section);
+        let crc = Self::crc32_mpeg2(&data);
+        data.extend_from_slice(&crc.to_be_bytes());
+        data
+    }
+
+    fn crc32_mpeg2(data: &[u8]) -> u32 {
+        let mut crc = 0xFFFFFFFFu32;
+        for byte in data {
+            crc ^= (*byte as u32) << 24;
+            for _ in 0..8 {
+                crc = match crc & 0x80000000 {
+                    0 => crc << 1,
+                    _ => (crc << 1) ^ 0x04C11DB7,
+                };
+            }
+        }
+        crc
+    }
+
+    /// ID3v2.4 tag with a TXXX frame carrying the text in UTF-8
+    fn id3_tag(text: &str) -> Vec<u8> {
+        let syncsafe = |size: usize| -> [u8; 4] {
+            [
+                (size >> 21) as u8 & 0x7F,
+                (size >> 14) as u8 & 0x7F,
+                (size >> 7) as u8 & 0x7F,
+                size as u8 & 0x7F,
+            ]
+        };
+
+        // text encoding UTF-8, empty description
+        let mut frame = vec![0x03, 0x00];
+        frame.extend_from_slice(text.as_bytes());
+
+        let mut tag = b"ID3".to_vec();
+        // version 2.4.0, flags
+        tag.extend_from_slice(&[0x04, 0x00, 0x00]);
+        tag.extend_from_slice(&syncsafe(frame.len() + 10));
+        tag.extend_from_slice(b"TXXX");
+        tag.extend_from_slice(&syncsafe(frame.len()));
+        tag.extend_from_slice(&[0x00, 0x00]);
+        tag.extend_from_slice(&frame);
+        tag
+    }
+}
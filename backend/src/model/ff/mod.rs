@@ -4,9 +4,20 @@ use utoipa::ToSchema;
 pub mod ffconfig;
 pub mod videoadaptationset;
 pub mod audioadaptationset;
+pub mod audiofilter;
 pub mod srt;
+pub mod input;
+pub mod streamselection;
+pub mod inputfailover;
 pub mod videofilter;
+pub mod hdr;
+pub mod closedcaptions;
+pub mod subtitle;
+pub mod scte35;
+pub mod marker;
+pub mod thumbnails;
 pub mod ffstatusvalue;
+pub mod probe;
 
 
 #[derive(Clone, Deserialize, Serialize, Debug, ToSchema)]
@@ -0,0 +1,145 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::{new_io_error, utils};
+
+use super::{input::Input, videofilter::VideoFilterCrop};
+
+/// Maximum time to probe an input, the crop detection may take as long again
+pub const MAX_TIMEOUT_MS: u64 = 30_000;
+
+#[derive(Clone, Deserialize, Serialize, Debug, ToSchema)]
+pub struct InFFProbe {
+    /// Server running ffprobe, the server of the config reading the input
+    pub server_uid: String,
+    pub input: Input,
+    /// 30 s at most
+    pub timeout_ms: u64,
+    /// Measure the black bars of the first video stream, takes up to another timeout
+    #[serde(default)]
+    pub detect_crop: bool,
+}
+
+impl InFFProbe {
+    pub fn validate(&self) -> std::io::Result<()> {
+        if self.timeout_ms == 0 {
+            return Err(new_io_error!(
+                std::io::ErrorKind::InvalidInput,
+                "The timeout must be greater than 0"
+            ));
+        }
+        self.input.validate()
+    }
+
+    pub fn timeout_ms(&self) -> u64 {
+        self.timeout_ms.min(MAX_TIMEOUT_MS)
+    }
+}
+
+/// Files and hosts which can be probed by a server, the probe API must not
+/// read the local files or reach the hosts of the network of the server
+#[derive(Clone, Debug, Default)]
+pub struct ProbeScope {
+    /// Directories of the files which can be probed
+    pub media_roots: Vec<std::path::PathBuf>,
+    /// Hosts of the network inputs which can be probed, "*" allows every host
+    pub hosts: Vec<String>,
+}
+
+impl ProbeScope {
+    pub fn validate_file(&self, path: &str) -> std::io::Result<()> {
+        // the symbolic links are resolved before the check
+        let path = utils::to_path_checked(path)?.canonicalize()?;
+        let in_media_root = self
+            .media_roots
+            .iter()
+            .filter_map(|root| root.canonicalize().ok())
+            .any(|root| path.starts_with(root));
+        if !in_media_root {
+            return Err(new_io_error!(
+                std::io::ErrorKind::PermissionDenied,
+                "The file is not in a media root of the server"
+            ));
+        }
+        Ok(())
+    }
+
+    pub fn validate_host(&self, host: Option<&str>) -> std::io::Result<()> {
+        let allowed = host.is_some_and(|host| {
+            self.hosts
+                .iter()
+                .any(|allowed| allowed == "*" || allowed.eq_ignore_ascii_case(host))
+        });
+        if !allowed {
+            return Err(new_io_error!(
+                std::io::ErrorKind::PermissionDenied,
+                format!(
+                    "The host {} is not allowed to be probed by the server",
+                    host.unwrap_or_default()
+                )
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Probe sent to the server of the config, see FFEvent
+#[derive(Clone, Deserialize, Serialize, Debug)]
+pub struct FFProbeRequest {
+    pub uid: String,
+    pub probe: InFFProbe,
+}
+
+/// Answer of the server of the config to a probe, read by the probe API
+#[derive(Clone, Deserialize, Serialize, Debug)]
+pub struct FFProbeAck {
+    pub uid: String,
+    pub result: Result<OutFFProbe, String>,
+}
+
+#[derive(Clone, Deserialize, Serialize, Debug, ToSchema)]
+pub struct ProbeProgram {
+    pub program_num: u32,
+    pub pmt_pid: Option<u32>,
+    pub pcr_pid: Option<u32>,
+    pub service_name: Option<String>,
+    pub service_provider: Option<String>,
+    /// Index of the streams carried by this program
+    pub streams: Vec<u32>,
+}
+
+#[derive(Clone, Deserialize, Serialize, Debug, ToSchema)]
+pub struct ProbeStream {
+    pub index: u32,
+    /// MPEG-TS PID
+    pub pid: Option<u32>,
+    /// video, audio, subtitle or data
+    pub codec_type: String,
+    pub codec_name: Option<String>,
+    pub profile: Option<String>,
+    pub bitrate: Option<u64>,
+    pub width: Option<u32>,
+    pub height: Option<u32>,
+    pub pix_fmt: Option<String>,
+    /// Frame rate of the stream, in frames per second for interlaced streams
+    pub framerate_num: Option<u32>,
+    pub framerate_den: Option<u32>,
+    /// progressive, tt, bb, tb or bt
+    pub field_order: Option<String>,
+    pub interlaced: bool,
+    pub color_primaries: Option<String>,
+    pub color_transfer: Option<String>,
+    pub language: Option<String>,
+    pub sample_rate: Option<u32>,
+    pub channels: Option<u32>,
+    pub channel_layout: Option<String>,
+}
+
+#[derive(Clone, Deserialize, Serialize, Debug, ToSchema)]
+pub struct OutFFProbe {
+    pub format_name: Option<String>,
+    pub programs: Vec<ProbeProgram>,
+    pub streams: Vec<ProbeStream>,
+    /// Crop removing the black bars of the first video stream, if requested
+    pub crop: Option<VideoFilterCrop>,
+}
@@ -0,0 +1,85 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::new_io_error;
+
+use super::{
+    input::Input,
+    streamselection::{StreamSelection, StreamSpecifier},
+};
+
+/// Tags of the splice points in the HLS media playlists
+#[derive(Default, Clone, Deserialize, Serialize, Debug, Copy, PartialEq, Eq, ToSchema)]
+pub enum Scte35HlsTags {
+    /// `EXT-X-DATERANGE` with the SCTE35-OUT/SCTE35-IN attributes
+    #[default]
+    DateRange,
+    /// `EXT-X-CUE-OUT` / `EXT-X-CUE-IN`
+    CueOut,
+}
+
+impl Display for Scte35HlsTags {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Scte35HlsTags::DateRange => write!(f, "daterange"),
+            Scte35HlsTags::CueOut => write!(f, "cue_out"),
+        }
+    }
+}
+
+/// Passthrough of the SCTE-35 cues of the MPEG-TS input, written as an
+/// `EventStream` of the MPD and as tags of the HLS media playlists.
+/// A keyframe is forced at every splice point in all video representations,
+/// so that a new segment starts at the splice point.
+#[derive(Clone, Deserialize, Serialize, Debug, ToSchema)]
+pub struct Scte35 {
+    /// Data stream carrying the cues, the first data stream by default
+    #[serde(default)]
+    pub source: Option<StreamSpecifier>,
+    /// Tags of the splice points in the HLS media playlists
+    #[serde(default)]
+    pub hls_tags: Scte35HlsTags,
+}
+
+impl Scte35 {
+    pub fn validate(&self, inputs: &[&Input]) -> std::io::Result<()> {
+        match self.source.as_ref() {
+            Some(StreamSpecifier::Language(_)) => {
+                return Err(new_io_error!(
+                    std::io::ErrorKind::InvalidInput,
+                    "The SCTE-35 stream is selected by index or PID"
+                ))
+            }
+            Some(source) => source.validate()?,
+            None => {}
+        }
+        if inputs.iter().any(|input| matches!(input, Input::Rtmp(_))) {
+            return Err(new_io_error!(
+                std::io::ErrorKind::InvalidInput,
+                "SCTE-35 cues are only carried by MPEG-TS inputs"
+            ));
+        }
+        Ok(())
+    }
+
+    /// Keyframe at the splice points of the cues
+    pub fn ff_force_key_frames(index: usize, args: &mut Vec<String>) {
+        args.push(format!("-force_key_frames:v:{index}"));
+        args.push("scte35".to_string());
+    }
+
+    /// The cues are passed to the dash muxer as a data stream
+    pub fn ff_map(&self, selection: &StreamSelection, args: &mut Vec<String>) {
+        args.push("-map".to_string());
+        args.push(selection.ff_data(self.source.as_ref()));
+        args.push("-c:d".to_string());
+        args.push("copy".to_string());
+    }
+
+    pub fn ff_hls_tags(&self, args: &mut Vec<String>) {
+        args.push("-scte35_hls_tags".to_string());
+        args.push(self.hls_tags.to_string());
+    }
+}
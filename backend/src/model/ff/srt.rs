@@ -3,6 +3,11 @@ use std::fmt::Display;
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
+use crate::new_io_error;
+
+/// Value returned in place of a secret when a config is sent back to a client
+pub const REDACTED: &str = "********";
+
 #[derive(Clone, Deserialize, Serialize, Debug, Copy, PartialEq, Eq,ToSchema)]
 pub enum SRTMode {
     Caller,
@@ -20,11 +25,42 @@ impl Display for SRTMode {
     }
 }
 
+#[derive(Clone, Deserialize, Serialize, Debug, Copy, PartialEq, Eq, ToSchema)]
+pub enum SRTKeyLength {
+    Aes128,
+    Aes192,
+    Aes256,
+}
+
+impl Display for SRTKeyLength {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SRTKeyLength::Aes128 => write!(f, "16"),
+            SRTKeyLength::Aes192 => write!(f, "24"),
+            SRTKeyLength::Aes256 => write!(f, "32"),
+        }
+    }
+}
+
 #[derive(Clone, Deserialize, Serialize, Debug, ToSchema)]
 pub struct Srt {
     pub uri: String,
     pub mode: SRTMode,
     pub connect_timeout_ms: u64,
+    /// Encryption passphrase, 10 to 79 characters
+    #[serde(default)]
+    pub passphrase: Option<String>,
+    #[serde(default)]
+    pub pbkeylen: Option<SRTKeyLength>,
+    #[serde(default)]
+    pub latency_ms: Option<u32>,
+    #[serde(default)]
+    pub rcvlatency_ms: Option<u32>,
+    #[serde(default)]
+    pub streamid: Option<String>,
+    /// Maximum bandwidth in bytes per second
+    #[serde(default)]
+    pub maxbw: Option<u64>,
 }
 
 impl Srt {
@@ -33,6 +69,65 @@ impl Srt {
             uri: String::new(),
             mode: SRTMode::Caller,
             connect_timeout_ms: 5000,
+            passphrase: None,
+            pbkeylen: None,
+            latency_ms: None,
+            rcvlatency_ms: None,
+            streamid: None,
+            maxbw: None,
+        }
+    }
+
+    pub fn validate(&self) -> std::io::Result<()> {
+        if self.uri.is_empty() {
+            return Err(new_io_error!(
+                std::io::ErrorKind::InvalidInput,
+                "Input URI is empty"
+            ));
+        }
+
+        match url::Url::parse(&self.uri) {
+            Ok(url) if url.scheme() == "srt" => {}
+            _ => {
+                return Err(new_io_error!(
+                    std::io::ErrorKind::InvalidInput,
+                    format!("Invalid SRT URI: {}", self.uri)
+                ));
+            }
+        }
+
+        if let Some(passphrase) = self.passphrase.as_ref() {
+            if passphrase.len() < 10 || passphrase.len() > 79 {
+                return Err(new_io_error!(
+                    std::io::ErrorKind::InvalidInput,
+                    "SRT passphrase must be between 10 and 79 characters"
+                ));
+            }
+        }
+
+        if self.pbkeylen.is_some() && self.passphrase.is_none() {
+            return Err(new_io_error!(
+                std::io::ErrorKind::InvalidInput,
+                "SRT pbkeylen requires a passphrase"
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Copy of this input without its secrets
+    pub fn redacted(&self) -> Self {
+        let mut srt = self.clone();
+        if srt.passphrase.is_some() {
+            srt.passphrase = Some(REDACTED.to_string());
+        }
+        srt
+    }
+
+    /// Put back the secrets of `stored` that have been redacted in this input
+    pub fn restore_secrets(&mut self, stored: &Srt) {
+        if self.passphrase.as_deref() == Some(REDACTED) {
+            self.passphrase = stored.passphrase.clone();
         }
     }
 }
@@ -41,12 +136,34 @@ impl Display for Srt {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         if let Ok(mut url) = url::Url::parse(&self.uri) {
             if url.scheme() == "srt" {
-                url.query_pairs_mut()
+                let mut query = url.query_pairs_mut();
+                query
                     .append_pair("mode", &self.mode.to_string())
                     .append_pair(
                         "connect_timeout",
                         self.connect_timeout_ms.to_string().as_str(),
                     );
+
+                // ffmpeg expects latencies in microseconds
+                if let Some(latency_ms) = self.latency_ms {
+                    query.append_pair("latency", &(latency_ms as u64 * 1000).to_string());
+                }
+                if let Some(rcvlatency_ms) = self.rcvlatency_ms {
+                    query.append_pair("rcvlatency", &(rcvlatency_ms as u64 * 1000).to_string());
+                }
+                if let Some(passphrase) = self.passphrase.as_ref() {
+                    query.append_pair("passphrase", passphrase);
+                }
+                if let Some(pbkeylen) = self.pbkeylen {
+                    query.append_pair("pbkeylen", &pbkeylen.to_string());
+                }
+                if let Some(streamid) = self.streamid.as_ref() {
+                    query.append_pair("streamid", streamid);
+                }
+                if let Some(maxbw) = self.maxbw {
+                    query.append_pair("maxbw", &maxbw.to_string());
+                }
+                drop(query);
                 return write!(f, "{}", url.as_str());
             }
         }
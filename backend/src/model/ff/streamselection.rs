@@ -0,0 +1,115 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::new_io_error;
+
+#[derive(Clone, Deserialize, Serialize, Debug, PartialEq, Eq, ToSchema)]
+pub enum StreamSpecifier {
+    /// Nth stream of this type, starting at 0
+    Index(u32),
+    /// MPEG-TS PID
+    Pid(u32),
+    /// ISO-639 language code. The filter graph uses the first matching stream,
+    /// `-map` maps every matching stream.
+    Language(String),
+}
+
+impl StreamSpecifier {
+    pub fn validate(&self) -> std::io::Result<()> {
+        match self {
+            StreamSpecifier::Index(_) => Ok(()),
+            StreamSpecifier::Pid(pid) if *pid <= 0x1FFF => Ok(()),
+            StreamSpecifier::Pid(pid) => Err(new_io_error!(
+                std::io::ErrorKind::InvalidInput,
+                format!("Invalid PID: {}", pid)
+            )),
+            StreamSpecifier::Language(language) => validate_language(language),
+        }
+    }
+
+    /// ffmpeg stream specifier, `stream_type` is "v", "a", "s" or "d"
+    fn ff_specifier(&self, stream_type: &str) -> String {
+        match self {
+            StreamSpecifier::Index(index) => format!("{}:{}", stream_type, index),
+            StreamSpecifier::Pid(pid) => format!("i:{:#x}", pid),
+            StreamSpecifier::Language(language) => {
+                format!("{}:m:language:{}", stream_type, language)
+            }
+        }
+    }
+}
+
+/// Check a lowercase ISO-639-1 or ISO-639-2 language code
+pub fn validate_language(language: &str) -> std::io::Result<()> {
+    match (2..=3).contains(&language.len()) && language.chars().all(|c| c.is_ascii_lowercase()) {
+        true => Ok(()),
+        false => Err(new_io_error!(
+            std::io::ErrorKind::InvalidInput,
+            format!("Invalid ISO-639 language code: {}", language)
+        )),
+    }
+}
+
+/// Streams of the input used by the encoder, the first video and audio
+/// streams of the input are used by default
+#[derive(Clone, Deserialize, Serialize, Debug, Default, ToSchema)]
+pub struct StreamSelection {
+    /// Program number of a multi-program transport stream
+    pub program: Option<u32>,
+    pub video: Option<StreamSpecifier>,
+    pub audio: Option<StreamSpecifier>,
+}
+
+impl StreamSelection {
+    pub fn validate(&self) -> std::io::Result<()> {
+        if let Some(video) = self.video.as_ref() {
+            video.validate()?;
+        }
+        if let Some(audio) = self.audio.as_ref() {
+            audio.validate()?;
+        }
+        Ok(())
+    }
+
+    pub fn ff_video(&self) -> String {
+        self.ff_stream("v", self.video.as_ref())
+    }
+
+    /// `source` overrides the default audio stream
+    pub fn ff_audio(&self, source: Option<&StreamSpecifier>) -> String {
+        self.ff_stream("a", source.or(self.audio.as_ref()))
+    }
+
+    /// Stream specifier of the audio stream for ffprobe `-select_streams`
+    pub fn ff_audio_specifier(&self, source: Option<&StreamSpecifier>) -> String {
+        self.ff_specifier("a", source.or(self.audio.as_ref()))
+    }
+
+    /// `source` overrides the first data stream
+    pub fn ff_data(&self, source: Option<&StreamSpecifier>) -> String {
+        self.ff_stream("d", source)
+    }
+
+    pub fn ff_subtitle(&self, source: &StreamSpecifier) -> String {
+        self.ff_stream("s", Some(source))
+    }
+
+    /// Stream specifier of the per-stream options of the input
+    pub fn ff_subtitle_specifier(&self, source: &StreamSpecifier) -> String {
+        self.ff_specifier("s", Some(source))
+    }
+
+    fn ff_stream(&self, stream_type: &str, specifier: Option<&StreamSpecifier>) -> String {
+        format!("0:{}", self.ff_specifier(stream_type, specifier))
+    }
+
+    fn ff_specifier(&self, stream_type: &str, specifier: Option<&StreamSpecifier>) -> String {
+        let specifier = specifier
+            .map(|s| s.ff_specifier(stream_type))
+            .unwrap_or_else(|| format!("{}:0", stream_type));
+        match self.program {
+            Some(program) => format!("p:{}:{}", program, specifier),
+            None => specifier,
+        }
+    }
+}
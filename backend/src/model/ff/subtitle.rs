@@ -0,0 +1,188 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::new_io_error;
+
+use super::{
+    ffconfig::Acceleration,
+    streamselection::{self, StreamSelection, StreamSpecifier},
+    videofilter,
+};
+
+#[derive(Clone, Deserialize, Serialize, Debug, PartialEq, Eq, ToSchema)]
+pub enum SubtitleSource {
+    /// DVB teletext, decoded with libzvbi
+    Teletext { page: u32 },
+    /// DVB bitmap subtitles
+    DvbSubtitle,
+}
+
+#[derive(Clone, Deserialize, Serialize, Debug, Copy, PartialEq, Eq, ToSchema)]
+pub enum SubtitleFormat {
+    /// WebVTT in fragmented MP4
+    WebVtt,
+    /// IMSC1 text profile (TTML) in fragmented MP4
+    Imsc1,
+    /// Burned into every video representation
+    BurnIn,
+}
+
+impl Display for SubtitleFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SubtitleFormat::WebVtt => write!(f, "webvtt"),
+            SubtitleFormat::Imsc1 => write!(f, "ttml"),
+            SubtitleFormat::BurnIn => write!(f, "burn-in"),
+        }
+    }
+}
+
+/// Subtitles of the source, each text track has its own adaptation set in the
+/// MPD and its own rendition in the SUBTITLES group of the HLS playlist
+#[derive(Clone, Deserialize, Serialize, Debug, ToSchema)]
+pub struct SubtitleTrack {
+    pub source: StreamSpecifier,
+    pub kind: SubtitleSource,
+    /// ISO-639 language code
+    pub language: String,
+    pub format: SubtitleFormat,
+}
+
+impl SubtitleTrack {
+    pub fn validate(&self) -> std::io::Result<()> {
+        self.source.validate()?;
+        streamselection::validate_language(&self.language)?;
+        match (&self.kind, self.format) {
+            (SubtitleSource::Teletext { page }, _) if !(100..=899).contains(page) => {
+                Err(new_io_error!(
+                    std::io::ErrorKind::InvalidInput,
+                    format!("Invalid teletext page: {}", page)
+                ))
+            }
+            (SubtitleSource::Teletext { .. }, SubtitleFormat::BurnIn) => Err(new_io_error!(
+                std::io::ErrorKind::InvalidInput,
+                "Teletext subtitles can not be burned in"
+            )),
+            (SubtitleSource::DvbSubtitle, SubtitleFormat::WebVtt | SubtitleFormat::Imsc1) => {
+                Err(new_io_error!(
+                    std::io::ErrorKind::InvalidInput,
+                    "DVB subtitles are bitmaps, they can only be burned in"
+                ))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn is_text(&self) -> bool {
+        self.format != SubtitleFormat::BurnIn
+    }
+
+    /// A teletext stream is decoded once, with a single page
+    pub fn validate_tracks(tracks: &[SubtitleTrack]) -> std::io::Result<()> {
+        for (i, track) in tracks.iter().enumerate() {
+            track.validate()?;
+            if let SubtitleSource::Teletext { .. } = track.kind {
+                if tracks[..i].iter().any(|t| t.source == track.source) {
+                    return Err(new_io_error!(
+                        std::io::ErrorKind::InvalidInput,
+                        "Several teletext pages of the same stream"
+                    ));
+                }
+            }
+        }
+        if tracks.iter().filter(|t| !t.is_text()).count() > 1 {
+            return Err(new_io_error!(
+                std::io::ErrorKind::InvalidInput,
+                "Only one subtitle track can be burned in"
+            ));
+        }
+        Ok(())
+    }
+
+    /// Options of the teletext decoders, before the input
+    pub fn ff_decoder_options(
+        selection: &StreamSelection,
+        tracks: &[SubtitleTrack],
+        args: &mut Vec<String>,
+    ) {
+        let mut teletext = false;
+        for track in tracks.iter() {
+            if let SubtitleSource::Teletext { page } = track.kind {
+                args.push(format!(
+                    "-txt_page:{}",
+                    selection.ff_subtitle_specifier(&track.source)
+                ));
+                args.push(page.to_string());
+                teletext = true;
+            }
+        }
+        if teletext {
+            args.push("-txt_format".to_string());
+            args.push("text".to_string());
+            args.push("-fix_sub_duration".to_string());
+        }
+    }
+
+    /// Overlay of the burned in subtitles on the source
+    pub fn ff_burn_in(
+        selection: &StreamSelection,
+        tracks: &[SubtitleTrack],
+        acceleration: Acceleration,
+        ten_bit: bool,
+    ) -> Option<String> {
+        let track = tracks.iter().find(|t| !t.is_text())?;
+        Some(videofilter::ff_hw_roundtrip(
+            acceleration,
+            ten_bit,
+            &format!(
+                "null[vsub];[vsub][{}]overlay=eof_action=pass",
+                selection.ff_subtitle(&track.source)
+            ),
+        ))
+    }
+
+    /// Text tracks are cut at the keyframes of the first video stream
+    pub fn ff_subtitle_encode(tracks: &[SubtitleTrack], args: &mut Vec<String>) {
+        let mut index = 0;
+        for track in tracks.iter().filter(|t| t.is_text()) {
+            args.push(format!("-c:s:{index}"));
+            args.push(track.format.to_string());
+            args.push(format!("-metadata:s:s:{index}"));
+            args.push(format!("language={}", track.language));
+            index += 1;
+        }
+        if index > 0 {
+            args.push("-fix_sub_duration_heartbeat:v:0".to_string());
+            args.push("1".to_string());
+        }
+    }
+
+    pub fn ff_map(selection: &StreamSelection, tracks: &[SubtitleTrack], args: &mut Vec<String>) {
+        for track in tracks.iter().filter(|t| t.is_text()) {
+            args.push("-map".to_string());
+            args.push(selection.ff_subtitle(&track.source));
+        }
+    }
+
+    /// `first_id` is the id of the first subtitle adaptation set and `first_stream`
+    /// the index of the first subtitle output stream
+    pub fn ff_adaptation_sets(
+        segment_duration: f64,
+        first_id: usize,
+        first_stream: usize,
+        tracks: &[SubtitleTrack],
+    ) -> String {
+        let mut adaptation_sets = String::new();
+        for (i, _) in tracks.iter().filter(|t| t.is_text()).enumerate() {
+            adaptation_sets.push_str(&format!(
+                "id={},seg_duration={},streams={} ",
+                first_id + i,
+                segment_duration,
+                first_stream + i
+            ));
+        }
+        adaptation_sets
+    }
+}
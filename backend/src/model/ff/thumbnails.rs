@@ -0,0 +1,114 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::new_io_error;
+
+use super::ffconfig::Acceleration;
+
+/// Trick-play thumbnails, written next to the segments as JPEG tiles of
+/// `columns` x `rows` thumbnails and signalled in the MPD and the HLS playlist
+#[derive(Clone, Deserialize, Serialize, Debug, ToSchema)]
+pub struct Thumbnails {
+    /// Thumbnails per row of a tile, from 1 to 20
+    pub columns: u32,
+    /// Thumbnails per column of a tile, from 1 to 20
+    pub rows: u32,
+    /// Interval between two thumbnails, in ms
+    pub interval_ms: u32,
+    /// Width of a thumbnail, in pixels, even
+    pub width: u32,
+    /// Height of a thumbnail, in pixels, even
+    pub height: u32,
+}
+
+impl Thumbnails {
+    pub fn validate(&self) -> std::io::Result<()> {
+        if !(1..=20).contains(&self.columns) || !(1..=20).contains(&self.rows) {
+            return Err(new_io_error!(
+                std::io::ErrorKind::InvalidInput,
+                "Thumbnail tiles must have between 1 and 20 columns and rows"
+            ));
+        }
+        if self.interval_ms < 1000 {
+            return Err(new_io_error!(
+                std::io::ErrorKind::InvalidInput,
+                "Thumbnail interval must be at least 1000 ms"
+            ));
+        }
+        if self.width == 0 || self.height == 0 || self.width % 2 != 0 || self.height % 2 != 0 {
+            return Err(new_io_error!(
+                std::io::ErrorKind::InvalidInput,
+                "Thumbnail width and height must be even and greater than 0"
+            ));
+        }
+        Ok(())
+    }
+
+    /// Filter chain from the source to the tiles, the thumbnails are scaled
+    /// before the download of the hardware frames
+    pub fn ff_filter(&self, acceleration: Acceleration) -> String {
+        let scale = match acceleration {
+            Acceleration::Vaapi => format!(
+                "scale_vaapi=w={}:h={}:format=nv12,hwdownload,format=nv12",
+                self.width, self.height
+            ),
+            Acceleration::Cuda => format!(
+                "scale_cuda=w={}:h={}:format=nv12,hwdownload,format=nv12",
+                self.width, self.height
+            ),
+            Acceleration::Software => format!("scale=w={}:h={}", self.width, self.height),
+        };
+        format!(
+            "fps=1000/{},{},tile={}x{},format=yuvj420p",
+            self.interval_ms, scale, self.columns, self.rows
+        )
+    }
+
+    /// Signal the tiles in the MPD and the HLS playlist
+    pub fn ff_dash_options(&self, args: &mut Vec<String>) {
+        args.push("-thumbnail_duration".to_string());
+        args.push(format!("{}ms", self.interval_ms));
+        args.push("-thumbnail_tiles".to_string());
+        args.push(format!("{}x{}", self.columns, self.rows));
+        args.push("-thumbnail_size".to_string());
+        args.push(format!("{}x{}", self.width, self.height));
+    }
+
+    /// Output of the tiles, `label` is the output of the filter chain
+    pub fn ff_output(&self, label: &str, output: &std::path::Path, args: &mut Vec<String>) {
+        args.push("-map".to_string());
+        args.push(format!("[{label}]"));
+        args.push("-fps_mode:v".to_string());
+        args.push("passthrough".to_string());
+        args.push("-c:v".to_string());
+        args.push("mjpeg".to_string());
+        args.push("-q:v".to_string());
+        args.push("5".to_string());
+        args.push("-f".to_string());
+        args.push("image2".to_string());
+        args.push("-start_number".to_string());
+        args.push("1".to_string());
+        args.push(
+            output
+                .join("thumbnail_%05d.jpg")
+                .to_string_lossy()
+                .into_owned(),
+        );
+    }
+
+    /// The tiles are written by the image2 muxer, they are not removed with the segments
+    pub fn remove_tiles(output: &std::path::Path) {
+        let Ok(entries) = std::fs::read_dir(output) else {
+            return;
+        };
+        for entry in entries.flatten() {
+            let name = entry.file_name();
+            let name = name.to_string_lossy();
+            if name.starts_with("thumbnail_") && name.ends_with(".jpg") {
+                if let Err(e) = std::fs::remove_file(entry.path()) {
+                    tracing::warn!("Fail to remove {}: {}", name, e);
+                }
+            }
+        }
+    }
+}
@@ -4,35 +4,90 @@ use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
 use crate::model::server::Gpu;
+use crate::new_io_error;
 
 use super::{
+    closedcaptions::ClosedCaptions,
     ffconfig::{Acceleration, FFConfig},
-    videofilter::VideoFilterDrawText,
+    marker::FFMarker,
+    scte35::Scte35,
+    videofilter::{
+        self, DrawTextContext, ScaleMode, Tonemap, VideoFilterCrop, VideoFilterDrawText,
+        VideoFilterOverlay,
+    },
 };
 
 #[derive(Clone, Deserialize, Serialize, Debug, PartialEq, Copy, ToSchema)]
 pub enum Colorspace {
     Auto,
     BT709,
+    /// HDR10, BT.2020 primaries with the PQ transfer
+    BT2020PQ,
+    /// BT.2020 primaries with the HLG transfer
+    BT2020HLG,
+}
+
+impl Colorspace {
+    pub fn is_hdr(&self) -> bool {
+        matches!(self, Colorspace::BT2020PQ | Colorspace::BT2020HLG)
+    }
+
+    pub fn primaries(&self) -> &str {
+        match self {
+            Colorspace::BT2020PQ | Colorspace::BT2020HLG => "bt2020",
+            _ => "bt709",
+        }
+    }
+
+    pub fn transfer(&self) -> &str {
+        match self {
+            Colorspace::BT2020PQ => "smpte2084",
+            Colorspace::BT2020HLG => "arib-std-b67",
+            _ => "bt709",
+        }
+    }
+
+    pub fn matrix(&self) -> &str {
+        match self {
+            Colorspace::BT2020PQ | Colorspace::BT2020HLG => "bt2020nc",
+            _ => "bt709",
+        }
+    }
+
+    pub fn ff_color(&self, index: usize, args: &mut Vec<String>) {
+        if *self == Colorspace::Auto {
+            return;
+        }
+        args.push(format!("-color_primaries:v:{index}"));
+        args.push(self.primaries().to_string());
+        args.push(format!("-color_trc:v:{index}"));
+        args.push(self.transfer().to_string());
+        args.push(format!("-colorspace:v:{index}"));
+        args.push(self.matrix().to_string());
+    }
 }
 
 impl Display for Colorspace {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Colorspace::BT709 => write!(f, "bt709"),
+            Colorspace::BT2020PQ => write!(f, "bt2020-pq"),
+            Colorspace::BT2020HLG => write!(f, "bt2020-hlg"),
             Colorspace::Auto => write!(f, "auto"),
         }
     }
 }
 
+#[allow(clippy::upper_case_acronyms)]
 #[derive(Clone, Deserialize, Serialize, Debug, Copy, PartialEq, ToSchema)]
 pub enum VideoEncoder {
     H264,
     HEVC,
+    AV1,
 }
 
 impl VideoEncoder {
-    pub fn to_string(&self, acceleration: Acceleration) -> &str {
+    pub fn to_string(self, acceleration: Acceleration) -> &'static str {
         match self {
             VideoEncoder::H264 => match acceleration {
                 Acceleration::Software => "libx264",
@@ -44,6 +99,20 @@ impl VideoEncoder {
                 Acceleration::Vaapi => "hevc_vaapi",
                 Acceleration::Cuda => "hevc_nvenc",
             },
+            VideoEncoder::AV1 => match acceleration {
+                Acceleration::Software => "libsvtav1",
+                Acceleration::Vaapi => "av1_vaapi",
+                Acceleration::Cuda => "av1_nvenc",
+            },
+        }
+    }
+
+    /// Option of the software encoder taking its private `key=value:key=value` parameters
+    pub fn params_option(self) -> &'static str {
+        match self {
+            VideoEncoder::H264 => "x264-params",
+            VideoEncoder::HEVC => "x265-params",
+            VideoEncoder::AV1 => "svtav1-params",
         }
     }
 }
@@ -62,17 +131,47 @@ pub enum Preset {
 }
 
 impl Preset {
-    pub fn _vaapi_compression_level(&self) -> u8 {
+    /// SVT-AV1 preset, from 0 (slowest) to 13 (fastest)
+    pub fn svtav1_preset(&self) -> u8 {
         match self {
-            Preset::Ultrafast => 1,
-            Preset::Superfast => 1,
-            Preset::Veryfast => 2,
-            Preset::Faster => 3,
-            Preset::Fast => 4,
-            Preset::Medium => 5,
+            Preset::Ultrafast => 12,
+            Preset::Superfast => 11,
+            Preset::Veryfast => 10,
+            Preset::Faster => 9,
+            Preset::Fast => 8,
+            Preset::Medium => 7,
             Preset::Slow => 6,
-            Preset::Slower => 7,
-            Preset::Veryslow => 7,
+            Preset::Slower => 5,
+            Preset::Veryslow => 4,
+        }
+    }
+
+    /// VAAPI target usage, from 1 (best quality) to 7 (fastest)
+    pub fn vaapi_compression_level(&self) -> u8 {
+        match self {
+            Preset::Ultrafast => 7,
+            Preset::Superfast => 7,
+            Preset::Veryfast => 6,
+            Preset::Faster => 5,
+            Preset::Fast => 5,
+            Preset::Medium => 4,
+            Preset::Slow => 3,
+            Preset::Slower => 2,
+            Preset::Veryslow => 1,
+        }
+    }
+
+    pub fn nvenc_preset(&self) -> NvencPreset {
+        match self {
+            Preset::Ultrafast => NvencPreset::P1,
+            Preset::Superfast => NvencPreset::P1,
+            Preset::Veryfast => NvencPreset::P2,
+            Preset::Faster => NvencPreset::P3,
+            Preset::Fast => NvencPreset::P3,
+            Preset::Medium => NvencPreset::P4,
+            Preset::Slow => NvencPreset::P5,
+            Preset::Slower => NvencPreset::P6,
+            Preset::Veryslow => NvencPreset::P7,
         }
     }
 }
@@ -93,9 +192,215 @@ impl Display for Preset {
     }
 }
 
+#[derive(Clone, Deserialize, Serialize, Debug, Copy, PartialEq, Eq, ToSchema)]
+pub enum NvencPreset {
+    P1,
+    P2,
+    P3,
+    P4,
+    P5,
+    P6,
+    P7,
+}
+
+impl Display for NvencPreset {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NvencPreset::P1 => write!(f, "p1"),
+            NvencPreset::P2 => write!(f, "p2"),
+            NvencPreset::P3 => write!(f, "p3"),
+            NvencPreset::P4 => write!(f, "p4"),
+            NvencPreset::P5 => write!(f, "p5"),
+            NvencPreset::P6 => write!(f, "p6"),
+            NvencPreset::P7 => write!(f, "p7"),
+        }
+    }
+}
+
+#[derive(Clone, Deserialize, Serialize, Debug, Copy, PartialEq, Eq, ToSchema)]
+pub enum NvencTune {
+    HighQuality,
+    LowLatency,
+    UltraLowLatency,
+}
+
+impl Display for NvencTune {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NvencTune::HighQuality => write!(f, "hq"),
+            NvencTune::LowLatency => write!(f, "ll"),
+            NvencTune::UltraLowLatency => write!(f, "ull"),
+        }
+    }
+}
+
+/// Encoder tuning, each setting is only available with some accelerations
+#[derive(Default, Clone, Deserialize, Serialize, Debug, ToSchema)]
+pub struct EncoderTuning {
+    /// NVENC only, derived from the representation preset if not set
+    pub nvenc_preset: Option<NvencPreset>,
+    /// NVENC only
+    pub nvenc_tune: Option<NvencTune>,
+    /// VAAPI only, from 1 (best quality) to 7 (fastest), derived from the
+    /// representation preset if not set
+    pub vaapi_compression_level: Option<u8>,
+    /// VAAPI only, use the low power encoding mode
+    #[serde(default)]
+    pub low_power: bool,
+    /// Max number of consecutive B-frames, not available with SVT-AV1
+    pub b_frames: Option<u8>,
+    /// Number of reference frames, not available with SVT-AV1
+    pub ref_frames: Option<u8>,
+    /// Software and NVENC only, number of frames of the rate control lookahead
+    pub lookahead: Option<u16>,
+    /// NVENC only
+    #[serde(default)]
+    pub spatial_aq: bool,
+    /// NVENC H264 and HEVC only
+    #[serde(default)]
+    pub temporal_aq: bool,
+}
+
+impl EncoderTuning {
+    pub fn validate(
+        &self,
+        acceleration: Acceleration,
+        encoder: VideoEncoder,
+    ) -> std::io::Result<()> {
+        let unsupported = |setting: &str| {
+            Err(new_io_error!(
+                std::io::ErrorKind::InvalidInput,
+                format!(
+                    "{} not supported by {}",
+                    setting,
+                    encoder.to_string(acceleration)
+                )
+            ))
+        };
+
+        let is_cuda = acceleration == Acceleration::Cuda;
+        let is_vaapi = acceleration == Acceleration::Vaapi;
+        let is_svtav1 = acceleration == Acceleration::Software && encoder == VideoEncoder::AV1;
+
+        if !is_cuda && self.nvenc_preset.is_some() {
+            return unsupported("NVENC preset");
+        }
+        if !is_cuda && self.nvenc_tune.is_some() {
+            return unsupported("NVENC tune");
+        }
+        if !is_cuda && self.spatial_aq {
+            return unsupported("Spatial AQ");
+        }
+        if !(is_cuda && encoder != VideoEncoder::AV1) && self.temporal_aq {
+            return unsupported("Temporal AQ");
+        }
+        if !is_vaapi && self.vaapi_compression_level.is_some() {
+            return unsupported("VAAPI compression level");
+        }
+        if !is_vaapi && self.low_power {
+            return unsupported("Low power");
+        }
+        if is_vaapi && self.lookahead.is_some() {
+            return unsupported("Lookahead");
+        }
+        if is_svtav1 && self.b_frames.is_some() {
+            return unsupported("B-frames");
+        }
+        if is_svtav1 && self.ref_frames.is_some() {
+            return unsupported("Reference frames");
+        }
+
+        if self
+            .vaapi_compression_level
+            .is_some_and(|l| !(1..=7).contains(&l))
+        {
+            return Err(new_io_error!(
+                std::io::ErrorKind::InvalidInput,
+                "VAAPI compression level must be between 1 and 7"
+            ));
+        }
+        if self.b_frames.is_some_and(|b| b > 16) || self.ref_frames.is_some_and(|r| r > 16) {
+            return Err(new_io_error!(
+                std::io::ErrorKind::InvalidInput,
+                "B-frames and reference frames must be between 0 and 16"
+            ));
+        }
+        if self.lookahead.is_some_and(|l| l > 250) {
+            return Err(new_io_error!(
+                std::io::ErrorKind::InvalidInput,
+                "Lookahead must be between 0 and 250 frames"
+            ));
+        }
+        Ok(())
+    }
+
+    pub fn ff_tuning(
+        &self,
+        acceleration: Acceleration,
+        encoder: VideoEncoder,
+        preset: &Preset,
+        index: usize,
+        args: &mut Vec<String>,
+        params: &mut Vec<String>,
+    ) {
+        let mut options: Vec<(&str, String)> = Vec::new();
+        match acceleration {
+            Acceleration::Cuda => {
+                let nvenc_preset = self.nvenc_preset.unwrap_or_else(|| preset.nvenc_preset());
+                options.push(("preset", nvenc_preset.to_string()));
+                if let Some(tune) = self.nvenc_tune {
+                    options.push(("tune", tune.to_string()));
+                }
+                if self.spatial_aq {
+                    options.push(("spatial-aq", "1".to_string()));
+                }
+                if self.temporal_aq {
+                    options.push(("temporal-aq", "1".to_string()));
+                }
+                if let Some(lookahead) = self.lookahead {
+                    options.push(("rc-lookahead", lookahead.to_string()));
+                }
+            }
+            Acceleration::Vaapi => {
+                let level = self
+                    .vaapi_compression_level
+                    .unwrap_or_else(|| preset.vaapi_compression_level());
+                options.push(("compression_level", level.to_string()));
+                if self.low_power {
+                    options.push(("low_power", "1".to_string()));
+                }
+            }
+            Acceleration::Software => {
+                if let Some(lookahead) = self.lookahead {
+                    match encoder {
+                        VideoEncoder::H264 | VideoEncoder::HEVC => {
+                            params.push(format!("rc-lookahead={lookahead}"))
+                        }
+                        VideoEncoder::AV1 => params.push(format!("lookahead={lookahead}")),
+                    }
+                }
+            }
+        }
+
+        if let Some(b_frames) = self.b_frames {
+            options.push(("bf", b_frames.to_string()));
+        }
+        if let Some(ref_frames) = self.ref_frames {
+            options.push(("refs", ref_frames.to_string()));
+        }
+
+        for (option, value) in options {
+            args.push(format!("-{option}:v:{index}"));
+            args.push(value);
+        }
+    }
+}
+
 #[derive(Clone, Deserialize, Serialize, Debug, ToSchema)]
 pub enum Profile {
     Main,
+    /// HEVC 10-bit
+    Main10,
     High,
     High10,
     High422,
@@ -106,6 +411,7 @@ impl Display for Profile {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Profile::Main => write!(f, "main"),
+            Profile::Main10 => write!(f, "main10"),
             Profile::High => write!(f, "high"),
             Profile::High10 => write!(f, "high10"),
             Profile::High422 => write!(f, "high422"),
@@ -114,6 +420,237 @@ impl Display for Profile {
     }
 }
 
+#[derive(Default, Clone, Deserialize, Serialize, Debug, PartialEq, Eq, ToSchema)]
+pub enum RateControl {
+    /// Variable bitrate capped by the max bitrate
+    #[default]
+    Vbr,
+    /// Constant bitrate, the max bitrate is ignored
+    Cbr,
+    /// Software only, constant rate factor capped by the max bitrate
+    Crf { crf: u8 },
+    /// VAAPI and NVENC only, constant quantizer, up to 51 for H264 and HEVC, 255 for AV1
+    Cqp { qp: u8 },
+    /// VAAPI only, intelligent constant quality
+    Icq { quality: u8 },
+    /// VAAPI only, quality-defined variable bitrate capped by the max bitrate
+    Qvbr { quality: u8 },
+    /// NVENC only, constant quality capped by the max bitrate
+    Cq { cq: u8 },
+    /// NVENC only, multi-pass variable bitrate
+    VbrHq,
+}
+
+impl RateControl {
+    pub fn validate(
+        &self,
+        acceleration: Acceleration,
+        encoder: VideoEncoder,
+    ) -> std::io::Result<()> {
+        let supported = match self {
+            RateControl::Vbr | RateControl::Cbr => true,
+            RateControl::Crf { .. } => acceleration == Acceleration::Software,
+            RateControl::Cqp { .. } => acceleration != Acceleration::Software,
+            RateControl::Icq { .. } | RateControl::Qvbr { .. } => {
+                acceleration == Acceleration::Vaapi
+            }
+            RateControl::Cq { .. } | RateControl::VbrHq => acceleration == Acceleration::Cuda,
+        };
+        if !supported {
+            return Err(new_io_error!(
+                std::io::ErrorKind::InvalidInput,
+                format!(
+                    "Rate control {:?} not supported by {}",
+                    self,
+                    encoder.to_string(acceleration)
+                )
+            ));
+        }
+
+        let max_quality = match (self, encoder) {
+            // av1_vaapi and av1_nvenc quantize from 0 to 255
+            (RateControl::Cqp { .. }, VideoEncoder::AV1) => 255,
+            (_, VideoEncoder::AV1) => 63,
+            _ => 51,
+        };
+        let quality = match self {
+            RateControl::Crf { crf } => Some(*crf),
+            RateControl::Cqp { qp } => Some(*qp),
+            RateControl::Icq { quality } | RateControl::Qvbr { quality } => Some(*quality),
+            RateControl::Cq { cq } => Some(*cq),
+            _ => None,
+        };
+        if quality.is_some_and(|q| q > max_quality) {
+            return Err(new_io_error!(
+                std::io::ErrorKind::InvalidInput,
+                format!(
+                    "Rate control {:?} above the maximum of {}",
+                    self, max_quality
+                )
+            ));
+        }
+        Ok(())
+    }
+
+    pub fn ff_rate_control(
+        &self,
+        acceleration: Acceleration,
+        encoder: VideoEncoder,
+        representation: &VideoRepresentation,
+        index: usize,
+        args: &mut Vec<String>,
+        params: &mut Vec<String>,
+    ) {
+        let bitrate = representation.bitrate.to_string();
+        let max_bitrate = match self {
+            RateControl::Cbr => representation.bitrate,
+            _ => representation.max_bitrate,
+        };
+        let buf_size = representation
+            .buf_size
+            .unwrap_or(max_bitrate / 2)
+            .to_string();
+        let max_bitrate = max_bitrate.to_string();
+
+        let mut options: Vec<(&str, String)> = Vec::new();
+        match self {
+            RateControl::Vbr => {
+                options.push(("b", bitrate));
+                options.push(("maxrate", max_bitrate));
+                options.push(("bufsize", buf_size));
+            }
+            RateControl::Cbr => {
+                match acceleration {
+                    Acceleration::Vaapi => options.push(("rc_mode", "CBR".to_string())),
+                    Acceleration::Cuda => options.push(("rc", "cbr".to_string())),
+                    Acceleration::Software => match encoder {
+                        VideoEncoder::H264 => params.push("nal-hrd=cbr".to_string()),
+                        VideoEncoder::HEVC => params.push("strict-cbr=1".to_string()),
+                        // libsvtav1 selects CBR when the max bitrate is the bitrate
+                        VideoEncoder::AV1 => {}
+                    },
+                }
+                options.push(("b", bitrate.clone()));
+                options.push(("minrate", bitrate));
+                options.push(("maxrate", max_bitrate));
+                options.push(("bufsize", buf_size));
+            }
+            RateControl::Crf { crf } => {
+                options.push(("crf", crf.to_string()));
+                options.push(("maxrate", max_bitrate));
+                options.push(("bufsize", buf_size));
+            }
+            RateControl::Cqp { qp } => {
+                match acceleration {
+                    Acceleration::Cuda => options.push(("rc", "constqp".to_string())),
+                    _ => options.push(("rc_mode", "CQP".to_string())),
+                }
+                options.push(("qp", qp.to_string()));
+            }
+            RateControl::Icq { quality } => {
+                options.push(("rc_mode", "ICQ".to_string()));
+                options.push(("global_quality", quality.to_string()));
+            }
+            RateControl::Qvbr { quality } => {
+                options.push(("rc_mode", "QVBR".to_string()));
+                options.push(("global_quality", quality.to_string()));
+                options.push(("b", bitrate));
+                options.push(("maxrate", max_bitrate));
+                options.push(("bufsize", buf_size));
+            }
+            RateControl::Cq { cq } => {
+                options.push(("rc", "vbr".to_string()));
+                options.push(("cq", cq.to_string()));
+                options.push(("b", "0".to_string()));
+                options.push(("maxrate", max_bitrate));
+                options.push(("bufsize", buf_size));
+            }
+            RateControl::VbrHq => {
+                options.push(("rc", "vbr".to_string()));
+                options.push(("multipass", "qres".to_string()));
+                options.push(("b", bitrate));
+                options.push(("maxrate", max_bitrate));
+                options.push(("bufsize", buf_size));
+            }
+        }
+
+        for (option, value) in options {
+            args.push(format!("-{option}:v:{index}"));
+            args.push(value);
+        }
+    }
+}
+
+/// AV1 encoder options
+#[derive(Default, Clone, Deserialize, Serialize, Debug, ToSchema)]
+pub struct Av1Options {
+    /// SVT-AV1 preset from 0 to 13, derived from the representation preset if not set
+    pub preset: Option<u8>,
+    /// log2 of the number of tile columns
+    pub tile_columns: Option<u8>,
+    /// log2 of the number of tile rows
+    pub tile_rows: Option<u8>,
+}
+
+impl Av1Options {
+    pub fn validate(&self) -> std::io::Result<()> {
+        if self.preset.is_some_and(|p| p > 13) {
+            return Err(new_io_error!(
+                std::io::ErrorKind::InvalidInput,
+                "SVT-AV1 preset must be between 0 and 13"
+            ));
+        }
+        if self.tile_columns.is_some_and(|t| t > 6) || self.tile_rows.is_some_and(|t| t > 6) {
+            return Err(new_io_error!(
+                std::io::ErrorKind::InvalidInput,
+                "AV1 tile columns and rows must be between 0 and 6"
+            ));
+        }
+        Ok(())
+    }
+
+    pub fn ff_options(
+        &self,
+        acceleration: Acceleration,
+        preset: &Preset,
+        index: usize,
+        args: &mut Vec<String>,
+        params: &mut Vec<String>,
+    ) {
+        let columns = self.tile_columns.unwrap_or(0);
+        let rows = self.tile_rows.unwrap_or(0);
+        let has_tiles = self.tile_columns.is_some() || self.tile_rows.is_some();
+
+        match acceleration {
+            Acceleration::Software => {
+                args.push(format!("-preset:v:{index}"));
+                args.push(
+                    self.preset
+                        .unwrap_or_else(|| preset.svtav1_preset())
+                        .to_string(),
+                );
+                if has_tiles {
+                    params.push(format!("tile-columns={columns}:tile-rows={rows}"));
+                }
+            }
+            Acceleration::Vaapi => {
+                if has_tiles {
+                    args.push(format!("-tiles:v:{index}"));
+                    args.push(format!("{}x{}", 1 << columns, 1 << rows));
+                }
+            }
+            Acceleration::Cuda => {
+                if has_tiles {
+                    args.push(format!("-tile-columns:v:{index}"));
+                    args.push((1 << columns).to_string());
+                    args.push(format!("-tile-rows:v:{index}"));
+                    args.push((1 << rows).to_string());
+                }
+            }
+        }
+    }
+}
+
 #[derive(Clone, Deserialize, Serialize, Debug, ToSchema)]
 pub struct VideoAdaptationSet {
     pub encoder: VideoEncoder,
@@ -122,6 +659,14 @@ pub struct VideoAdaptationSet {
     pub framerate_den: u32,
     pub aspect_ratio_num: Option<u32>,
     pub aspect_ratio_den: Option<u32>,
+    /// Tone-mapping of the HDR input to BT.709 SDR
+    #[serde(default)]
+    pub tonemap: Option<Tonemap>,
+    /// Crop of the source, before the scaling
+    #[serde(default)]
+    pub crop: Option<VideoFilterCrop>,
+    #[serde(default)]
+    pub scale_mode: ScaleMode,
 }
 
 #[derive(Clone, Deserialize, Serialize, Debug, ToSchema)]
@@ -131,9 +676,24 @@ pub struct VideoRepresentation {
     pub bitrate: u32,
     pub max_bitrate: u32,
     pub buf_size: Option<u32>,
+    /// Frame rate of the representation, an integer decimation of the frame
+    /// rate of the adaptation set. The frame rate of the adaptation set if not set.
+    #[serde(default)]
+    pub framerate_num: Option<u32>,
+    #[serde(default)]
+    pub framerate_den: Option<u32>,
+    #[serde(default)]
+    pub rate_control: RateControl,
     pub preset: Preset,
+    #[serde(default)]
+    pub tuning: EncoderTuning,
+    /// Ignored by AV1, always encoded with the main profile
     pub profile: Profile,
     pub text: VideoFilterDrawText,
+    #[serde(default = "VideoFilterOverlay::new")]
+    pub overlay: VideoFilterOverlay,
+    #[serde(default)]
+    pub av1: Av1Options,
 }
 
 impl VideoRepresentation {
@@ -144,29 +704,49 @@ impl VideoRepresentation {
             bitrate: 8000000,
             max_bitrate: 8000000,
             buf_size: None,
+            framerate_num: None,
+            framerate_den: None,
+            rate_control: RateControl::Vbr,
             preset: Preset::Medium,
+            tuning: EncoderTuning::default(),
             profile: Profile::Main,
             text: VideoFilterDrawText::new(),
+            overlay: VideoFilterOverlay::new(),
+            av1: Av1Options::default(),
         }
     }
 }
 
 impl VideoRepresentation {
-    pub fn ff_sw_filter(&self, acceleration: Acceleration) -> String {
-        let mut filter = String::new();
-        if self.text.enabled {
-            if acceleration == Acceleration::Vaapi || acceleration == Acceleration::Cuda {
-                filter.push_str("hwdownload,format=nv12,");
-            }
-            filter.push_str(&self.text.ff_drawtext());
-            if acceleration == Acceleration::Vaapi {
-                filter.push_str(",hwupload,format=vaapi");
-            } else if acceleration == Acceleration::Cuda {
-                filter.push_str(",hwupload_cuda");
-            }
+    /// Filters run in software on the source, before the scaling, `ten_bit` keeps the
+    /// 10-bit frames of the hardware accelerations
+    pub fn ff_sw_filter(
+        &self,
+        acceleration: Acceleration,
+        ten_bit: bool,
+        text_context: &DrawTextContext,
+    ) -> String {
+        match self.text.enabled {
+            true => videofilter::ff_hw_roundtrip(
+                acceleration,
+                ten_bit,
+                &self.text.ff_drawtext(text_context),
+            ),
+            false => String::new(),
         }
+    }
 
-        filter
+    /// Overlay run in software on the scaled and padded output, sized against the
+    /// height of the representation, `id` makes the labels of the graph unique
+    pub fn ff_overlay_filter(&self, acceleration: Acceleration, ten_bit: bool, id: &str) -> String {
+        match self.overlay.enabled {
+            true => videofilter::ff_hw_roundtrip(
+                acceleration,
+                ten_bit,
+                &format!("null{}", self.overlay.ff_overlay(id, self.height)),
+            ),
+            false => String::new(),
+        }
     }
 }
 
@@ -179,22 +759,179 @@ impl VideoAdaptationSet {
             framerate_den: 1,
             aspect_ratio_num: None,
             aspect_ratio_den: None,
+            tonemap: None,
+            crop: None,
+            scale_mode: ScaleMode::Stretch,
         }
     }
 
-    pub fn gop_size(&self, segment_duration_ms: u64) -> u64 {
-        (self.framerate_num as u64 * segment_duration_ms)
-            .checked_div(1000 * self.framerate_den as u64)
+    pub fn validate(&self, config: &FFConfig) -> std::io::Result<()> {
+        let acceleration = config.acceleration;
+        let colorspace = config.colorspace;
+        if self.tonemap.is_some() && !colorspace.is_hdr() {
+            return Err(new_io_error!(
+                std::io::ErrorKind::InvalidInput,
+                "Tone-mapping requires a BT2020PQ or BT2020HLG colorspace"
+            ));
+        }
+        if self.tonemap.is_some()
+            && acceleration == Acceleration::Vaapi
+            && colorspace == Colorspace::BT2020HLG
+        {
+            return Err(new_io_error!(
+                std::io::ErrorKind::InvalidInput,
+                "The VAAPI tone-mapping only supports the BT2020PQ colorspace"
+            ));
+        }
+        if let Some(crop) = self.crop.as_ref() {
+            crop.validate()?;
+        }
+        for representation in &self.representations {
+            if self.colorspace(colorspace).is_hdr() {
+                let ten_bit = match self.encoder {
+                    VideoEncoder::H264 => false,
+                    VideoEncoder::HEVC => matches!(representation.profile, Profile::Main10),
+                    VideoEncoder::AV1 => true,
+                };
+                if !ten_bit {
+                    return Err(new_io_error!(
+                        std::io::ErrorKind::InvalidInput,
+                        format!(
+                            "{} requires HEVC with the Main10 profile or AV1",
+                            colorspace
+                        )
+                    ));
+                }
+            }
+            representation
+                .rate_control
+                .validate(acceleration, self.encoder)?;
+            representation.tuning.validate(acceleration, self.encoder)?;
+            representation.av1.validate()?;
+            representation.text.validate()?;
+            representation.overlay.validate()?;
+            if representation.overlay.enabled
+                && (representation.width == 0 || representation.height == 0)
+            {
+                return Err(new_io_error!(
+                    std::io::ErrorKind::InvalidInput,
+                    "The overlay requires the width and the height of the representation"
+                ));
+            }
+            self.validate_framerate(representation, config.segment_duration_ms as u64)?;
+            if config.closed_captions.is_some() {
+                if self.encoder == VideoEncoder::AV1 {
+                    return Err(new_io_error!(
+                        std::io::ErrorKind::InvalidInput,
+                        "Closed captions are not available with AV1"
+                    ));
+                }
+                if representation.framerate_num.is_some() {
+                    return Err(new_io_error!(
+                        std::io::ErrorKind::InvalidInput,
+                        "Closed captions can not be carried through a decimated frame rate"
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// The frame rate of a representation must be an integer decimation of the
+    /// frame rate of the adaptation set, with a whole number of frames per segment
+    fn validate_framerate(
+        &self,
+        representation: &VideoRepresentation,
+        segment_duration_ms: u64,
+    ) -> std::io::Result<()> {
+        if representation.framerate_num.is_none() {
+            return Ok(());
+        }
+
+        if self.framerate_num == 0 || self.framerate_den == 0 {
+            return Err(new_io_error!(
+                std::io::ErrorKind::InvalidInput,
+                format!(
+                    "Invalid frame rate {}/{}",
+                    self.framerate_num, self.framerate_den
+                )
+            ));
+        }
+
+        let (num, den) = self.framerate(representation);
+        if num == 0 || den == 0 {
+            return Err(new_io_error!(
+                std::io::ErrorKind::InvalidInput,
+                format!("Invalid frame rate {}/{}", num, den)
+            ));
+        }
+
+        // set_num / set_den = k * num / den
+        let set = self.framerate_num as u64 * den as u64;
+        let rep = self.framerate_den as u64 * num as u64;
+        if set % rep != 0 {
+            return Err(new_io_error!(
+                std::io::ErrorKind::InvalidInput,
+                format!(
+                    "Frame rate {}/{} is not an integer decimation of {}/{}",
+                    num, den, self.framerate_num, self.framerate_den
+                )
+            ));
+        }
+
+        if (num as u64 * segment_duration_ms) % (1000 * den as u64) != 0 {
+            return Err(new_io_error!(
+                std::io::ErrorKind::InvalidInput,
+                format!(
+                    "Segment duration of {} ms is not a whole number of frames at {}/{}",
+                    segment_duration_ms, num, den
+                )
+            ));
+        }
+        Ok(())
+    }
+
+    /// Frame rate of a representation, the one of the adaptation set if not set
+    pub fn framerate(&self, representation: &VideoRepresentation) -> (u32, u32) {
+        match (representation.framerate_num, representation.framerate_den) {
+            (Some(num), Some(den)) => (num, den),
+            (Some(num), None) => (num, 1),
+            _ => (self.framerate_num, self.framerate_den),
+        }
+    }
+
+    /// Colorspace of the output, BT.709 when tone-mapped
+    pub fn colorspace(&self, colorspace: Colorspace) -> Colorspace {
+        match self.tonemap {
+            Some(_) => Colorspace::BT709,
+            None => colorspace,
+        }
+    }
+
+    /// One GOP per segment, so that every representation has a key frame at
+    /// the segment boundaries
+    pub fn gop_size(&self, representation: &VideoRepresentation, segment_duration_ms: u64) -> u64 {
+        let (num, den) = self.framerate(representation);
+        (num as u64 * segment_duration_ms)
+            .checked_div(1000 * den as u64)
             .unwrap_or(1)
     }
 
-    pub fn ff_adaptation_sets(segment_duration: f64, sets: &[VideoAdaptationSet]) -> String {
+    /// `descriptor` is added to every adaptation set
+    pub fn ff_adaptation_sets(
+        segment_duration: f64,
+        descriptor: Option<&str>,
+        sets: &[VideoAdaptationSet],
+    ) -> String {
         let mut index = 0;
         let mut adaptation_sets_id = 0;
         let mut adaptation_sets = String::new();
         for adaptation in sets.iter() {
             if !adaptation.representations.is_empty() {
                 adaptation_sets.push_str(&format!("id={},", adaptation_sets_id));
+                if let Some(descriptor) = descriptor {
+                    adaptation_sets.push_str(&format!("descriptor={},", descriptor));
+                }
                 adaptation_sets.push_str(&format!("seg_duration={},", segment_duration));
                 adaptation_sets.push_str("streams=");
                 for _ in 0..adaptation.representations.len() {
@@ -214,6 +951,7 @@ impl VideoAdaptationSet {
         gpu: &Gpu,
         config: &FFConfig,
         sets: &[VideoAdaptationSet],
+        markers: bool,
         args: &mut Vec<String>,
     ) {
         let mut index = 0;
@@ -227,19 +965,17 @@ impl VideoAdaptationSet {
                 args.push(format!("-g:v:{index}"));
                 args.push(format!(
                     "{}",
-                    adaptation.gop_size(config.segment_duration_ms as u64)
+                    adaptation.gop_size(representation, config.segment_duration_ms as u64)
                 ));
                 args.push(format!("-keyint_min:v:{index}"));
                 args.push(format!(
                     "{}",
-                    adaptation.gop_size(config.segment_duration_ms as u64)
+                    adaptation.gop_size(representation, config.segment_duration_ms as u64)
                 ));
 
+                let (framerate_num, framerate_den) = adaptation.framerate(representation);
                 args.push(format!("-r:{index}"));
-                args.push(format!(
-                    "{}/{}",
-                    adaptation.framerate_num, adaptation.framerate_den
-                ));
+                args.push(format!("{}/{}", framerate_num, framerate_den));
                 args.push(format!("-c:v:{index}"));
                 args.push(adaptation.encoder.to_string(gpu.acceleration).to_owned());
 
@@ -248,23 +984,74 @@ impl VideoAdaptationSet {
                     args.push(gpu.index.to_string());
                 }
 
-                args.push(format!("-b:v:{index}"));
-                args.push(representation.bitrate.to_string());
-                args.push(format!("-maxrate:v:{index}"));
-                args.push(representation.max_bitrate.to_string());
-                args.push(format!("-bufsize:v:{index}"));
-                args.push(
-                    representation
-                        .buf_size
-                        .unwrap_or(representation.max_bitrate / 2)
-                        .to_string(),
+                // private parameters of the software encoders
+                let mut params = Vec::new();
+
+                representation.rate_control.ff_rate_control(
+                    gpu.acceleration,
+                    adaptation.encoder,
+                    representation,
+                    index,
+                    args,
+                    &mut params,
+                );
+                representation.tuning.ff_tuning(
+                    gpu.acceleration,
+                    adaptation.encoder,
+                    &representation.preset,
+                    index,
+                    args,
+                    &mut params,
                 );
-                args.push(format!("-profile:v:{index}"));
-                args.push(representation.profile.to_string());
+                if adaptation.encoder == VideoEncoder::AV1 {
+                    representation.av1.ff_options(
+                        gpu.acceleration,
+                        &representation.preset,
+                        index,
+                        args,
+                        &mut params,
+                    );
+                } else {
+                    args.push(format!("-profile:v:{index}"));
+                    args.push(representation.profile.to_string());
 
-                if gpu.acceleration == Acceleration::Software {
-                    args.push(format!("-preset:v:{index}"));
-                    args.push(representation.preset.to_string());
+                    if gpu.acceleration == Acceleration::Software {
+                        args.push(format!("-preset:v:{index}"));
+                        args.push(representation.preset.to_string());
+                    }
+                }
+
+                let colorspace = adaptation.colorspace(config.colorspace);
+                colorspace.ff_color(index, args);
+                if colorspace.is_hdr() {
+                    if gpu.acceleration == Acceleration::Software {
+                        args.push(format!("-pix_fmt:v:{index}"));
+                        args.push("yuv420p10le".to_string());
+                    }
+                    if colorspace == Colorspace::BT2020PQ
+                        && adaptation.encoder == VideoEncoder::HEVC
+                    {
+                        params.push("hdr10=1:repeat-headers=1".to_string());
+                    }
+                    config
+                        .hdr_metadata
+                        .ff_params(adaptation.encoder, &mut params);
+                }
+
+                if config.closed_captions.is_some() {
+                    ClosedCaptions::ff_encode(gpu.acceleration, adaptation.encoder, index, args);
+                }
+
+                // the scte35 keyframes include the markers
+                if config.scte35.is_some() {
+                    Scte35::ff_force_key_frames(index, args);
+                } else if markers {
+                    FFMarker::ff_force_key_frames(index, args);
+                }
+
+                if gpu.acceleration == Acceleration::Software && !params.is_empty() {
+                    args.push(format!("-{}:v:{index}", adaptation.encoder.params_option()));
+                    args.push(params.join(":"));
                 }
 
                 index += 1;
@@ -283,59 +1070,132 @@ impl VideoAdaptationSet {
         }
     }
 
-    pub fn ff_split_filter(acceleration: Acceleration, sets: &[VideoAdaptationSet]) -> String {
+    /// Scale to the size of the representation, the padding and the cropping
+    /// of the scale mode run in software on CUDA
+    fn ff_scale(
+        &self,
+        acceleration: Acceleration,
+        representation: &VideoRepresentation,
+        ten_bit: bool,
+    ) -> String {
+        let (width, height) = (representation.width, representation.height);
+        let options = self.scale_mode.ff_scale_options();
+        let mut filter = match acceleration {
+            Acceleration::Vaapi => {
+                let mut scale = format!("scale_vaapi=w={width}:h={height}{options}");
+                if ten_bit {
+                    scale.push_str(":format=p010");
+                }
+                scale
+            }
+            Acceleration::Cuda => {
+                let mut scale = format!("scale_cuda=w={width}:h={height}{options}");
+                if ten_bit {
+                    scale.push_str(":format=p010le");
+                }
+                scale
+            }
+            Acceleration::Software => format!("scale=w={width}:h={height}{options}"),
+        };
+
+        match (acceleration, self.scale_mode) {
+            (Acceleration::Vaapi, ScaleMode::Pad) => filter.push_str(&format!(
+                ",pad_vaapi=w={width}:h={height}:x=(ow-iw)/2:y=(oh-ih)/2:color=black"
+            )),
+            _ => {
+                if let Some(fit) = self.scale_mode.ff_fit(width, height) {
+                    filter.push(',');
+                    filter.push_str(&videofilter::ff_hw_roundtrip(acceleration, ten_bit, &fit));
+                }
+            }
+        }
+        filter
+    }
+
+    /// The crop runs once on the source of the adaptation set, before the split of
+    /// its representations
+    fn active_crop(&self) -> Option<&VideoFilterCrop> {
+        self.crop
+            .as_ref()
+            .filter(|crop| !crop.is_empty() && !self.representations.is_empty())
+    }
+
+    pub fn ff_split_filter(
+        acceleration: Acceleration,
+        sets: &[VideoAdaptationSet],
+        colorspace: Colorspace,
+        text_context: &DrawTextContext,
+    ) -> String {
+        let ten_bit = colorspace.is_hdr();
         let mut filter = String::new();
-        let nb: usize = sets.iter().map(|x| x.representations.len()).sum();
+        let nb: usize = sets
+            .iter()
+            .map(|x| match x.active_crop() {
+                Some(_) => 1,
+                None => x.representations.len(),
+            })
+            .sum();
 
         filter.push_str(&format!("split={nb}",));
         for (adaptation_i, adaptation) in sets.iter().enumerate() {
+            if adaptation.active_crop().is_some() {
+                filter.push_str(&format!("[c{adaptation_i}]"));
+                continue;
+            }
             for (representation_i, _) in adaptation.representations.iter().enumerate() {
                 filter.push_str(&format!("[s{adaptation_i}_{representation_i}]"));
             }
         }
         filter.push(';');
+        for (adaptation_i, adaptation) in sets.iter().enumerate() {
+            if let Some(crop) = adaptation.active_crop() {
+                filter.push_str(&format!(
+                    "[c{adaptation_i}]{},split={}",
+                    videofilter::ff_hw_roundtrip(acceleration, ten_bit, &crop.ff_crop()),
+                    adaptation.representations.len()
+                ));
+                for (representation_i, _) in adaptation.representations.iter().enumerate() {
+                    filter.push_str(&format!("[s{adaptation_i}_{representation_i}]"));
+                }
+                filter.push(';');
+            }
+        }
         for (adaptation_i, adaptation) in sets.iter().enumerate() {
             for (representation_i, representation) in adaptation.representations.iter().enumerate()
             {
-                if representation.width > 0 && representation.height > 0
-                    || representation.text.enabled
-                {
-                    let stream_id = format!("[s{adaptation_i}_{representation_i}]");
-                    filter.push_str(&stream_id);
-                    filter.push_str(&representation.ff_sw_filter(acceleration));
+                let mut chain = Vec::new();
+                let sw_filter = representation.ff_sw_filter(acceleration, ten_bit, text_context);
+                if !sw_filter.is_empty() {
+                    chain.push(sw_filter);
+                }
 
-                    if representation.width > 0 && representation.height > 0 {
-                        if representation.text.enabled {
-                            filter.push(',');
-                        }
+                if representation.width > 0 && representation.height > 0 {
+                    chain.push(adaptation.ff_scale(acceleration, representation, ten_bit));
+                    if let (Some(num), Some(den)) =
+                        (adaptation.aspect_ratio_num, adaptation.aspect_ratio_den)
+                    {
+                        chain.push(format!("setdar={}/{}", num, den));
+                    }
+                }
 
-                        match acceleration {
-                            Acceleration::Vaapi => {
-                                filter.push_str(&format!(
-                                    "scale_vaapi=w={}:h={}",
-                                    representation.width, representation.height
-                                ));
-                            }
-                            Acceleration::Cuda => {
-                                filter.push_str(&format!(
-                                    "scale_cuda=w={}:h={}",
-                                    representation.width, representation.height
-                                ));
-                            }
-                            Acceleration::Software => {
-                                filter.push_str(&format!(
-                                    "scale=w={}:h={}",
-                                    representation.width, representation.height,
-                                ));
-                            }
-                        }
+                if let Some(tonemap) = adaptation.tonemap {
+                    chain.push(tonemap.ff_tonemap(acceleration, colorspace));
+                }
 
-                        if let (Some(num), Some(den)) =
-                            (adaptation.aspect_ratio_num, adaptation.aspect_ratio_den)
-                        {
-                            filter.push_str(&format!(",setdar={}/{}", num, den));
-                        }
-                    }
+                // after the tone-mapping, the image is not HDR
+                let overlay = representation.ff_overlay_filter(
+                    acceleration,
+                    ten_bit && adaptation.tonemap.is_none(),
+                    &format!("{adaptation_i}_{representation_i}"),
+                );
+                if !overlay.is_empty() {
+                    chain.push(overlay);
+                }
+
+                if !chain.is_empty() {
+                    let stream_id = format!("[s{adaptation_i}_{representation_i}]");
+                    filter.push_str(&stream_id);
+                    filter.push_str(&chain.join(","));
                     filter.push_str(&stream_id);
                     filter.push(';');
                 }
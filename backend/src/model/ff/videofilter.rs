@@ -1,14 +1,333 @@
+use std::fmt::Display;
+
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
+use crate::new_io_error;
+
+use super::{ffconfig::Acceleration, videoadaptationset::Colorspace};
+
 #[derive(Clone, Deserialize, Serialize, Debug, ToSchema)]
 pub struct VideoFilterDeinterlace {
     pub enable: bool,
+    /// Only deinterlace the content detected as interlaced
+    #[serde(default)]
+    pub auto: bool,
 }
 
 impl VideoFilterDeinterlace {
     pub fn new() -> Self {
-        Self { enable: false }
+        Self {
+            enable: false,
+            auto: false,
+        }
+    }
+}
+
+/// Deinterlacing algorithm, Default is bwdif on Software and CUDA
+#[derive(Default, Clone, Deserialize, Serialize, Debug, Copy, PartialEq, Eq, ToSchema)]
+pub enum DeinterlaceMode {
+    #[default]
+    Default,
+    /// Software and CUDA
+    Bwdif,
+    /// Software and CUDA
+    Yadif,
+    /// VAAPI
+    Bob,
+    /// VAAPI
+    MotionAdaptive,
+    /// VAAPI
+    MotionCompensated,
+}
+
+#[derive(Default, Clone, Deserialize, Serialize, Debug, Copy, PartialEq, Eq, ToSchema)]
+pub enum DeinterlaceRate {
+    /// One frame per field, the frame rate is doubled
+    #[default]
+    Field,
+    /// One frame per frame
+    Frame,
+}
+
+/// Field order of interlaced frames
+#[derive(Default, Clone, Deserialize, Serialize, Debug, Copy, PartialEq, Eq, ToSchema)]
+pub enum FieldParity {
+    /// Field order signalled by the source
+    #[default]
+    Auto,
+    /// Top field first
+    Tff,
+    /// Bottom field first
+    Bff,
+}
+
+impl Display for FieldParity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FieldParity::Auto => write!(f, "auto"),
+            FieldParity::Tff => write!(f, "tff"),
+            FieldParity::Bff => write!(f, "bff"),
+        }
+    }
+}
+
+#[derive(Default, Clone, Deserialize, Serialize, Debug, ToSchema)]
+#[serde(default)]
+pub struct Deinterlace {
+    pub mode: DeinterlaceMode,
+    pub rate: DeinterlaceRate,
+    /// Ignored by VAAPI, which follows the field order of the frames
+    pub parity: FieldParity,
+    /// Only the frames flagged as interlaced are deinterlaced
+    pub interlaced_only: bool,
+    /// The interlaced frames and their field order are detected by idet instead of
+    /// the flags of the source, only they are deinterlaced. Runs in software.
+    pub auto: bool,
+}
+
+impl Deinterlace {
+    pub fn validate(&self, acceleration: Acceleration) -> std::io::Result<()> {
+        let supported = match acceleration {
+            Acceleration::Software | Acceleration::Cuda => matches!(
+                self.mode,
+                DeinterlaceMode::Default | DeinterlaceMode::Bwdif | DeinterlaceMode::Yadif
+            ),
+            Acceleration::Vaapi => !matches!(
+                self.mode,
+                DeinterlaceMode::Bwdif | DeinterlaceMode::Yadif
+            ),
+        };
+        if !supported {
+            return Err(new_io_error!(
+                std::io::ErrorKind::InvalidInput,
+                format!("Deinterlace mode {:?} not available with {:?}", self.mode, acceleration)
+            ));
+        }
+        if self.auto && self.parity != FieldParity::Auto {
+            return Err(new_io_error!(
+                std::io::ErrorKind::InvalidInput,
+                "The field order is detected by the automatic deinterlacing"
+            ));
+        }
+        Ok(())
+    }
+
+    /// idet flags the interlaced frames and logs the detected field order of each frame
+    fn ff_detect(acceleration: Acceleration, ten_bit: bool) -> String {
+        ff_hw_roundtrip(
+            acceleration,
+            ten_bit,
+            "idet,metadata=mode=print:key=lavfi.idet.multiple.current_frame",
+        )
+    }
+
+    pub fn ff_deinterlace(&self, acceleration: Acceleration, ten_bit: bool) -> String {
+        let mut filter = String::new();
+        if self.auto {
+            filter.push_str(&Self::ff_detect(acceleration, ten_bit));
+            filter.push(',');
+        }
+        let interlaced_only = self.interlaced_only || self.auto;
+
+        let deinterlace = match acceleration {
+            Acceleration::Vaapi => {
+                let mode = match self.mode {
+                    DeinterlaceMode::Bob => "bob",
+                    DeinterlaceMode::MotionAdaptive => "motion_adaptive",
+                    DeinterlaceMode::MotionCompensated => "motion_compensated",
+                    _ => "default",
+                };
+                let rate = match self.rate {
+                    DeinterlaceRate::Field => "field",
+                    DeinterlaceRate::Frame => "frame",
+                };
+                format!(
+                    "deinterlace_vaapi=mode={}:rate={}:auto={}",
+                    mode, rate, interlaced_only as u8
+                )
+            }
+            Acceleration::Software | Acceleration::Cuda => {
+                let name = match self.mode {
+                    DeinterlaceMode::Yadif => "yadif",
+                    _ => "bwdif",
+                };
+                let suffix = match acceleration {
+                    Acceleration::Cuda => "_cuda",
+                    _ => "",
+                };
+                let mode = match self.rate {
+                    DeinterlaceRate::Field => "send_field",
+                    DeinterlaceRate::Frame => "send_frame",
+                };
+                let deint = match interlaced_only {
+                    true => "interlaced",
+                    false => "all",
+                };
+                format!(
+                    "{}{}=mode={}:parity={}:deint={}",
+                    name, suffix, mode, self.parity, deint
+                )
+            }
+        };
+        filter.push_str(&deinterlace);
+        filter
+    }
+}
+
+#[derive(Clone, Deserialize, Serialize, Debug, ToSchema)]
+pub enum Denoise {
+    /// Spatial and temporal denoiser, luma spatial strength from 0 to 255,
+    /// the other strengths are derived from it
+    Hqdn3d { strength: f64 },
+    /// Non-local means denoiser, strength from 1 to 30. Slow.
+    Nlmeans { strength: f64 },
+    /// VAAPI denoiser, level from 0 to 64
+    Vaapi { level: u32 },
+}
+
+impl Denoise {
+    pub fn validate(&self, acceleration: Acceleration) -> std::io::Result<()> {
+        let valid = match self {
+            Denoise::Hqdn3d { strength } => (0.0..=255.0).contains(strength),
+            Denoise::Nlmeans { strength } => (1.0..=30.0).contains(strength),
+            Denoise::Vaapi { level } => {
+                if acceleration != Acceleration::Vaapi {
+                    return Err(new_io_error!(
+                        std::io::ErrorKind::InvalidInput,
+                        "The VAAPI denoiser requires the VAAPI acceleration"
+                    ));
+                }
+                *level <= 64
+            }
+        };
+        if !valid {
+            return Err(new_io_error!(
+                std::io::ErrorKind::InvalidInput,
+                format!("Invalid denoise strength {:?}", self)
+            ));
+        }
+        Ok(())
+    }
+
+    pub fn ff_denoise(&self) -> String {
+        match self {
+            Denoise::Hqdn3d { strength } => format!("hqdn3d=luma_spatial={}", strength),
+            Denoise::Nlmeans { strength } => format!("nlmeans=s={}", strength),
+            Denoise::Vaapi { level } => format!("denoise_vaapi=denoise={}", level),
+        }
+    }
+}
+
+#[derive(Clone, Deserialize, Serialize, Debug, ToSchema)]
+pub enum Sharpen {
+    /// Unsharp mask, luma amount from 0 to 5
+    Unsharp { amount: f64 },
+    /// VAAPI sharpening, level from 0 to 64
+    Vaapi { level: u32 },
+}
+
+impl Sharpen {
+    pub fn validate(&self, acceleration: Acceleration) -> std::io::Result<()> {
+        let valid = match self {
+            Sharpen::Unsharp { amount } => (0.0..=5.0).contains(amount),
+            Sharpen::Vaapi { level } => {
+                if acceleration != Acceleration::Vaapi {
+                    return Err(new_io_error!(
+                        std::io::ErrorKind::InvalidInput,
+                        "The VAAPI sharpening requires the VAAPI acceleration"
+                    ));
+                }
+                *level <= 64
+            }
+        };
+        if !valid {
+            return Err(new_io_error!(
+                std::io::ErrorKind::InvalidInput,
+                format!("Invalid sharpen amount {:?}", self)
+            ));
+        }
+        Ok(())
+    }
+
+    pub fn ff_sharpen(&self) -> String {
+        match self {
+            Sharpen::Unsharp { amount } => format!("unsharp=la={}", amount),
+            Sharpen::Vaapi { level } => format!("sharpness_vaapi=sharpness={}", level),
+        }
+    }
+}
+
+/// Filter applied to the source before the split into representations
+#[derive(Clone, Deserialize, Serialize, Debug, ToSchema)]
+pub enum VideoFilter {
+    Deinterlace(Deinterlace),
+    Denoise(Denoise),
+    Sharpen(Sharpen),
+    /// Fix the field order of a source signalling a wrong one, before the deinterlacing
+    FieldOrder(FieldParity),
+}
+
+impl VideoFilter {
+    pub fn validate(&self, acceleration: Acceleration) -> std::io::Result<()> {
+        match self {
+            VideoFilter::Deinterlace(deinterlace) => deinterlace.validate(acceleration),
+            VideoFilter::Denoise(denoise) => denoise.validate(acceleration),
+            VideoFilter::Sharpen(sharpen) => sharpen.validate(acceleration),
+            VideoFilter::FieldOrder(parity) => match parity {
+                FieldParity::Auto => Err(new_io_error!(
+                    std::io::ErrorKind::InvalidInput,
+                    "The field order must be Tff or Bff"
+                )),
+                _ => Ok(()),
+            },
+        }
+    }
+
+    /// Software filter, downloaded from the GPU with the hardware accelerations
+    fn is_software(&self) -> bool {
+        matches!(
+            self,
+            VideoFilter::Denoise(Denoise::Hqdn3d { .. } | Denoise::Nlmeans { .. })
+                | VideoFilter::Sharpen(Sharpen::Unsharp { .. })
+        )
+    }
+
+    pub fn ff_filter(&self, acceleration: Acceleration, ten_bit: bool) -> String {
+        match self {
+            VideoFilter::Deinterlace(deinterlace) => {
+                deinterlace.ff_deinterlace(acceleration, ten_bit)
+            }
+            VideoFilter::Denoise(denoise) => denoise.ff_denoise(),
+            VideoFilter::Sharpen(sharpen) => sharpen.ff_sharpen(),
+            VideoFilter::FieldOrder(parity) => format!("setfield=mode={}", parity),
+        }
+    }
+
+    /// Chain of the filters, consecutive software filters share a single download
+    /// from the GPU. `ten_bit` keeps the 10-bit frames of the hardware accelerations.
+    pub fn ff_filters(
+        filters: &[VideoFilter],
+        acceleration: Acceleration,
+        ten_bit: bool,
+    ) -> String {
+        let mut chain = Vec::new();
+        let mut sw_chain = Vec::new();
+        for filter in filters {
+            if filter.is_software() {
+                sw_chain.push(filter.ff_filter(acceleration, ten_bit));
+                continue;
+            }
+            if !sw_chain.is_empty() {
+                chain.push(ff_hw_roundtrip(acceleration, ten_bit, &sw_chain.join(",")));
+                sw_chain.clear();
+            }
+            chain.push(filter.ff_filter(acceleration, ten_bit));
+        }
+        if !sw_chain.is_empty() {
+            chain.push(ff_hw_roundtrip(acceleration, ten_bit, &sw_chain.join(",")));
+        }
+        chain.join(",")
     }
 }
 
@@ -25,6 +344,33 @@ pub enum TextPosition {
     BottomRight,
 }
 
+/// Background box of a text
+#[derive(Clone, Deserialize, Serialize, Debug, ToSchema)]
+pub struct DrawTextBox {
+    pub color: String,
+    /// Width of the border around the text, in pixels
+    pub border: u32,
+}
+
+#[derive(Clone, Deserialize, Serialize, Debug, ToSchema)]
+pub struct DrawTextShadow {
+    pub color: String,
+    /// Offset of the shadow, in pixels
+    pub x: i32,
+    pub y: i32,
+}
+
+/// Values of the text variables known when the command is built
+pub struct DrawTextContext<'a> {
+    pub config_name: &'a str,
+    pub server_name: &'a str,
+}
+
+/// The text accepts the variables:
+/// {localtime} {utctime}: wall clock, HH:MM:SS
+/// {timecode}: PTS of the frame, HH:MM:SS.mmm
+/// {frame}: frame counter
+/// {config_name} {server_name}
 #[derive(Clone, Deserialize, Serialize, Debug, ToSchema)]
 pub struct VideoFilterDrawText {
     pub enabled: bool,
@@ -32,6 +378,13 @@ pub struct VideoFilterDrawText {
     pub fontsize: u32,
     pub color: String,
     pub position: TextPosition,
+    /// Path of a TrueType font, the default font of fontconfig if not set
+    #[serde(default)]
+    pub fontfile: Option<String>,
+    #[serde(default)]
+    pub background: Option<DrawTextBox>,
+    #[serde(default)]
+    pub shadow: Option<DrawTextShadow>,
 }
 
 impl VideoFilterDrawText {
@@ -42,7 +395,29 @@ impl VideoFilterDrawText {
             fontsize: 80,
             color: "white".to_string(),
             position: TextPosition::TopCenter,
+            fontfile: None,
+            background: None,
+            shadow: None,
+        }
+    }
+
+    pub fn validate(&self) -> std::io::Result<()> {
+        if !self.enabled {
+            return Ok(());
+        }
+        if self.fontsize == 0 {
+            return Err(new_io_error!(
+                std::io::ErrorKind::InvalidInput,
+                "Text font size must be greater than 0"
+            ));
         }
+        if self.fontfile.as_ref().is_some_and(|f| f.is_empty()) {
+            return Err(new_io_error!(
+                std::io::ErrorKind::InvalidInput,
+                "Text with an empty font file"
+            ));
+        }
+        Ok(())
     }
 
     fn xy(&self) -> &str {
@@ -59,13 +434,323 @@ impl VideoFilterDrawText {
         }
     }
 
-    pub fn ff_drawtext(&self) -> String {
-        format!(
-            "drawtext=text='{}':{}:fontsize={}:fontcolor={}",
-            self.text,
+    /// Escape a literal for the text expansion of drawtext
+    fn escape_expansion(value: &str) -> String {
+        let mut escaped = String::new();
+        for c in value.chars() {
+            if matches!(c, '\\' | '%') {
+                escaped.push('\\');
+            }
+            escaped.push(c);
+        }
+        escaped
+    }
+
+    /// Text with its variables replaced by drawtext expansions,
+    /// unknown variables are kept as is
+    fn expand(&self, context: &DrawTextContext) -> String {
+        let mut expanded = String::new();
+        let mut remaining = self.text.as_str();
+        while let Some(start) = remaining.find('{') {
+            expanded.push_str(&Self::escape_expansion(&remaining[..start]));
+            remaining = &remaining[start..];
+
+            let variable = remaining.find('}').and_then(|end| {
+                let value = match &remaining[1..end] {
+                    "localtime" => "%{localtime:%T}".to_string(),
+                    "utctime" => "%{gmtime:%T}".to_string(),
+                    "timecode" => "%{pts:hms}".to_string(),
+                    "frame" => "%{frame_num}".to_string(),
+                    "config_name" => Self::escape_expansion(context.config_name),
+                    "server_name" => Self::escape_expansion(context.server_name),
+                    _ => return None,
+                };
+                Some((value, end))
+            });
+
+            match variable {
+                Some((value, end)) => {
+                    expanded.push_str(&value);
+                    remaining = &remaining[end + 1..];
+                }
+                None => {
+                    expanded.push('{');
+                    remaining = &remaining[1..];
+                }
+            }
+        }
+        expanded.push_str(&Self::escape_expansion(remaining));
+        expanded
+    }
+
+    pub fn ff_drawtext(&self, context: &DrawTextContext) -> String {
+        let mut filter = format!(
+            "drawtext=text={}:{}:fontsize={}:fontcolor={}",
+            ff_escape(&self.expand(context)),
             self.xy(),
             self.fontsize,
-            self.color
+            ff_escape(&self.color)
+        );
+        if let Some(fontfile) = self.fontfile.as_ref() {
+            filter.push_str(&format!(":fontfile={}", ff_escape(fontfile)));
+        }
+        if let Some(background) = self.background.as_ref() {
+            filter.push_str(&format!(
+                ":box=1:boxcolor={}:boxborderw={}",
+                ff_escape(&background.color),
+                background.border
+            ));
+        }
+        if let Some(shadow) = self.shadow.as_ref() {
+            filter.push_str(&format!(
+                ":shadowcolor={}:shadowx={}:shadowy={}",
+                ff_escape(&shadow.color),
+                shadow.x,
+                shadow.y
+            ));
+        }
+        filter
+    }
+}
+
+/// Pixels removed from the edges of the source, ex: burned-in black bars
+#[derive(Default, Clone, Deserialize, Serialize, Debug, PartialEq, Eq, ToSchema)]
+pub struct VideoFilterCrop {
+    pub top: u32,
+    pub bottom: u32,
+    pub left: u32,
+    pub right: u32,
+}
+
+impl VideoFilterCrop {
+    /// Even values keep the chroma planes of 4:2:0 frames aligned
+    pub fn validate(&self) -> std::io::Result<()> {
+        if [self.top, self.bottom, self.left, self.right].iter().any(|v| v % 2 != 0) {
+            return Err(new_io_error!(
+                std::io::ErrorKind::InvalidInput,
+                "Crop values must be even"
+            ));
+        }
+        Ok(())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.top == 0 && self.bottom == 0 && self.left == 0 && self.right == 0
+    }
+
+    pub fn ff_crop(&self) -> String {
+        format!(
+            "crop=w=iw-{}:h=ih-{}:x={}:y={}",
+            self.left + self.right,
+            self.top + self.bottom,
+            self.left,
+            self.top
+        )
+    }
+}
+
+/// Scaling of the source to the size of a representation
+#[derive(Default, Clone, Deserialize, Serialize, Debug, Copy, PartialEq, Eq, ToSchema)]
+pub enum ScaleMode {
+    /// The source is scaled to the size, the aspect ratio is not preserved
+    #[default]
+    Stretch,
+    /// The aspect ratio is preserved, the output fits in the size and may be smaller
+    Fit,
+    /// The aspect ratio is preserved, the output fits in the size and is padded with
+    /// black bars (letterbox or pillarbox)
+    Pad,
+    /// The aspect ratio is preserved, the output covers the size and is cropped
+    Fill,
+}
+
+impl ScaleMode {
+    /// Options of the scale, scale_vaapi and scale_cuda filters
+    pub fn ff_scale_options(&self) -> &str {
+        match self {
+            ScaleMode::Stretch => "",
+            ScaleMode::Fit | ScaleMode::Pad => ":force_original_aspect_ratio=decrease:force_divisible_by=2",
+            ScaleMode::Fill => ":force_original_aspect_ratio=increase:force_divisible_by=2",
+        }
+    }
+
+    /// Filter bringing the scaled frames to the size, run in software
+    pub fn ff_fit(&self, width: u32, height: u32) -> Option<String> {
+        match self {
+            ScaleMode::Stretch | ScaleMode::Fit => None,
+            ScaleMode::Pad => Some(format!("pad=w={width}:h={height}:x=(ow-iw)/2:y=(oh-ih)/2:color=black")),
+            ScaleMode::Fill => Some(format!("crop=w={width}:h={height}")),
+        }
+    }
+}
+
+/// Tone-mapping of HDR frames to BT.709 SDR
+#[derive(Clone, Deserialize, Serialize, Debug, Copy, PartialEq, Eq, ToSchema)]
+pub enum Tonemap {
+    Hable,
+    Mobius,
+    Reinhard,
+    Clip,
+}
+
+impl Display for Tonemap {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Tonemap::Hable => write!(f, "hable"),
+            Tonemap::Mobius => write!(f, "mobius"),
+            Tonemap::Reinhard => write!(f, "reinhard"),
+            Tonemap::Clip => write!(f, "clip"),
+        }
+    }
+}
+
+impl Tonemap {
+    /// The input colour properties are those of `colorspace`, the live sources often
+    /// do not signal them. tonemap_vaapi has no tone-mapping algorithm option and
+    /// only supports HDR10, the CUDA frames are tone-mapped in software.
+    pub fn ff_tonemap(&self, acceleration: Acceleration, colorspace: Colorspace) -> String {
+        let zscale = |format: &str| {
+            format!(
+                "zscale=tin={}:pin={}:min={}:t=linear:npl=100,format=gbrpf32le,zscale=p=bt709,tonemap=tonemap={}:desat=0,zscale=t=bt709:m=bt709:r=tv,format={}",
+                colorspace.transfer(),
+                colorspace.primaries(),
+                colorspace.matrix(),
+                self,
+                format
+            )
+        };
+        match acceleration {
+            Acceleration::Software => zscale("yuv420p"),
+            Acceleration::Vaapi => "tonemap_vaapi=format=nv12:t=bt709:m=bt709:p=bt709".to_string(),
+            Acceleration::Cuda => {
+                format!("hwdownload,format=p010le,{},hwupload_cuda", zscale("nv12"))
+            }
+        }
+    }
+}
+
+/// Escape a filter option value, for the filter options then for the filter graph
+pub fn ff_escape(value: &str) -> String {
+    let mut option = String::new();
+    for c in value.chars() {
+        if matches!(c, '\\' | '\'' | ':') {
+            option.push('\\');
+        }
+        option.push(c);
+    }
+
+    let mut graph = String::new();
+    for c in option.chars() {
+        if matches!(c, '\\' | '\'' | '[' | ']' | ',' | ';') {
+            graph.push('\\');
+        }
+        graph.push(c);
+    }
+    graph
+}
+
+/// Run a software filter on the frames of the hardware accelerations
+pub fn ff_hw_roundtrip(acceleration: Acceleration, ten_bit: bool, sw_filter: &str) -> String {
+    let hw_format = match ten_bit {
+        true => "p010le",
+        false => "nv12",
+    };
+    match acceleration {
+        Acceleration::Software => sw_filter.to_string(),
+        Acceleration::Vaapi | Acceleration::Cuda => {
+            let mut filter = format!("hwdownload,format={hw_format},{sw_filter}");
+            if ten_bit {
+                filter.push_str(&format!(",format={hw_format}"));
+            }
+            if acceleration == Acceleration::Vaapi {
+                filter.push_str(",hwupload,format=vaapi");
+            } else {
+                filter.push_str(",hwupload_cuda");
+            }
+            filter
+        }
+    }
+}
+
+/// Image overlay, ex: a station logo
+#[derive(Clone, Deserialize, Serialize, Debug, ToSchema)]
+pub struct VideoFilterOverlay {
+    pub enabled: bool,
+    /// Path or URL of the image, PNG with alpha
+    pub image: String,
+    pub position: TextPosition,
+    /// Margin to the edges, in pixels
+    pub margin: u32,
+    /// Height of the image relative to the height of the representation, from 0 to 1
+    pub height_ratio: f64,
+    /// From 0 (transparent) to 1 (opaque)
+    pub opacity: f64,
+}
+
+impl VideoFilterOverlay {
+    pub fn new() -> Self {
+        Self {
+            enabled: false,
+            image: String::new(),
+            position: TextPosition::TopRight,
+            margin: 20,
+            height_ratio: 0.1,
+            opacity: 1.0,
+        }
+    }
+
+    pub fn validate(&self) -> std::io::Result<()> {
+        if !self.enabled {
+            return Ok(());
+        }
+        if self.image.is_empty() {
+            return Err(new_io_error!(
+                std::io::ErrorKind::InvalidInput,
+                "Overlay without image"
+            ));
+        }
+        if !(self.height_ratio > 0.0 && self.height_ratio <= 1.0) {
+            return Err(new_io_error!(
+                std::io::ErrorKind::InvalidInput,
+                "Overlay height ratio must be between 0 and 1"
+            ));
+        }
+        if !(0.0..=1.0).contains(&self.opacity) {
+            return Err(new_io_error!(
+                std::io::ErrorKind::InvalidInput,
+                "Overlay opacity must be between 0 and 1"
+            ));
+        }
+        Ok(())
+    }
+
+    fn xy(&self) -> String {
+        let m = self.margin;
+        match self.position {
+            TextPosition::TopLeft => format!("x={m}:y={m}"),
+            TextPosition::TopCenter => format!("x=(W-w)/2:y={m}"),
+            TextPosition::TopRight => format!("x=W-w-{m}:y={m}"),
+            TextPosition::MiddleLeft => format!("x={m}:y=(H-h)/2"),
+            TextPosition::MiddleCenter => "x=(W-w)/2:y=(H-h)/2".to_string(),
+            TextPosition::MiddleRight => format!("x=W-w-{m}:y=(H-h)/2"),
+            TextPosition::BottomLeft => format!("x={m}:y=H-h-{m}"),
+            TextPosition::BottomCenter => format!("x=(W-w)/2:y=H-h-{m}"),
+            TextPosition::BottomRight => format!("x=W-w-{m}:y=H-h-{m}"),
+        }
+    }
+
+    /// Overlay the image on the output of the previous filter of the chain, the
+    /// image is scaled against `height`, the height of the output.
+    /// `id` makes the labels of the graph unique
+    pub fn ff_overlay(&self, id: &str, height: u32) -> String {
+        let main = format!("[ovm{id}]");
+        let logo = format!("[ovl{id}]");
+        let logo_height = ((height as f64 * self.height_ratio).round() as u32).max(1);
+        format!(
+            "{main};movie={},format=rgba,colorchannelmixer=aa={},scale=w=-1:h={logo_height}{logo};{main}{logo}overlay={}",
+            ff_escape(&self.image),
+            self.opacity,
+            self.xy()
         )
     }
 }
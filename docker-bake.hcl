@@ -41,6 +41,11 @@ variable "ORGREPOS" {
   default = "sessystems"
 }
 
+variable "FFMPEG_EXPERIMENTAL_PATCHES" {
+  description = "Apply the experimental patches of ffmpeg/README.md, SCTE-35 and markers"
+  default = "false"
+}
+
 variable "VERSION" {
   description = "The version tag for the Docker image"
   default = "" # Default is empty, we will set it in the workflow
@@ -118,6 +123,7 @@ target "ffmpeg" {
     }
     args = {
         FFMEPG_BRANCH = replace("${item.ffmpeg-tag}", "-", ".")
+        FFMPEG_EXPERIMENTAL_PATCHES = "${FFMPEG_EXPERIMENTAL_PATCHES}"
     }
     
 }
@@ -0,0 +1,34 @@
+#!/bin/sh
+# Run check of an ffmpeg built with the experimental patches: a marker read from
+# the standard input is inserted at a keyframe and written by the dash muxer.
+# The backend depends on:
+#  - the options -scte35_hls_tags and -write_markers of the dash muxer, detected by ffmpegbin.rs
+#  - the line "Marker <id> inserted at <time> ms" of the standard error, the
+#    acknowledgement of the insert_marker API
+# usage: check.sh [ffmpeg]
+FFMPEG=${1:-ffmpeg}
+OUT=$(mktemp -d)
+trap 'rm -rf "$OUT"' EXIT
+
+fail() {
+    echo "check of the experimental patches failed: $1"
+    cat "$OUT/stderr.log" 2>/dev/null
+    exit 1
+}
+
+for OPTION in -scte35_hls_tags -write_markers; do
+    $FFMPEG -hide_banner -h muxer=dash | grep -q -- "$OPTION" || fail "no $OPTION option in the dash muxer"
+done
+
+# ID3 tag without frame, sent once the encoding runs, stdin is kept open until the end
+{ sleep 4; printf 'Mcheck-marker id3 2000 49443304000000000000\n'; sleep 12; } |
+    $FFMPEG -hide_banner -loglevel info -re -f lavfi -i testsrc=size=320x240:rate=25 -t 12 \
+        -c:v mpeg4 -g 50 -force_key_frames:v:0 markers \
+        -f dash -seg_duration 2 -hls_playlist 1 "$OUT/manifest.mpd" 2>"$OUT/stderr.log" ||
+    fail "ffmpeg exited with an error"
+
+grep -Eq "Marker check-marker inserted at [0-9]+ ms" "$OUT/stderr.log" || fail "no Marker inserted line"
+grep -q 'schemeIdUri="https://aomedia.org/emsg/ID3"' "$OUT/manifest.mpd" || fail "no ID3 EventStream in the MPD"
+cat "$OUT"/media_*.m3u8 | grep -q '#EXT-X-DATERANGE:ID="check-marker"' || fail "no EXT-X-DATERANGE in the HLS playlists"
+
+echo "check of the experimental patches passed"
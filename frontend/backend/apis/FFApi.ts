@@ -4,7 +4,7 @@
  * live-cmaf-transcoder
  * API for the Live CMAF Transcoder
  *
- * The version of the OpenAPI document: 0.1.52
+ * The version of the OpenAPI document: 0.1.57
  * 
  *
  * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
@@ -17,8 +17,12 @@ import * as runtime from '../runtime';
 import type {
   InConfigID,
   InFFCreate,
+  InFFInsertMarker,
+  InFFProbe,
   InFFUpdate,
   InSetState,
+  OutFFInsertMarker,
+  OutFFProbe,
   OutGetAllFFConfig,
   OutGetFFStatus,
   VideoAdaptationSet,
@@ -29,10 +33,18 @@ import {
     InConfigIDToJSON,
     InFFCreateFromJSON,
     InFFCreateToJSON,
+    InFFInsertMarkerFromJSON,
+    InFFInsertMarkerToJSON,
+    InFFProbeFromJSON,
+    InFFProbeToJSON,
     InFFUpdateFromJSON,
     InFFUpdateToJSON,
     InSetStateFromJSON,
     InSetStateToJSON,
+    OutFFInsertMarkerFromJSON,
+    OutFFInsertMarkerToJSON,
+    OutFFProbeFromJSON,
+    OutFFProbeToJSON,
     OutGetAllFFConfigFromJSON,
     OutGetAllFFConfigToJSON,
     OutGetFFStatusFromJSON,
@@ -51,6 +63,14 @@ export interface GetFfCommandRequest {
     inConfigID: InConfigID;
 }
 
+export interface InsertMarkerRequest {
+    inFFInsertMarker: InFFInsertMarker;
+}
+
+export interface ProbeRequest {
+    inFFProbe: InFFProbe;
+}
+
 export interface RemoveRequest {
     inConfigID: InConfigID;
 }
@@ -240,6 +260,74 @@ export class FFApi extends runtime.BaseAPI {
         return await response.value();
     }
 
+    /**
+     */
+    async insertMarkerRaw(requestParameters: InsertMarkerRequest, initOverrides?: RequestInit | runtime.InitOverrideFunction): Promise<runtime.ApiResponse<OutFFInsertMarker>> {
+        if (requestParameters['inFFInsertMarker'] == null) {
+            throw new runtime.RequiredError(
+                'inFFInsertMarker',
+                'Required parameter "inFFInsertMarker" was null or undefined when calling insertMarker().'
+            );
+        }
+
+        const queryParameters: any = {};
+
+        const headerParameters: runtime.HTTPHeaders = {};
+
+        headerParameters['Content-Type'] = 'application/json';
+
+        const response = await this.request({
+            path: `/api/ff/insert_marker`,
+            method: 'POST',
+            headers: headerParameters,
+            query: queryParameters,
+            body: InFFInsertMarkerToJSON(requestParameters['inFFInsertMarker']),
+        }, initOverrides);
+
+        return new runtime.JSONApiResponse(response, (jsonValue) => OutFFInsertMarkerFromJSON(jsonValue));
+    }
+
+    /**
+     */
+    async insertMarker(requestParameters: InsertMarkerRequest, initOverrides?: RequestInit | runtime.InitOverrideFunction): Promise<OutFFInsertMarker> {
+        const response = await this.insertMarkerRaw(requestParameters, initOverrides);
+        return await response.value();
+    }
+
+    /**
+     */
+    async probeRaw(requestParameters: ProbeRequest, initOverrides?: RequestInit | runtime.InitOverrideFunction): Promise<runtime.ApiResponse<OutFFProbe>> {
+        if (requestParameters['inFFProbe'] == null) {
+            throw new runtime.RequiredError(
+                'inFFProbe',
+                'Required parameter "inFFProbe" was null or undefined when calling probe().'
+            );
+        }
+
+        const queryParameters: any = {};
+
+        const headerParameters: runtime.HTTPHeaders = {};
+
+        headerParameters['Content-Type'] = 'application/json';
+
+        const response = await this.request({
+            path: `/api/ff/probe`,
+            method: 'POST',
+            headers: headerParameters,
+            query: queryParameters,
+            body: InFFProbeToJSON(requestParameters['inFFProbe']),
+        }, initOverrides);
+
+        return new runtime.JSONApiResponse(response, (jsonValue) => OutFFProbeFromJSON(jsonValue));
+    }
+
+    /**
+     */
+    async probe(requestParameters: ProbeRequest, initOverrides?: RequestInit | runtime.InitOverrideFunction): Promise<OutFFProbe> {
+        const response = await this.probeRaw(requestParameters, initOverrides);
+        return await response.value();
+    }
+
     /**
      */
     async removeRaw(requestParameters: RemoveRequest, initOverrides?: RequestInit | runtime.InitOverrideFunction): Promise<runtime.ApiResponse<boolean>> {
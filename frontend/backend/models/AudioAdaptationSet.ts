@@ -4,7 +4,7 @@
  * live-cmaf-transcoder
  * API for the Live CMAF Transcoder
  *
- * The version of the OpenAPI document: 0.1.52
+ * The version of the OpenAPI document: 0.1.57
  * 
  *
  * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
@@ -13,13 +13,6 @@
  */
 
 import { mapValues } from '../runtime';
-import type { Audioprofile } from './Audioprofile';
-import {
-    AudioprofileFromJSON,
-    AudioprofileFromJSONTyped,
-    AudioprofileToJSON,
-    AudioprofileToJSONTyped,
-} from './Audioprofile';
 import type { Role } from './Role';
 import {
     RoleFromJSON,
@@ -27,6 +20,27 @@ import {
     RoleToJSON,
     RoleToJSONTyped,
 } from './Role';
+import type { StreamSpecifier } from './StreamSpecifier';
+import {
+    StreamSpecifierFromJSON,
+    StreamSpecifierFromJSONTyped,
+    StreamSpecifierToJSON,
+    StreamSpecifierToJSONTyped,
+} from './StreamSpecifier';
+import type { AudioFilter } from './AudioFilter';
+import {
+    AudioFilterFromJSON,
+    AudioFilterFromJSONTyped,
+    AudioFilterToJSON,
+    AudioFilterToJSONTyped,
+} from './AudioFilter';
+import type { ChannelLayout } from './ChannelLayout';
+import {
+    ChannelLayoutFromJSON,
+    ChannelLayoutFromJSONTyped,
+    ChannelLayoutToJSON,
+    ChannelLayoutToJSONTyped,
+} from './ChannelLayout';
 import type { AudioEncoder } from './AudioEncoder';
 import {
     AudioEncoderFromJSON,
@@ -34,6 +48,13 @@ import {
     AudioEncoderToJSON,
     AudioEncoderToJSONTyped,
 } from './AudioEncoder';
+import type { AudioRepresentation } from './AudioRepresentation';
+import {
+    AudioRepresentationFromJSON,
+    AudioRepresentationFromJSONTyped,
+    AudioRepresentationToJSON,
+    AudioRepresentationToJSONTyped,
+} from './AudioRepresentation';
 
 /**
  * 
@@ -42,11 +63,11 @@ import {
  */
 export interface AudioAdaptationSet {
     /**
-     * 
-     * @type {number}
+     * Ignored by the passthrough
+     * @type {ChannelLayout}
      * @memberof AudioAdaptationSet
      */
-    bitrate: number;
+    channelLayout: ChannelLayout;
     /**
      * 
      * @type {AudioEncoder}
@@ -55,10 +76,22 @@ export interface AudioAdaptationSet {
     encoder: AudioEncoder;
     /**
      * 
-     * @type {Audioprofile}
+     * @type {AudioFilter}
+     * @memberof AudioAdaptationSet
+     */
+    filter: AudioFilter;
+    /**
+     * ISO-639 language code
+     * @type {string}
+     * @memberof AudioAdaptationSet
+     */
+    language?: string | null;
+    /**
+     * 
+     * @type {Array<AudioRepresentation>}
      * @memberof AudioAdaptationSet
      */
-    profile?: Audioprofile;
+    representations: Array<AudioRepresentation>;
     /**
      * 
      * @type {Role}
@@ -66,11 +99,11 @@ export interface AudioAdaptationSet {
      */
     role: Role;
     /**
-     * 
-     * @type {number}
+     * Source audio stream, the default audio stream of the config if not set
+     * @type {StreamSpecifier}
      * @memberof AudioAdaptationSet
      */
-    sampleRate: number;
+    source?: StreamSpecifier | null;
 }
 
 
@@ -79,10 +112,11 @@ export interface AudioAdaptationSet {
  * Check if a given object implements the AudioAdaptationSet interface.
  */
 export function instanceOfAudioAdaptationSet(value: object): value is AudioAdaptationSet {
-    if (!('bitrate' in value) || value['bitrate'] === undefined) return false;
+    if (!('channelLayout' in value) || value['channelLayout'] === undefined) return false;
     if (!('encoder' in value) || value['encoder'] === undefined) return false;
+    if (!('filter' in value) || value['filter'] === undefined) return false;
+    if (!('representations' in value) || value['representations'] === undefined) return false;
     if (!('role' in value) || value['role'] === undefined) return false;
-    if (!('sampleRate' in value) || value['sampleRate'] === undefined) return false;
     return true;
 }
 
@@ -96,11 +130,13 @@ export function AudioAdaptationSetFromJSONTyped(json: any, ignoreDiscriminator:
     }
     return {
         
-        'bitrate': json['bitrate'],
+        'channelLayout': ChannelLayoutFromJSON(json['channel_layout']),
         'encoder': AudioEncoderFromJSON(json['encoder']),
-        'profile': json['profile'] == null ? undefined : AudioprofileFromJSON(json['profile']),
+        'filter': AudioFilterFromJSON(json['filter']),
+        'language': json['language'] == null ? undefined : json['language'],
+        'representations': ((json['representations'] as Array<any>).map(AudioRepresentationFromJSON)),
         'role': RoleFromJSON(json['role']),
-        'sampleRate': json['sample_rate'],
+        'source': json['source'] == null ? undefined : StreamSpecifierFromJSON(json['source']),
     };
 }
 
@@ -115,11 +151,13 @@ export function AudioAdaptationSetFromJSONTyped(json: any, ignoreDiscriminator:
 
     return {
         
-        'bitrate': value['bitrate'],
+        'channel_layout': ChannelLayoutToJSON(value['channelLayout']),
         'encoder': AudioEncoderToJSON(value['encoder']),
-        'profile': AudioprofileToJSON(value['profile']),
+        'filter': AudioFilterToJSON(value['filter']),
+        'language': value['language'],
+        'representations': ((value['representations'] as Array<any>).map(AudioRepresentationToJSON)),
         'role': RoleToJSON(value['role']),
-        'sample_rate': value['sampleRate'],
+        'source': StreamSpecifierToJSON(value['source']),
     };
 }
 
@@ -4,7 +4,7 @@
  * live-cmaf-transcoder
  * API for the Live CMAF Transcoder
  *
- * The version of the OpenAPI document: 0.1.52
+ * The version of the OpenAPI document: 0.1.57
  * 
  *
  * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
@@ -19,7 +19,10 @@
  */
 export const AudioEncoder = {
     Aac: 'Aac',
-    FdkAac: 'FDKAac'
+    FdkAac: 'FDKAac',
+    Ac3: 'Ac3',
+    Eac3: 'Eac3',
+    Copy: 'Copy'
 } as const;
 export type AudioEncoder = typeof AudioEncoder[keyof typeof AudioEncoder];
 
@@ -0,0 +1,89 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * live-cmaf-transcoder
+ * API for the Live CMAF Transcoder
+ *
+ * The version of the OpenAPI document: 0.1.57
+ * 
+ *
+ * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
+ * https://openapi-generator.tech
+ * Do not edit the class manually.
+ */
+
+import { mapValues } from '../runtime';
+import type { AudioFilterLoudnorm } from './AudioFilterLoudnorm';
+import {
+    AudioFilterLoudnormFromJSON,
+    AudioFilterLoudnormFromJSONTyped,
+    AudioFilterLoudnormToJSON,
+    AudioFilterLoudnormToJSONTyped,
+} from './AudioFilterLoudnorm';
+
+/**
+ * 
+ * @export
+ * @interface AudioFilter
+ */
+export interface AudioFilter {
+    /**
+     * Audio delay relative to the video, in ms. Negative values advance the audio.
+     * @type {number}
+     * @memberof AudioFilter
+     */
+    delayMs?: number;
+    /**
+     * 
+     * @type {AudioFilterLoudnorm}
+     * @memberof AudioFilter
+     */
+    loudnorm?: AudioFilterLoudnorm | null;
+    /**
+     * Gain applied before the loudness normalization, in dB
+     * @type {number}
+     * @memberof AudioFilter
+     */
+    volumeDb?: number | null;
+}
+
+/**
+ * Check if a given object implements the AudioFilter interface.
+ */
+export function instanceOfAudioFilter(value: object): value is AudioFilter {
+    return true;
+}
+
+export function AudioFilterFromJSON(json: any): AudioFilter {
+    return AudioFilterFromJSONTyped(json, false);
+}
+
+export function AudioFilterFromJSONTyped(json: any, ignoreDiscriminator: boolean): AudioFilter {
+    if (json == null) {
+        return json;
+    }
+    return {
+        
+        'delayMs': json['delay_ms'] == null ? undefined : json['delay_ms'],
+        'loudnorm': json['loudnorm'] == null ? undefined : AudioFilterLoudnormFromJSON(json['loudnorm']),
+        'volumeDb': json['volume_db'] == null ? undefined : json['volume_db'],
+    };
+}
+
+  export function AudioFilterToJSON(json: any): AudioFilter {
+      return AudioFilterToJSONTyped(json, false);
+  }
+
+  export function AudioFilterToJSONTyped(value?: AudioFilter | null, ignoreDiscriminator: boolean = false): any {
+    if (value == null) {
+        return value;
+    }
+
+    return {
+        
+        'delay_ms': value['delayMs'],
+        'loudnorm': AudioFilterLoudnormToJSON(value['loudnorm']),
+        'volume_db': value['volumeDb'],
+    };
+}
+
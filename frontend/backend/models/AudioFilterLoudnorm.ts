@@ -0,0 +1,81 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * live-cmaf-transcoder
+ * API for the Live CMAF Transcoder
+ *
+ * The version of the OpenAPI document: 0.1.57
+ * 
+ *
+ * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
+ * https://openapi-generator.tech
+ * Do not edit the class manually.
+ */
+
+import { mapValues } from '../runtime';
+/**
+ * EBU R128 loudness normalization
+ * @export
+ * @interface AudioFilterLoudnorm
+ */
+export interface AudioFilterLoudnorm {
+    /**
+     * Integrated loudness target, in LUFS
+     * @type {number}
+     * @memberof AudioFilterLoudnorm
+     */
+    integrated?: number;
+    /**
+     * Loudness range target, in LU
+     * @type {number}
+     * @memberof AudioFilterLoudnorm
+     */
+    lra?: number;
+    /**
+     * Maximum true peak, in dBTP
+     * @type {number}
+     * @memberof AudioFilterLoudnorm
+     */
+    truePeak?: number;
+}
+
+/**
+ * Check if a given object implements the AudioFilterLoudnorm interface.
+ */
+export function instanceOfAudioFilterLoudnorm(value: object): value is AudioFilterLoudnorm {
+    return true;
+}
+
+export function AudioFilterLoudnormFromJSON(json: any): AudioFilterLoudnorm {
+    return AudioFilterLoudnormFromJSONTyped(json, false);
+}
+
+export function AudioFilterLoudnormFromJSONTyped(json: any, ignoreDiscriminator: boolean): AudioFilterLoudnorm {
+    if (json == null) {
+        return json;
+    }
+    return {
+        
+        'integrated': json['integrated'] == null ? undefined : json['integrated'],
+        'lra': json['lra'] == null ? undefined : json['lra'],
+        'truePeak': json['true_peak'] == null ? undefined : json['true_peak'],
+    };
+}
+
+  export function AudioFilterLoudnormToJSON(json: any): AudioFilterLoudnorm {
+      return AudioFilterLoudnormToJSONTyped(json, false);
+  }
+
+  export function AudioFilterLoudnormToJSONTyped(value?: AudioFilterLoudnorm | null, ignoreDiscriminator: boolean = false): any {
+    if (value == null) {
+        return value;
+    }
+
+    return {
+        
+        'integrated': value['integrated'],
+        'lra': value['lra'],
+        'true_peak': value['truePeak'],
+    };
+}
+
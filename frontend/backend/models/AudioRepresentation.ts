@@ -0,0 +1,93 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * live-cmaf-transcoder
+ * API for the Live CMAF Transcoder
+ *
+ * The version of the OpenAPI document: 0.1.57
+ * 
+ *
+ * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
+ * https://openapi-generator.tech
+ * Do not edit the class manually.
+ */
+
+import { mapValues } from '../runtime';
+import type { Audioprofile } from './Audioprofile';
+import {
+    AudioprofileFromJSON,
+    AudioprofileFromJSONTyped,
+    AudioprofileToJSON,
+    AudioprofileToJSONTyped,
+} from './Audioprofile';
+
+/**
+ * 
+ * @export
+ * @interface AudioRepresentation
+ */
+export interface AudioRepresentation {
+    /**
+     * 
+     * @type {number}
+     * @memberof AudioRepresentation
+     */
+    bitrate: number;
+    /**
+     * AAC profile
+     * @type {Audioprofile}
+     * @memberof AudioRepresentation
+     */
+    profile?: Audioprofile;
+    /**
+     * 
+     * @type {number}
+     * @memberof AudioRepresentation
+     */
+    sampleRate: number;
+}
+
+
+
+/**
+ * Check if a given object implements the AudioRepresentation interface.
+ */
+export function instanceOfAudioRepresentation(value: object): value is AudioRepresentation {
+    if (!('bitrate' in value) || value['bitrate'] === undefined) return false;
+    if (!('sampleRate' in value) || value['sampleRate'] === undefined) return false;
+    return true;
+}
+
+export function AudioRepresentationFromJSON(json: any): AudioRepresentation {
+    return AudioRepresentationFromJSONTyped(json, false);
+}
+
+export function AudioRepresentationFromJSONTyped(json: any, ignoreDiscriminator: boolean): AudioRepresentation {
+    if (json == null) {
+        return json;
+    }
+    return {
+        
+        'bitrate': json['bitrate'],
+        'profile': json['profile'] == null ? undefined : AudioprofileFromJSON(json['profile']),
+        'sampleRate': json['sample_rate'],
+    };
+}
+
+  export function AudioRepresentationToJSON(json: any): AudioRepresentation {
+      return AudioRepresentationToJSONTyped(json, false);
+  }
+
+  export function AudioRepresentationToJSONTyped(value?: AudioRepresentation | null, ignoreDiscriminator: boolean = false): any {
+    if (value == null) {
+        return value;
+    }
+
+    return {
+        
+        'bitrate': value['bitrate'],
+        'profile': AudioprofileToJSON(value['profile']),
+        'sample_rate': value['sampleRate'],
+    };
+}
+
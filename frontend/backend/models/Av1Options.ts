@@ -0,0 +1,81 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * live-cmaf-transcoder
+ * API for the Live CMAF Transcoder
+ *
+ * The version of the OpenAPI document: 0.1.57
+ * 
+ *
+ * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
+ * https://openapi-generator.tech
+ * Do not edit the class manually.
+ */
+
+import { mapValues } from '../runtime';
+/**
+ * AV1 encoder options
+ * @export
+ * @interface Av1Options
+ */
+export interface Av1Options {
+    /**
+     * SVT-AV1 preset from 0 to 13, derived from the representation preset if not set
+     * @type {number}
+     * @memberof Av1Options
+     */
+    preset?: number | null;
+    /**
+     * log2 of the number of tile columns
+     * @type {number}
+     * @memberof Av1Options
+     */
+    tileColumns?: number | null;
+    /**
+     * log2 of the number of tile rows
+     * @type {number}
+     * @memberof Av1Options
+     */
+    tileRows?: number | null;
+}
+
+/**
+ * Check if a given object implements the Av1Options interface.
+ */
+export function instanceOfAv1Options(value: object): value is Av1Options {
+    return true;
+}
+
+export function Av1OptionsFromJSON(json: any): Av1Options {
+    return Av1OptionsFromJSONTyped(json, false);
+}
+
+export function Av1OptionsFromJSONTyped(json: any, ignoreDiscriminator: boolean): Av1Options {
+    if (json == null) {
+        return json;
+    }
+    return {
+        
+        'preset': json['preset'] == null ? undefined : json['preset'],
+        'tileColumns': json['tile_columns'] == null ? undefined : json['tile_columns'],
+        'tileRows': json['tile_rows'] == null ? undefined : json['tile_rows'],
+    };
+}
+
+  export function Av1OptionsToJSON(json: any): Av1Options {
+      return Av1OptionsToJSONTyped(json, false);
+  }
+
+  export function Av1OptionsToJSONTyped(value?: Av1Options | null, ignoreDiscriminator: boolean = false): any {
+    if (value == null) {
+        return value;
+    }
+
+    return {
+        
+        'preset': value['preset'],
+        'tile_columns': value['tileColumns'],
+        'tile_rows': value['tileRows'],
+    };
+}
+
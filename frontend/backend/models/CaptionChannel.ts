@@ -0,0 +1,75 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * live-cmaf-transcoder
+ * API for the Live CMAF Transcoder
+ *
+ * The version of the OpenAPI document: 0.1.57
+ * 
+ *
+ * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
+ * https://openapi-generator.tech
+ * Do not edit the class manually.
+ */
+
+import { mapValues } from '../runtime';
+/**
+ * 
+ * @export
+ * @interface CaptionChannel
+ */
+export interface CaptionChannel {
+    /**
+     * CC1 to CC4 for CEA-608, caption service 1 to 63 for CEA-708
+     * @type {number}
+     * @memberof CaptionChannel
+     */
+    channel: number;
+    /**
+     * ISO-639 language code
+     * @type {string}
+     * @memberof CaptionChannel
+     */
+    language: string;
+}
+
+/**
+ * Check if a given object implements the CaptionChannel interface.
+ */
+export function instanceOfCaptionChannel(value: object): value is CaptionChannel {
+    if (!('channel' in value) || value['channel'] === undefined) return false;
+    if (!('language' in value) || value['language'] === undefined) return false;
+    return true;
+}
+
+export function CaptionChannelFromJSON(json: any): CaptionChannel {
+    return CaptionChannelFromJSONTyped(json, false);
+}
+
+export function CaptionChannelFromJSONTyped(json: any, ignoreDiscriminator: boolean): CaptionChannel {
+    if (json == null) {
+        return json;
+    }
+    return {
+        
+        'channel': json['channel'],
+        'language': json['language'],
+    };
+}
+
+  export function CaptionChannelToJSON(json: any): CaptionChannel {
+      return CaptionChannelToJSONTyped(json, false);
+  }
+
+  export function CaptionChannelToJSONTyped(value?: CaptionChannel | null, ignoreDiscriminator: boolean = false): any {
+    if (value == null) {
+        return value;
+    }
+
+    return {
+        
+        'channel': value['channel'],
+        'language': value['language'],
+    };
+}
+
@@ -0,0 +1,53 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * live-cmaf-transcoder
+ * API for the Live CMAF Transcoder
+ *
+ * The version of the OpenAPI document: 0.1.57
+ * 
+ *
+ * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
+ * https://openapi-generator.tech
+ * Do not edit the class manually.
+ */
+
+
+/**
+ * 
+ * @export
+ */
+export const CaptionStandard = {
+    Cea608: 'Cea608',
+    Cea708: 'Cea708'
+} as const;
+export type CaptionStandard = typeof CaptionStandard[keyof typeof CaptionStandard];
+
+
+export function instanceOfCaptionStandard(value: any): boolean {
+    for (const key in CaptionStandard) {
+        if (Object.prototype.hasOwnProperty.call(CaptionStandard, key)) {
+            if (CaptionStandard[key as keyof typeof CaptionStandard] === value) {
+                return true;
+            }
+        }
+    }
+    return false;
+}
+
+export function CaptionStandardFromJSON(json: any): CaptionStandard {
+    return CaptionStandardFromJSONTyped(json, false);
+}
+
+export function CaptionStandardFromJSONTyped(json: any, ignoreDiscriminator: boolean): CaptionStandard {
+    return json as CaptionStandard;
+}
+
+export function CaptionStandardToJSON(value?: CaptionStandard | null): any {
+    return value as any;
+}
+
+export function CaptionStandardToJSONTyped(value: any, ignoreDiscriminator: boolean): CaptionStandard {
+    return value as CaptionStandard;
+}
+
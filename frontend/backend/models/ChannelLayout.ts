@@ -0,0 +1,55 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * live-cmaf-transcoder
+ * API for the Live CMAF Transcoder
+ *
+ * The version of the OpenAPI document: 0.1.57
+ * 
+ *
+ * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
+ * https://openapi-generator.tech
+ * Do not edit the class manually.
+ */
+
+
+/**
+ * 
+ * @export
+ */
+export const ChannelLayout = {
+    Source: 'Source',
+    Mono: 'Mono',
+    Stereo: 'Stereo',
+    Surround51: 'Surround51'
+} as const;
+export type ChannelLayout = typeof ChannelLayout[keyof typeof ChannelLayout];
+
+
+export function instanceOfChannelLayout(value: any): boolean {
+    for (const key in ChannelLayout) {
+        if (Object.prototype.hasOwnProperty.call(ChannelLayout, key)) {
+            if (ChannelLayout[key as keyof typeof ChannelLayout] === value) {
+                return true;
+            }
+        }
+    }
+    return false;
+}
+
+export function ChannelLayoutFromJSON(json: any): ChannelLayout {
+    return ChannelLayoutFromJSONTyped(json, false);
+}
+
+export function ChannelLayoutFromJSONTyped(json: any, ignoreDiscriminator: boolean): ChannelLayout {
+    return json as ChannelLayout;
+}
+
+export function ChannelLayoutToJSON(value?: ChannelLayout | null): any {
+    return value as any;
+}
+
+export function ChannelLayoutToJSONTyped(value: any, ignoreDiscriminator: boolean): ChannelLayout {
+    return value as ChannelLayout;
+}
+
@@ -0,0 +1,92 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * live-cmaf-transcoder
+ * API for the Live CMAF Transcoder
+ *
+ * The version of the OpenAPI document: 0.1.57
+ * 
+ *
+ * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
+ * https://openapi-generator.tech
+ * Do not edit the class manually.
+ */
+
+import { mapValues } from '../runtime';
+import type { CaptionChannel } from './CaptionChannel';
+import {
+    CaptionChannelFromJSON,
+    CaptionChannelFromJSONTyped,
+    CaptionChannelToJSON,
+    CaptionChannelToJSONTyped,
+} from './CaptionChannel';
+import type { CaptionStandard } from './CaptionStandard';
+import {
+    CaptionStandardFromJSON,
+    CaptionStandardFromJSONTyped,
+    CaptionStandardToJSON,
+    CaptionStandardToJSONTyped,
+} from './CaptionStandard';
+
+/**
+ * A53 closed captions of the source, carried in the SEI of every video representation
+ * @export
+ * @interface ClosedCaptions
+ */
+export interface ClosedCaptions {
+    /**
+     * Channels signalled in the MPD and the HLS playlist
+     * @type {Array<CaptionChannel>}
+     * @memberof ClosedCaptions
+     */
+    channels: Array<CaptionChannel>;
+    /**
+     * Standard of the captions of the source, selects the numbering of the channels
+     * @type {CaptionStandard}
+     * @memberof ClosedCaptions
+     */
+    standard: CaptionStandard;
+}
+
+
+
+/**
+ * Check if a given object implements the ClosedCaptions interface.
+ */
+export function instanceOfClosedCaptions(value: object): value is ClosedCaptions {
+    if (!('channels' in value) || value['channels'] === undefined) return false;
+    if (!('standard' in value) || value['standard'] === undefined) return false;
+    return true;
+}
+
+export function ClosedCaptionsFromJSON(json: any): ClosedCaptions {
+    return ClosedCaptionsFromJSONTyped(json, false);
+}
+
+export function ClosedCaptionsFromJSONTyped(json: any, ignoreDiscriminator: boolean): ClosedCaptions {
+    if (json == null) {
+        return json;
+    }
+    return {
+        
+        'channels': ((json['channels'] as Array<any>).map(CaptionChannelFromJSON)),
+        'standard': CaptionStandardFromJSON(json['standard']),
+    };
+}
+
+  export function ClosedCaptionsToJSON(json: any): ClosedCaptions {
+      return ClosedCaptionsToJSONTyped(json, false);
+  }
+
+  export function ClosedCaptionsToJSONTyped(value?: ClosedCaptions | null, ignoreDiscriminator: boolean = false): any {
+    if (value == null) {
+        return value;
+    }
+
+    return {
+        
+        'channels': ((value['channels'] as Array<any>).map(CaptionChannelToJSON)),
+        'standard': CaptionStandardToJSON(value['standard']),
+    };
+}
+
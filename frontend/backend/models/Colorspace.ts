@@ -4,7 +4,7 @@
  * live-cmaf-transcoder
  * API for the Live CMAF Transcoder
  *
- * The version of the OpenAPI document: 0.1.52
+ * The version of the OpenAPI document: 0.1.57
  * 
  *
  * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
@@ -19,7 +19,9 @@
  */
 export const Colorspace = {
     Auto: 'Auto',
-    Bt709: 'BT709'
+    Bt709: 'BT709',
+    Bt2020Pq: 'BT2020PQ',
+    Bt2020Hlg: 'BT2020HLG'
 } as const;
 export type Colorspace = typeof Colorspace[keyof typeof Colorspace];
 
@@ -0,0 +1,121 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * live-cmaf-transcoder
+ * API for the Live CMAF Transcoder
+ *
+ * The version of the OpenAPI document: 0.1.57
+ * 
+ *
+ * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
+ * https://openapi-generator.tech
+ * Do not edit the class manually.
+ */
+
+import { mapValues } from '../runtime';
+import type { FieldParity } from './FieldParity';
+import {
+    FieldParityFromJSON,
+    FieldParityFromJSONTyped,
+    FieldParityToJSON,
+    FieldParityToJSONTyped,
+} from './FieldParity';
+import type { DeinterlaceRate } from './DeinterlaceRate';
+import {
+    DeinterlaceRateFromJSON,
+    DeinterlaceRateFromJSONTyped,
+    DeinterlaceRateToJSON,
+    DeinterlaceRateToJSONTyped,
+} from './DeinterlaceRate';
+import type { DeinterlaceMode } from './DeinterlaceMode';
+import {
+    DeinterlaceModeFromJSON,
+    DeinterlaceModeFromJSONTyped,
+    DeinterlaceModeToJSON,
+    DeinterlaceModeToJSONTyped,
+} from './DeinterlaceMode';
+
+/**
+ * 
+ * @export
+ * @interface Deinterlace
+ */
+export interface Deinterlace {
+    /**
+     * The interlaced frames and their field order are detected by idet instead of the flags of the source, only they are deinterlaced. Runs in software.
+     * @type {boolean}
+     * @memberof Deinterlace
+     */
+    auto?: boolean;
+    /**
+     * Only the frames flagged as interlaced are deinterlaced
+     * @type {boolean}
+     * @memberof Deinterlace
+     */
+    interlacedOnly?: boolean;
+    /**
+     * 
+     * @type {DeinterlaceMode}
+     * @memberof Deinterlace
+     */
+    mode?: DeinterlaceMode;
+    /**
+     * Ignored by VAAPI, which follows the field order of the frames
+     * @type {FieldParity}
+     * @memberof Deinterlace
+     */
+    parity?: FieldParity;
+    /**
+     * 
+     * @type {DeinterlaceRate}
+     * @memberof Deinterlace
+     */
+    rate?: DeinterlaceRate;
+}
+
+
+
+/**
+ * Check if a given object implements the Deinterlace interface.
+ */
+export function instanceOfDeinterlace(value: object): value is Deinterlace {
+    return true;
+}
+
+export function DeinterlaceFromJSON(json: any): Deinterlace {
+    return DeinterlaceFromJSONTyped(json, false);
+}
+
+export function DeinterlaceFromJSONTyped(json: any, ignoreDiscriminator: boolean): Deinterlace {
+    if (json == null) {
+        return json;
+    }
+    return {
+        
+        'auto': json['auto'] == null ? undefined : json['auto'],
+        'interlacedOnly': json['interlaced_only'] == null ? undefined : json['interlaced_only'],
+        'mode': json['mode'] == null ? undefined : DeinterlaceModeFromJSON(json['mode']),
+        'parity': json['parity'] == null ? undefined : FieldParityFromJSON(json['parity']),
+        'rate': json['rate'] == null ? undefined : DeinterlaceRateFromJSON(json['rate']),
+    };
+}
+
+  export function DeinterlaceToJSON(json: any): Deinterlace {
+      return DeinterlaceToJSONTyped(json, false);
+  }
+
+  export function DeinterlaceToJSONTyped(value?: Deinterlace | null, ignoreDiscriminator: boolean = false): any {
+    if (value == null) {
+        return value;
+    }
+
+    return {
+        
+        'auto': value['auto'],
+        'interlaced_only': value['interlacedOnly'],
+        'mode': DeinterlaceModeToJSON(value['mode']),
+        'parity': FieldParityToJSON(value['parity']),
+        'rate': DeinterlaceRateToJSON(value['rate']),
+    };
+}
+
@@ -0,0 +1,57 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * live-cmaf-transcoder
+ * API for the Live CMAF Transcoder
+ *
+ * The version of the OpenAPI document: 0.1.57
+ * 
+ *
+ * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
+ * https://openapi-generator.tech
+ * Do not edit the class manually.
+ */
+
+
+/**
+ * Deinterlacing algorithm, Default is bwdif on Software and CUDA
+ * @export
+ */
+export const DeinterlaceMode = {
+    Default: 'Default',
+    Bwdif: 'Bwdif',
+    Yadif: 'Yadif',
+    Bob: 'Bob',
+    MotionAdaptive: 'MotionAdaptive',
+    MotionCompensated: 'MotionCompensated'
+} as const;
+export type DeinterlaceMode = typeof DeinterlaceMode[keyof typeof DeinterlaceMode];
+
+
+export function instanceOfDeinterlaceMode(value: any): boolean {
+    for (const key in DeinterlaceMode) {
+        if (Object.prototype.hasOwnProperty.call(DeinterlaceMode, key)) {
+            if (DeinterlaceMode[key as keyof typeof DeinterlaceMode] === value) {
+                return true;
+            }
+        }
+    }
+    return false;
+}
+
+export function DeinterlaceModeFromJSON(json: any): DeinterlaceMode {
+    return DeinterlaceModeFromJSONTyped(json, false);
+}
+
+export function DeinterlaceModeFromJSONTyped(json: any, ignoreDiscriminator: boolean): DeinterlaceMode {
+    return json as DeinterlaceMode;
+}
+
+export function DeinterlaceModeToJSON(value?: DeinterlaceMode | null): any {
+    return value as any;
+}
+
+export function DeinterlaceModeToJSONTyped(value: any, ignoreDiscriminator: boolean): DeinterlaceMode {
+    return value as DeinterlaceMode;
+}
+
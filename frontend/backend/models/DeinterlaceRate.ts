@@ -0,0 +1,53 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * live-cmaf-transcoder
+ * API for the Live CMAF Transcoder
+ *
+ * The version of the OpenAPI document: 0.1.57
+ * 
+ *
+ * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
+ * https://openapi-generator.tech
+ * Do not edit the class manually.
+ */
+
+
+/**
+ * 
+ * @export
+ */
+export const DeinterlaceRate = {
+    Field: 'Field',
+    Frame: 'Frame'
+} as const;
+export type DeinterlaceRate = typeof DeinterlaceRate[keyof typeof DeinterlaceRate];
+
+
+export function instanceOfDeinterlaceRate(value: any): boolean {
+    for (const key in DeinterlaceRate) {
+        if (Object.prototype.hasOwnProperty.call(DeinterlaceRate, key)) {
+            if (DeinterlaceRate[key as keyof typeof DeinterlaceRate] === value) {
+                return true;
+            }
+        }
+    }
+    return false;
+}
+
+export function DeinterlaceRateFromJSON(json: any): DeinterlaceRate {
+    return DeinterlaceRateFromJSONTyped(json, false);
+}
+
+export function DeinterlaceRateFromJSONTyped(json: any, ignoreDiscriminator: boolean): DeinterlaceRate {
+    return json as DeinterlaceRate;
+}
+
+export function DeinterlaceRateToJSON(value?: DeinterlaceRate | null): any {
+    return value as any;
+}
+
+export function DeinterlaceRateToJSONTyped(value: any, ignoreDiscriminator: boolean): DeinterlaceRate {
+    return value as DeinterlaceRate;
+}
+
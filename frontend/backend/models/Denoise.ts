@@ -0,0 +1,91 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * live-cmaf-transcoder
+ * API for the Live CMAF Transcoder
+ *
+ * The version of the OpenAPI document: 0.1.57
+ * 
+ *
+ * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
+ * https://openapi-generator.tech
+ * Do not edit the class manually.
+ */
+
+import type { DenoiseOneOf } from './DenoiseOneOf';
+import {
+    instanceOfDenoiseOneOf,
+    DenoiseOneOfFromJSON,
+    DenoiseOneOfFromJSONTyped,
+    DenoiseOneOfToJSON,
+} from './DenoiseOneOf';
+import type { DenoiseOneOf1 } from './DenoiseOneOf1';
+import {
+    instanceOfDenoiseOneOf1,
+    DenoiseOneOf1FromJSON,
+    DenoiseOneOf1FromJSONTyped,
+    DenoiseOneOf1ToJSON,
+} from './DenoiseOneOf1';
+import type { DenoiseOneOf2 } from './DenoiseOneOf2';
+import {
+    instanceOfDenoiseOneOf2,
+    DenoiseOneOf2FromJSON,
+    DenoiseOneOf2FromJSONTyped,
+    DenoiseOneOf2ToJSON,
+} from './DenoiseOneOf2';
+
+/**
+ * @type Denoise
+ * 
+ * @export
+ */
+export type Denoise = DenoiseOneOf | DenoiseOneOf1 | DenoiseOneOf2;
+
+export function DenoiseFromJSON(json: any): Denoise {
+    return DenoiseFromJSONTyped(json, false);
+}
+
+export function DenoiseFromJSONTyped(json: any, ignoreDiscriminator: boolean): Denoise {
+    if (json == null) {
+        return json;
+    }
+    if (typeof json !== 'object') {
+        return json;
+    }
+    if ('Hqdn3d' in json) {
+        return DenoiseOneOfFromJSONTyped(json, true);
+    }
+    if ('Nlmeans' in json) {
+        return DenoiseOneOf1FromJSONTyped(json, true);
+    }
+    if ('Vaapi' in json) {
+        return DenoiseOneOf2FromJSONTyped(json, true);
+    }
+
+    return {} as any;
+}
+
+export function DenoiseToJSON(json: any): any {
+    return DenoiseToJSONTyped(json, false);
+}
+
+export function DenoiseToJSONTyped(value?: Denoise | null, ignoreDiscriminator: boolean = false): any {
+    if (value == null) {
+        return value;
+    }
+    if (typeof value !== 'object') {
+        return value;
+    }
+    if (instanceOfDenoiseOneOf(value)) {
+        return DenoiseOneOfToJSON(value as DenoiseOneOf);
+    }
+    if (instanceOfDenoiseOneOf1(value)) {
+        return DenoiseOneOf1ToJSON(value as DenoiseOneOf1);
+    }
+    if (instanceOfDenoiseOneOf2(value)) {
+        return DenoiseOneOf2ToJSON(value as DenoiseOneOf2);
+    }
+
+    return {};
+}
+
@@ -0,0 +1,74 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * live-cmaf-transcoder
+ * API for the Live CMAF Transcoder
+ *
+ * The version of the OpenAPI document: 0.1.57
+ * 
+ *
+ * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
+ * https://openapi-generator.tech
+ * Do not edit the class manually.
+ */
+
+import { mapValues } from '../runtime';
+import type { DenoiseOneOfHqdn3d } from './DenoiseOneOfHqdn3d';
+import {
+    DenoiseOneOfHqdn3dFromJSON,
+    DenoiseOneOfHqdn3dFromJSONTyped,
+    DenoiseOneOfHqdn3dToJSON,
+    DenoiseOneOfHqdn3dToJSONTyped,
+} from './DenoiseOneOfHqdn3d';
+
+/**
+ * Spatial and temporal denoiser, luma spatial strength from 0 to 255, the other strengths are derived from it
+ * @export
+ * @interface DenoiseOneOf
+ */
+export interface DenoiseOneOf {
+    /**
+     * 
+     * @type {DenoiseOneOfHqdn3d}
+     * @memberof DenoiseOneOf
+     */
+    hqdn3d: DenoiseOneOfHqdn3d;
+}
+
+/**
+ * Check if a given object implements the DenoiseOneOf interface.
+ */
+export function instanceOfDenoiseOneOf(value: object): value is DenoiseOneOf {
+    if (!('hqdn3d' in value) || value['hqdn3d'] === undefined) return false;
+    return true;
+}
+
+export function DenoiseOneOfFromJSON(json: any): DenoiseOneOf {
+    return DenoiseOneOfFromJSONTyped(json, false);
+}
+
+export function DenoiseOneOfFromJSONTyped(json: any, ignoreDiscriminator: boolean): DenoiseOneOf {
+    if (json == null) {
+        return json;
+    }
+    return {
+        
+        'hqdn3d': DenoiseOneOfHqdn3dFromJSON(json['Hqdn3d']),
+    };
+}
+
+  export function DenoiseOneOfToJSON(json: any): DenoiseOneOf {
+      return DenoiseOneOfToJSONTyped(json, false);
+  }
+
+  export function DenoiseOneOfToJSONTyped(value?: DenoiseOneOf | null, ignoreDiscriminator: boolean = false): any {
+    if (value == null) {
+        return value;
+    }
+
+    return {
+        
+        'Hqdn3d': DenoiseOneOfHqdn3dToJSON(value['hqdn3d']),
+    };
+}
+
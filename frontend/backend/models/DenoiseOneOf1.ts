@@ -0,0 +1,74 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * live-cmaf-transcoder
+ * API for the Live CMAF Transcoder
+ *
+ * The version of the OpenAPI document: 0.1.57
+ * 
+ *
+ * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
+ * https://openapi-generator.tech
+ * Do not edit the class manually.
+ */
+
+import { mapValues } from '../runtime';
+import type { DenoiseOneOf1Nlmeans } from './DenoiseOneOf1Nlmeans';
+import {
+    DenoiseOneOf1NlmeansFromJSON,
+    DenoiseOneOf1NlmeansFromJSONTyped,
+    DenoiseOneOf1NlmeansToJSON,
+    DenoiseOneOf1NlmeansToJSONTyped,
+} from './DenoiseOneOf1Nlmeans';
+
+/**
+ * Non-local means denoiser, strength from 1 to 30. Slow.
+ * @export
+ * @interface DenoiseOneOf1
+ */
+export interface DenoiseOneOf1 {
+    /**
+     * 
+     * @type {DenoiseOneOf1Nlmeans}
+     * @memberof DenoiseOneOf1
+     */
+    nlmeans: DenoiseOneOf1Nlmeans;
+}
+
+/**
+ * Check if a given object implements the DenoiseOneOf1 interface.
+ */
+export function instanceOfDenoiseOneOf1(value: object): value is DenoiseOneOf1 {
+    if (!('nlmeans' in value) || value['nlmeans'] === undefined) return false;
+    return true;
+}
+
+export function DenoiseOneOf1FromJSON(json: any): DenoiseOneOf1 {
+    return DenoiseOneOf1FromJSONTyped(json, false);
+}
+
+export function DenoiseOneOf1FromJSONTyped(json: any, ignoreDiscriminator: boolean): DenoiseOneOf1 {
+    if (json == null) {
+        return json;
+    }
+    return {
+        
+        'nlmeans': DenoiseOneOf1NlmeansFromJSON(json['Nlmeans']),
+    };
+}
+
+  export function DenoiseOneOf1ToJSON(json: any): DenoiseOneOf1 {
+      return DenoiseOneOf1ToJSONTyped(json, false);
+  }
+
+  export function DenoiseOneOf1ToJSONTyped(value?: DenoiseOneOf1 | null, ignoreDiscriminator: boolean = false): any {
+    if (value == null) {
+        return value;
+    }
+
+    return {
+        
+        'Nlmeans': DenoiseOneOf1NlmeansToJSON(value['nlmeans']),
+    };
+}
+
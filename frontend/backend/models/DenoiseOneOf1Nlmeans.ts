@@ -0,0 +1,66 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * live-cmaf-transcoder
+ * API for the Live CMAF Transcoder
+ *
+ * The version of the OpenAPI document: 0.1.57
+ * 
+ *
+ * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
+ * https://openapi-generator.tech
+ * Do not edit the class manually.
+ */
+
+import { mapValues } from '../runtime';
+/**
+ * Non-local means denoiser, strength from 1 to 30. Slow.
+ * @export
+ * @interface DenoiseOneOf1Nlmeans
+ */
+export interface DenoiseOneOf1Nlmeans {
+    /**
+     * 
+     * @type {number}
+     * @memberof DenoiseOneOf1Nlmeans
+     */
+    strength: number;
+}
+
+/**
+ * Check if a given object implements the DenoiseOneOf1Nlmeans interface.
+ */
+export function instanceOfDenoiseOneOf1Nlmeans(value: object): value is DenoiseOneOf1Nlmeans {
+    if (!('strength' in value) || value['strength'] === undefined) return false;
+    return true;
+}
+
+export function DenoiseOneOf1NlmeansFromJSON(json: any): DenoiseOneOf1Nlmeans {
+    return DenoiseOneOf1NlmeansFromJSONTyped(json, false);
+}
+
+export function DenoiseOneOf1NlmeansFromJSONTyped(json: any, ignoreDiscriminator: boolean): DenoiseOneOf1Nlmeans {
+    if (json == null) {
+        return json;
+    }
+    return {
+        
+        'strength': json['strength'],
+    };
+}
+
+  export function DenoiseOneOf1NlmeansToJSON(json: any): DenoiseOneOf1Nlmeans {
+      return DenoiseOneOf1NlmeansToJSONTyped(json, false);
+  }
+
+  export function DenoiseOneOf1NlmeansToJSONTyped(value?: DenoiseOneOf1Nlmeans | null, ignoreDiscriminator: boolean = false): any {
+    if (value == null) {
+        return value;
+    }
+
+    return {
+        
+        'strength': value['strength'],
+    };
+}
+
@@ -0,0 +1,74 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * live-cmaf-transcoder
+ * API for the Live CMAF Transcoder
+ *
+ * The version of the OpenAPI document: 0.1.57
+ * 
+ *
+ * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
+ * https://openapi-generator.tech
+ * Do not edit the class manually.
+ */
+
+import { mapValues } from '../runtime';
+import type { DenoiseOneOf2Vaapi } from './DenoiseOneOf2Vaapi';
+import {
+    DenoiseOneOf2VaapiFromJSON,
+    DenoiseOneOf2VaapiFromJSONTyped,
+    DenoiseOneOf2VaapiToJSON,
+    DenoiseOneOf2VaapiToJSONTyped,
+} from './DenoiseOneOf2Vaapi';
+
+/**
+ * VAAPI denoiser, level from 0 to 64
+ * @export
+ * @interface DenoiseOneOf2
+ */
+export interface DenoiseOneOf2 {
+    /**
+     * 
+     * @type {DenoiseOneOf2Vaapi}
+     * @memberof DenoiseOneOf2
+     */
+    vaapi: DenoiseOneOf2Vaapi;
+}
+
+/**
+ * Check if a given object implements the DenoiseOneOf2 interface.
+ */
+export function instanceOfDenoiseOneOf2(value: object): value is DenoiseOneOf2 {
+    if (!('vaapi' in value) || value['vaapi'] === undefined) return false;
+    return true;
+}
+
+export function DenoiseOneOf2FromJSON(json: any): DenoiseOneOf2 {
+    return DenoiseOneOf2FromJSONTyped(json, false);
+}
+
+export function DenoiseOneOf2FromJSONTyped(json: any, ignoreDiscriminator: boolean): DenoiseOneOf2 {
+    if (json == null) {
+        return json;
+    }
+    return {
+        
+        'vaapi': DenoiseOneOf2VaapiFromJSON(json['Vaapi']),
+    };
+}
+
+  export function DenoiseOneOf2ToJSON(json: any): DenoiseOneOf2 {
+      return DenoiseOneOf2ToJSONTyped(json, false);
+  }
+
+  export function DenoiseOneOf2ToJSONTyped(value?: DenoiseOneOf2 | null, ignoreDiscriminator: boolean = false): any {
+    if (value == null) {
+        return value;
+    }
+
+    return {
+        
+        'Vaapi': DenoiseOneOf2VaapiToJSON(value['vaapi']),
+    };
+}
+
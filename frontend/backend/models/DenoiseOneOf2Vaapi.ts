@@ -0,0 +1,66 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * live-cmaf-transcoder
+ * API for the Live CMAF Transcoder
+ *
+ * The version of the OpenAPI document: 0.1.57
+ * 
+ *
+ * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
+ * https://openapi-generator.tech
+ * Do not edit the class manually.
+ */
+
+import { mapValues } from '../runtime';
+/**
+ * VAAPI denoiser, level from 0 to 64
+ * @export
+ * @interface DenoiseOneOf2Vaapi
+ */
+export interface DenoiseOneOf2Vaapi {
+    /**
+     * 
+     * @type {number}
+     * @memberof DenoiseOneOf2Vaapi
+     */
+    level: number;
+}
+
+/**
+ * Check if a given object implements the DenoiseOneOf2Vaapi interface.
+ */
+export function instanceOfDenoiseOneOf2Vaapi(value: object): value is DenoiseOneOf2Vaapi {
+    if (!('level' in value) || value['level'] === undefined) return false;
+    return true;
+}
+
+export function DenoiseOneOf2VaapiFromJSON(json: any): DenoiseOneOf2Vaapi {
+    return DenoiseOneOf2VaapiFromJSONTyped(json, false);
+}
+
+export function DenoiseOneOf2VaapiFromJSONTyped(json: any, ignoreDiscriminator: boolean): DenoiseOneOf2Vaapi {
+    if (json == null) {
+        return json;
+    }
+    return {
+        
+        'level': json['level'],
+    };
+}
+
+  export function DenoiseOneOf2VaapiToJSON(json: any): DenoiseOneOf2Vaapi {
+      return DenoiseOneOf2VaapiToJSONTyped(json, false);
+  }
+
+  export function DenoiseOneOf2VaapiToJSONTyped(value?: DenoiseOneOf2Vaapi | null, ignoreDiscriminator: boolean = false): any {
+    if (value == null) {
+        return value;
+    }
+
+    return {
+        
+        'level': value['level'],
+    };
+}
+
@@ -0,0 +1,66 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * live-cmaf-transcoder
+ * API for the Live CMAF Transcoder
+ *
+ * The version of the OpenAPI document: 0.1.57
+ * 
+ *
+ * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
+ * https://openapi-generator.tech
+ * Do not edit the class manually.
+ */
+
+import { mapValues } from '../runtime';
+/**
+ * Spatial and temporal denoiser, luma spatial strength from 0 to 255, the other strengths are derived from it
+ * @export
+ * @interface DenoiseOneOfHqdn3d
+ */
+export interface DenoiseOneOfHqdn3d {
+    /**
+     * 
+     * @type {number}
+     * @memberof DenoiseOneOfHqdn3d
+     */
+    strength: number;
+}
+
+/**
+ * Check if a given object implements the DenoiseOneOfHqdn3d interface.
+ */
+export function instanceOfDenoiseOneOfHqdn3d(value: object): value is DenoiseOneOfHqdn3d {
+    if (!('strength' in value) || value['strength'] === undefined) return false;
+    return true;
+}
+
+export function DenoiseOneOfHqdn3dFromJSON(json: any): DenoiseOneOfHqdn3d {
+    return DenoiseOneOfHqdn3dFromJSONTyped(json, false);
+}
+
+export function DenoiseOneOfHqdn3dFromJSONTyped(json: any, ignoreDiscriminator: boolean): DenoiseOneOfHqdn3d {
+    if (json == null) {
+        return json;
+    }
+    return {
+        
+        'strength': json['strength'],
+    };
+}
+
+  export function DenoiseOneOfHqdn3dToJSON(json: any): DenoiseOneOfHqdn3d {
+      return DenoiseOneOfHqdn3dToJSONTyped(json, false);
+  }
+
+  export function DenoiseOneOfHqdn3dToJSONTyped(value?: DenoiseOneOfHqdn3d | null, ignoreDiscriminator: boolean = false): any {
+    if (value == null) {
+        return value;
+    }
+
+    return {
+        
+        'strength': value['strength'],
+    };
+}
+
@@ -0,0 +1,54 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * live-cmaf-transcoder
+ * API for the Live CMAF Transcoder
+ *
+ * The version of the OpenAPI document: 0.1.57
+ * 
+ *
+ * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
+ * https://openapi-generator.tech
+ * Do not edit the class manually.
+ */
+
+
+/**
+ * Field order of the source detected by the automatic deinterlacing
+ * @export
+ */
+export const DetectedFieldOrder = {
+    Progressive: 'Progressive',
+    Tff: 'Tff',
+    Bff: 'Bff'
+} as const;
+export type DetectedFieldOrder = typeof DetectedFieldOrder[keyof typeof DetectedFieldOrder];
+
+
+export function instanceOfDetectedFieldOrder(value: any): boolean {
+    for (const key in DetectedFieldOrder) {
+        if (Object.prototype.hasOwnProperty.call(DetectedFieldOrder, key)) {
+            if (DetectedFieldOrder[key as keyof typeof DetectedFieldOrder] === value) {
+                return true;
+            }
+        }
+    }
+    return false;
+}
+
+export function DetectedFieldOrderFromJSON(json: any): DetectedFieldOrder {
+    return DetectedFieldOrderFromJSONTyped(json, false);
+}
+
+export function DetectedFieldOrderFromJSONTyped(json: any, ignoreDiscriminator: boolean): DetectedFieldOrder {
+    return json as DetectedFieldOrder;
+}
+
+export function DetectedFieldOrderToJSON(value?: DetectedFieldOrder | null): any {
+    return value as any;
+}
+
+export function DetectedFieldOrderToJSONTyped(value: any, ignoreDiscriminator: boolean): DetectedFieldOrder {
+    return value as DetectedFieldOrder;
+}
+
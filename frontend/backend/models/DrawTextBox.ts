@@ -0,0 +1,75 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * live-cmaf-transcoder
+ * API for the Live CMAF Transcoder
+ *
+ * The version of the OpenAPI document: 0.1.57
+ * 
+ *
+ * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
+ * https://openapi-generator.tech
+ * Do not edit the class manually.
+ */
+
+import { mapValues } from '../runtime';
+/**
+ * Background box of a text
+ * @export
+ * @interface DrawTextBox
+ */
+export interface DrawTextBox {
+    /**
+     * Width of the border around the text, in pixels
+     * @type {number}
+     * @memberof DrawTextBox
+     */
+    border: number;
+    /**
+     * 
+     * @type {string}
+     * @memberof DrawTextBox
+     */
+    color: string;
+}
+
+/**
+ * Check if a given object implements the DrawTextBox interface.
+ */
+export function instanceOfDrawTextBox(value: object): value is DrawTextBox {
+    if (!('border' in value) || value['border'] === undefined) return false;
+    if (!('color' in value) || value['color'] === undefined) return false;
+    return true;
+}
+
+export function DrawTextBoxFromJSON(json: any): DrawTextBox {
+    return DrawTextBoxFromJSONTyped(json, false);
+}
+
+export function DrawTextBoxFromJSONTyped(json: any, ignoreDiscriminator: boolean): DrawTextBox {
+    if (json == null) {
+        return json;
+    }
+    return {
+        
+        'border': json['border'],
+        'color': json['color'],
+    };
+}
+
+  export function DrawTextBoxToJSON(json: any): DrawTextBox {
+      return DrawTextBoxToJSONTyped(json, false);
+  }
+
+  export function DrawTextBoxToJSONTyped(value?: DrawTextBox | null, ignoreDiscriminator: boolean = false): any {
+    if (value == null) {
+        return value;
+    }
+
+    return {
+        
+        'border': value['border'],
+        'color': value['color'],
+    };
+}
+
@@ -0,0 +1,84 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * live-cmaf-transcoder
+ * API for the Live CMAF Transcoder
+ *
+ * The version of the OpenAPI document: 0.1.57
+ * 
+ *
+ * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
+ * https://openapi-generator.tech
+ * Do not edit the class manually.
+ */
+
+import { mapValues } from '../runtime';
+/**
+ * 
+ * @export
+ * @interface DrawTextShadow
+ */
+export interface DrawTextShadow {
+    /**
+     * 
+     * @type {string}
+     * @memberof DrawTextShadow
+     */
+    color: string;
+    /**
+     * Offset of the shadow, in pixels
+     * @type {number}
+     * @memberof DrawTextShadow
+     */
+    x: number;
+    /**
+     * 
+     * @type {number}
+     * @memberof DrawTextShadow
+     */
+    y: number;
+}
+
+/**
+ * Check if a given object implements the DrawTextShadow interface.
+ */
+export function instanceOfDrawTextShadow(value: object): value is DrawTextShadow {
+    if (!('color' in value) || value['color'] === undefined) return false;
+    if (!('x' in value) || value['x'] === undefined) return false;
+    if (!('y' in value) || value['y'] === undefined) return false;
+    return true;
+}
+
+export function DrawTextShadowFromJSON(json: any): DrawTextShadow {
+    return DrawTextShadowFromJSONTyped(json, false);
+}
+
+export function DrawTextShadowFromJSONTyped(json: any, ignoreDiscriminator: boolean): DrawTextShadow {
+    if (json == null) {
+        return json;
+    }
+    return {
+        
+        'color': json['color'],
+        'x': json['x'],
+        'y': json['y'],
+    };
+}
+
+  export function DrawTextShadowToJSON(json: any): DrawTextShadow {
+      return DrawTextShadowToJSONTyped(json, false);
+  }
+
+  export function DrawTextShadowToJSONTyped(value?: DrawTextShadow | null, ignoreDiscriminator: boolean = false): any {
+    if (value == null) {
+        return value;
+    }
+
+    return {
+        
+        'color': value['color'],
+        'x': value['x'],
+        'y': value['y'],
+    };
+}
+
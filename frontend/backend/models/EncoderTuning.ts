@@ -0,0 +1,146 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * live-cmaf-transcoder
+ * API for the Live CMAF Transcoder
+ *
+ * The version of the OpenAPI document: 0.1.57
+ * 
+ *
+ * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
+ * https://openapi-generator.tech
+ * Do not edit the class manually.
+ */
+
+import { mapValues } from '../runtime';
+import type { NvencTune } from './NvencTune';
+import {
+    NvencTuneFromJSON,
+    NvencTuneFromJSONTyped,
+    NvencTuneToJSON,
+    NvencTuneToJSONTyped,
+} from './NvencTune';
+import type { NvencPreset } from './NvencPreset';
+import {
+    NvencPresetFromJSON,
+    NvencPresetFromJSONTyped,
+    NvencPresetToJSON,
+    NvencPresetToJSONTyped,
+} from './NvencPreset';
+
+/**
+ * Encoder tuning, each setting is only available with some accelerations
+ * @export
+ * @interface EncoderTuning
+ */
+export interface EncoderTuning {
+    /**
+     * Max number of consecutive B-frames, not available with SVT-AV1
+     * @type {number}
+     * @memberof EncoderTuning
+     */
+    bFrames?: number | null;
+    /**
+     * Software and NVENC only, number of frames of the rate control lookahead
+     * @type {number}
+     * @memberof EncoderTuning
+     */
+    lookahead?: number | null;
+    /**
+     * VAAPI only, use the low power encoding mode
+     * @type {boolean}
+     * @memberof EncoderTuning
+     */
+    lowPower?: boolean;
+    /**
+     * NVENC only, derived from the representation preset if not set
+     * @type {NvencPreset}
+     * @memberof EncoderTuning
+     */
+    nvencPreset?: NvencPreset | null;
+    /**
+     * NVENC only
+     * @type {NvencTune}
+     * @memberof EncoderTuning
+     */
+    nvencTune?: NvencTune | null;
+    /**
+     * Number of reference frames, not available with SVT-AV1
+     * @type {number}
+     * @memberof EncoderTuning
+     */
+    refFrames?: number | null;
+    /**
+     * NVENC only
+     * @type {boolean}
+     * @memberof EncoderTuning
+     */
+    spatialAq?: boolean;
+    /**
+     * NVENC H264 and HEVC only
+     * @type {boolean}
+     * @memberof EncoderTuning
+     */
+    temporalAq?: boolean;
+    /**
+     * VAAPI only, from 1 (best quality) to 7 (fastest), derived from the representation preset if not set
+     * @type {number}
+     * @memberof EncoderTuning
+     */
+    vaapiCompressionLevel?: number | null;
+}
+
+
+
+/**
+ * Check if a given object implements the EncoderTuning interface.
+ */
+export function instanceOfEncoderTuning(value: object): value is EncoderTuning {
+    return true;
+}
+
+export function EncoderTuningFromJSON(json: any): EncoderTuning {
+    return EncoderTuningFromJSONTyped(json, false);
+}
+
+export function EncoderTuningFromJSONTyped(json: any, ignoreDiscriminator: boolean): EncoderTuning {
+    if (json == null) {
+        return json;
+    }
+    return {
+        
+        'bFrames': json['b_frames'] == null ? undefined : json['b_frames'],
+        'lookahead': json['lookahead'] == null ? undefined : json['lookahead'],
+        'lowPower': json['low_power'] == null ? undefined : json['low_power'],
+        'nvencPreset': json['nvenc_preset'] == null ? undefined : NvencPresetFromJSON(json['nvenc_preset']),
+        'nvencTune': json['nvenc_tune'] == null ? undefined : NvencTuneFromJSON(json['nvenc_tune']),
+        'refFrames': json['ref_frames'] == null ? undefined : json['ref_frames'],
+        'spatialAq': json['spatial_aq'] == null ? undefined : json['spatial_aq'],
+        'temporalAq': json['temporal_aq'] == null ? undefined : json['temporal_aq'],
+        'vaapiCompressionLevel': json['vaapi_compression_level'] == null ? undefined : json['vaapi_compression_level'],
+    };
+}
+
+  export function EncoderTuningToJSON(json: any): EncoderTuning {
+      return EncoderTuningToJSONTyped(json, false);
+  }
+
+  export function EncoderTuningToJSONTyped(value?: EncoderTuning | null, ignoreDiscriminator: boolean = false): any {
+    if (value == null) {
+        return value;
+    }
+
+    return {
+        
+        'b_frames': value['bFrames'],
+        'lookahead': value['lookahead'],
+        'low_power': value['lowPower'],
+        'nvenc_preset': NvencPresetToJSON(value['nvencPreset']),
+        'nvenc_tune': NvencTuneToJSON(value['nvencTune']),
+        'ref_frames': value['refFrames'],
+        'spatial_aq': value['spatialAq'],
+        'temporal_aq': value['temporalAq'],
+        'vaapi_compression_level': value['vaapiCompressionLevel'],
+    };
+}
+
@@ -4,7 +4,7 @@
  * live-cmaf-transcoder
  * API for the Live CMAF Transcoder
  *
- * The version of the OpenAPI document: 0.1.52
+ * The version of the OpenAPI document: 0.1.57
  * 
  *
  * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
@@ -13,6 +13,76 @@
  */
 
 import { mapValues } from '../runtime';
+import type { StreamSelection } from './StreamSelection';
+import {
+    StreamSelectionFromJSON,
+    StreamSelectionFromJSONTyped,
+    StreamSelectionToJSON,
+    StreamSelectionToJSONTyped,
+} from './StreamSelection';
+import type { HdrMetadata } from './HdrMetadata';
+import {
+    HdrMetadataFromJSON,
+    HdrMetadataFromJSONTyped,
+    HdrMetadataToJSON,
+    HdrMetadataToJSONTyped,
+} from './HdrMetadata';
+import type { InputFailover } from './InputFailover';
+import {
+    InputFailoverFromJSON,
+    InputFailoverFromJSONTyped,
+    InputFailoverToJSON,
+    InputFailoverToJSONTyped,
+} from './InputFailover';
+import type { Scte35 } from './Scte35';
+import {
+    Scte35FromJSON,
+    Scte35FromJSONTyped,
+    Scte35ToJSON,
+    Scte35ToJSONTyped,
+} from './Scte35';
+import type { Thumbnails } from './Thumbnails';
+import {
+    ThumbnailsFromJSON,
+    ThumbnailsFromJSONTyped,
+    ThumbnailsToJSON,
+    ThumbnailsToJSONTyped,
+} from './Thumbnails';
+import type { Colorspace } from './Colorspace';
+import {
+    ColorspaceFromJSON,
+    ColorspaceFromJSONTyped,
+    ColorspaceToJSON,
+    ColorspaceToJSONTyped,
+} from './Colorspace';
+import type { VideoAdaptationSet } from './VideoAdaptationSet';
+import {
+    VideoAdaptationSetFromJSON,
+    VideoAdaptationSetFromJSONTyped,
+    VideoAdaptationSetToJSON,
+    VideoAdaptationSetToJSONTyped,
+} from './VideoAdaptationSet';
+import type { VideoFilterDeinterlace } from './VideoFilterDeinterlace';
+import {
+    VideoFilterDeinterlaceFromJSON,
+    VideoFilterDeinterlaceFromJSONTyped,
+    VideoFilterDeinterlaceToJSON,
+    VideoFilterDeinterlaceToJSONTyped,
+} from './VideoFilterDeinterlace';
+import type { ClosedCaptions } from './ClosedCaptions';
+import {
+    ClosedCaptionsFromJSON,
+    ClosedCaptionsFromJSONTyped,
+    ClosedCaptionsToJSON,
+    ClosedCaptionsToJSONTyped,
+} from './ClosedCaptions';
+import type { Input } from './Input';
+import {
+    InputFromJSON,
+    InputFromJSONTyped,
+    InputToJSON,
+    InputToJSONTyped,
+} from './Input';
 import type { MPDType } from './MPDType';
 import {
     MPDTypeFromJSON,
@@ -20,13 +90,13 @@ import {
     MPDTypeToJSON,
     MPDTypeToJSONTyped,
 } from './MPDType';
-import type { Srt } from './Srt';
+import type { VideoFilter } from './VideoFilter';
 import {
-    SrtFromJSON,
-    SrtFromJSONTyped,
-    SrtToJSON,
-    SrtToJSONTyped,
-} from './Srt';
+    VideoFilterFromJSON,
+    VideoFilterFromJSONTyped,
+    VideoFilterToJSON,
+    VideoFilterToJSONTyped,
+} from './VideoFilter';
 import type { State } from './State';
 import {
     StateFromJSON,
@@ -34,13 +104,13 @@ import {
     StateToJSON,
     StateToJSONTyped,
 } from './State';
-import type { Colorspace } from './Colorspace';
+import type { SubtitleTrack } from './SubtitleTrack';
 import {
-    ColorspaceFromJSON,
-    ColorspaceFromJSONTyped,
-    ColorspaceToJSON,
-    ColorspaceToJSONTyped,
-} from './Colorspace';
+    SubtitleTrackFromJSON,
+    SubtitleTrackFromJSONTyped,
+    SubtitleTrackToJSON,
+    SubtitleTrackToJSONTyped,
+} from './SubtitleTrack';
 import type { Acceleration } from './Acceleration';
 import {
     AccelerationFromJSON,
@@ -48,13 +118,6 @@ import {
     AccelerationToJSON,
     AccelerationToJSONTyped,
 } from './Acceleration';
-import type { VideoAdaptationSet } from './VideoAdaptationSet';
-import {
-    VideoAdaptationSetFromJSON,
-    VideoAdaptationSetFromJSONTyped,
-    VideoAdaptationSetToJSON,
-    VideoAdaptationSetToJSONTyped,
-} from './VideoAdaptationSet';
 import type { AudioAdaptationSet } from './AudioAdaptationSet';
 import {
     AudioAdaptationSetFromJSON,
@@ -62,13 +125,6 @@ import {
     AudioAdaptationSetToJSON,
     AudioAdaptationSetToJSONTyped,
 } from './AudioAdaptationSet';
-import type { VideoFilterDeinterlace } from './VideoFilterDeinterlace';
-import {
-    VideoFilterDeinterlaceFromJSON,
-    VideoFilterDeinterlaceFromJSONTyped,
-    VideoFilterDeinterlaceToJSON,
-    VideoFilterDeinterlaceToJSONTyped,
-} from './VideoFilterDeinterlace';
 
 /**
  * 
@@ -90,10 +146,22 @@ export interface FFConfig {
     astDelayMs: number;
     /**
      * 
-     * @type {AudioAdaptationSet}
+     * @type {Array<AudioAdaptationSet>}
      * @memberof FFConfig
      */
-    audioAdaptationSet: AudioAdaptationSet;
+    audioAdaptationSet: Array<AudioAdaptationSet>;
+    /**
+     * 
+     * @type {Array<Input>}
+     * @memberof FFConfig
+     */
+    backupInputs?: Array<Input>;
+    /**
+     * 
+     * @type {ClosedCaptions}
+     * @memberof FFConfig
+     */
+    closedCaptions?: ClosedCaptions | null;
     /**
      * 
      * @type {Colorspace}
@@ -101,7 +169,7 @@ export interface FFConfig {
      */
     colorspace: Colorspace;
     /**
-     * 
+     * Deinterlacing with the default options, before the video filters. Ignored if the video filters have a deinterlacing.
      * @type {VideoFilterDeinterlace}
      * @memberof FFConfig
      */
@@ -130,6 +198,12 @@ export interface FFConfig {
      * @memberof FFConfig
      */
     gpuUid: string;
+    /**
+     * HDR static metadata of the BT2020PQ and BT2020HLG colorspaces
+     * @type {HdrMetadata}
+     * @memberof FFConfig
+     */
+    hdrMetadata?: HdrMetadata;
     /**
      * 
      * @type {string}
@@ -138,10 +212,16 @@ export interface FFConfig {
     initSegName: string;
     /**
      * 
-     * @type {Srt}
+     * @type {Input}
      * @memberof FFConfig
      */
-    input: Srt;
+    input: Input;
+    /**
+     * 
+     * @type {InputFailover}
+     * @memberof FFConfig
+     */
+    inputFailover?: InputFailover;
     /**
      * 
      * @type {string}
@@ -166,6 +246,12 @@ export interface FFConfig {
      * @memberof FFConfig
      */
     output: string;
+    /**
+     * 
+     * @type {Scte35}
+     * @memberof FFConfig
+     */
+    scte35?: Scte35 | null;
     /**
      * 
      * @type {number}
@@ -184,6 +270,24 @@ export interface FFConfig {
      * @memberof FFConfig
      */
     state: State;
+    /**
+     * 
+     * @type {StreamSelection}
+     * @memberof FFConfig
+     */
+    streamSelection?: StreamSelection;
+    /**
+     * 
+     * @type {Array<SubtitleTrack>}
+     * @memberof FFConfig
+     */
+    subtitles?: Array<SubtitleTrack>;
+    /**
+     * 
+     * @type {Thumbnails}
+     * @memberof FFConfig
+     */
+    thumbnails?: Thumbnails | null;
     /**
      * 
      * @type {string}
@@ -202,6 +306,12 @@ export interface FFConfig {
      * @memberof FFConfig
      */
     videoAdaptationSet: Array<VideoAdaptationSet>;
+    /**
+     * Filters applied in order to the source, before the scaling
+     * @type {Array<VideoFilter>}
+     * @memberof FFConfig
+     */
+    videoFilters?: Array<VideoFilter>;
     /**
      * 
      * @type {number}
@@ -249,25 +359,34 @@ export function FFConfigFromJSONTyped(json: any, ignoreDiscriminator: boolean):
         
         'acceleration': json['acceleration'] == null ? undefined : AccelerationFromJSON(json['acceleration']),
         'astDelayMs': json['ast_delay_ms'],
-        'audioAdaptationSet': AudioAdaptationSetFromJSON(json['audio_adaptation_set']),
+        'audioAdaptationSet': ((json['audio_adaptation_set'] as Array<any>).map(AudioAdaptationSetFromJSON)),
+        'backupInputs': json['backup_inputs'] == null ? undefined : ((json['backup_inputs'] as Array<any>).map(InputFromJSON)),
+        'closedCaptions': json['closed_captions'] == null ? undefined : ClosedCaptionsFromJSON(json['closed_captions']),
         'colorspace': ColorspaceFromJSON(json['colorspace']),
         'deinterlace': VideoFilterDeinterlaceFromJSON(json['deinterlace']),
         'enableHls': json['enable_hls'],
         'encryptionKey': json['encryption_key'] == null ? undefined : json['encryption_key'],
         'encryptionKid': json['encryption_kid'] == null ? undefined : json['encryption_kid'],
         'gpuUid': json['gpu_uid'],
+        'hdrMetadata': json['hdr_metadata'] == null ? undefined : HdrMetadataFromJSON(json['hdr_metadata']),
         'initSegName': json['init_seg_name'],
-        'input': SrtFromJSON(json['input']),
+        'input': InputFromJSON(json['input']),
+        'inputFailover': json['input_failover'] == null ? undefined : InputFailoverFromJSON(json['input_failover']),
         'mediaSegName': json['media_seg_name'],
         'mpdType': MPDTypeFromJSON(json['mpd_type']),
         'name': json['name'],
         'output': json['output'],
+        'scte35': json['scte35'] == null ? undefined : Scte35FromJSON(json['scte35']),
         'segmentDurationMs': json['segment_duration_ms'],
         'serverUid': json['server_uid'],
         'state': StateFromJSON(json['state']),
+        'streamSelection': json['stream_selection'] == null ? undefined : StreamSelectionFromJSON(json['stream_selection']),
+        'subtitles': json['subtitles'] == null ? undefined : ((json['subtitles'] as Array<any>).map(SubtitleTrackFromJSON)),
+        'thumbnails': json['thumbnails'] == null ? undefined : ThumbnailsFromJSON(json['thumbnails']),
         'uid': json['uid'],
         'utcTimingUrl': json['utc_timing_url'] == null ? undefined : json['utc_timing_url'],
         'videoAdaptationSet': ((json['video_adaptation_set'] as Array<any>).map(VideoAdaptationSetFromJSON)),
+        'videoFilters': json['video_filters'] == null ? undefined : ((json['video_filters'] as Array<any>).map(VideoFilterFromJSON)),
         'windowSize': json['window_size'],
     };
 }
@@ -285,25 +404,34 @@ export function FFConfigFromJSONTyped(json: any, ignoreDiscriminator: boolean):
         
         'acceleration': AccelerationToJSON(value['acceleration']),
         'ast_delay_ms': value['astDelayMs'],
-        'audio_adaptation_set': AudioAdaptationSetToJSON(value['audioAdaptationSet']),
+        'audio_adaptation_set': ((value['audioAdaptationSet'] as Array<any>).map(AudioAdaptationSetToJSON)),
+        'backup_inputs': value['backupInputs'] == null ? undefined : ((value['backupInputs'] as Array<any>).map(InputToJSON)),
+        'closed_captions': ClosedCaptionsToJSON(value['closedCaptions']),
         'colorspace': ColorspaceToJSON(value['colorspace']),
         'deinterlace': VideoFilterDeinterlaceToJSON(value['deinterlace']),
         'enable_hls': value['enableHls'],
         'encryption_key': value['encryptionKey'],
         'encryption_kid': value['encryptionKid'],
         'gpu_uid': value['gpuUid'],
+        'hdr_metadata': HdrMetadataToJSON(value['hdrMetadata']),
         'init_seg_name': value['initSegName'],
-        'input': SrtToJSON(value['input']),
+        'input': InputToJSON(value['input']),
+        'input_failover': InputFailoverToJSON(value['inputFailover']),
         'media_seg_name': value['mediaSegName'],
         'mpd_type': MPDTypeToJSON(value['mpdType']),
         'name': value['name'],
         'output': value['output'],
+        'scte35': Scte35ToJSON(value['scte35']),
         'segment_duration_ms': value['segmentDurationMs'],
         'server_uid': value['serverUid'],
         'state': StateToJSON(value['state']),
+        'stream_selection': StreamSelectionToJSON(value['streamSelection']),
+        'subtitles': value['subtitles'] == null ? undefined : ((value['subtitles'] as Array<any>).map(SubtitleTrackToJSON)),
+        'thumbnails': ThumbnailsToJSON(value['thumbnails']),
         'uid': value['uid'],
         'utc_timing_url': value['utcTimingUrl'],
         'video_adaptation_set': ((value['videoAdaptationSet'] as Array<any>).map(VideoAdaptationSetToJSON)),
+        'video_filters': value['videoFilters'] == null ? undefined : ((value['videoFilters'] as Array<any>).map(VideoFilterToJSON)),
         'window_size': value['windowSize'],
     };
 }
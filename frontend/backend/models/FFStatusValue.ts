@@ -4,7 +4,7 @@
  * live-cmaf-transcoder
  * API for the Live CMAF Transcoder
  *
- * The version of the OpenAPI document: 0.1.52
+ * The version of the OpenAPI document: 0.1.57
  * 
  *
  * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
@@ -20,6 +20,27 @@ import {
     CoreStateToJSON,
     CoreStateToJSONTyped,
 } from './CoreState';
+import type { DetectedFieldOrder } from './DetectedFieldOrder';
+import {
+    DetectedFieldOrderFromJSON,
+    DetectedFieldOrderFromJSONTyped,
+    DetectedFieldOrderToJSON,
+    DetectedFieldOrderToJSONTyped,
+} from './DetectedFieldOrder';
+import type { InputSwitchReason } from './InputSwitchReason';
+import {
+    InputSwitchReasonFromJSON,
+    InputSwitchReasonFromJSONTyped,
+    InputSwitchReasonToJSON,
+    InputSwitchReasonToJSONTyped,
+} from './InputSwitchReason';
+import type { LoudnessValue } from './LoudnessValue';
+import {
+    LoudnessValueFromJSON,
+    LoudnessValueFromJSONTyped,
+    LoudnessValueToJSON,
+    LoudnessValueToJSONTyped,
+} from './LoudnessValue';
 
 /**
  * 
@@ -27,6 +48,12 @@ import {
  * @interface FFStatusValue
  */
 export interface FFStatusValue {
+    /**
+     * 
+     * @type {number}
+     * @memberof FFStatusValue
+     */
+    activeInput?: number;
     /**
      * 
      * @type {string}
@@ -57,18 +84,42 @@ export interface FFStatusValue {
      * @memberof FFStatusValue
      */
     duplicateFrames: number;
+    /**
+     * 
+     * @type {DetectedFieldOrder}
+     * @memberof FFStatusValue
+     */
+    fieldOrder?: DetectedFieldOrder | null;
     /**
      * 
      * @type {number}
      * @memberof FFStatusValue
      */
     fps: number;
+    /**
+     * 
+     * @type {InputSwitchReason}
+     * @memberof FFStatusValue
+     */
+    lastInputSwitch?: InputSwitchReason | null;
+    /**
+     * 
+     * @type {LoudnessValue}
+     * @memberof FFStatusValue
+     */
+    loudness?: LoudnessValue | null;
     /**
      * 
      * @type {number}
      * @memberof FFStatusValue
      */
     memoryUsage: number;
+    /**
+     * 
+     * @type {number}
+     * @memberof FFStatusValue
+     */
+    nbInputSwitch?: number;
     /**
      * 
      * @type {number}
@@ -118,13 +169,18 @@ export function FFStatusValueFromJSONTyped(json: any, ignoreDiscriminator: boole
     }
     return {
         
+        'activeInput': json['active_input'] == null ? undefined : json['active_input'],
         'configUid': json['config_uid'],
         'cpuUsage': json['cpu_usage'],
         'currentState': CoreStateFromJSON(json['current_state']),
         'dropFrames': json['drop_frames'],
         'duplicateFrames': json['duplicate_frames'],
+        'fieldOrder': json['field_order'] == null ? undefined : DetectedFieldOrderFromJSON(json['field_order']),
         'fps': json['fps'],
+        'lastInputSwitch': json['last_input_switch'] == null ? undefined : InputSwitchReasonFromJSON(json['last_input_switch']),
+        'loudness': json['loudness'] == null ? undefined : LoudnessValueFromJSON(json['loudness']),
         'memoryUsage': json['memory_usage'],
+        'nbInputSwitch': json['nb_input_switch'] == null ? undefined : json['nb_input_switch'],
         'nbRestart': json['nb_restart'],
         'outTimeMs': json['out_time_ms'],
         'speed': json['speed'],
@@ -142,13 +198,18 @@ export function FFStatusValueFromJSONTyped(json: any, ignoreDiscriminator: boole
 
     return {
         
+        'active_input': value['activeInput'],
         'config_uid': value['configUid'],
         'cpu_usage': value['cpuUsage'],
         'current_state': CoreStateToJSON(value['currentState']),
         'drop_frames': value['dropFrames'],
         'duplicate_frames': value['duplicateFrames'],
+        'field_order': DetectedFieldOrderToJSON(value['fieldOrder']),
         'fps': value['fps'],
+        'last_input_switch': InputSwitchReasonToJSON(value['lastInputSwitch']),
+        'loudness': LoudnessValueToJSON(value['loudness']),
         'memory_usage': value['memoryUsage'],
+        'nb_input_switch': value['nbInputSwitch'],
         'nb_restart': value['nbRestart'],
         'out_time_ms': value['outTimeMs'],
         'speed': value['speed'],
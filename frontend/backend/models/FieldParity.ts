@@ -0,0 +1,54 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * live-cmaf-transcoder
+ * API for the Live CMAF Transcoder
+ *
+ * The version of the OpenAPI document: 0.1.57
+ * 
+ *
+ * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
+ * https://openapi-generator.tech
+ * Do not edit the class manually.
+ */
+
+
+/**
+ * Field order of interlaced frames
+ * @export
+ */
+export const FieldParity = {
+    Auto: 'Auto',
+    Tff: 'Tff',
+    Bff: 'Bff'
+} as const;
+export type FieldParity = typeof FieldParity[keyof typeof FieldParity];
+
+
+export function instanceOfFieldParity(value: any): boolean {
+    for (const key in FieldParity) {
+        if (Object.prototype.hasOwnProperty.call(FieldParity, key)) {
+            if (FieldParity[key as keyof typeof FieldParity] === value) {
+                return true;
+            }
+        }
+    }
+    return false;
+}
+
+export function FieldParityFromJSON(json: any): FieldParity {
+    return FieldParityFromJSONTyped(json, false);
+}
+
+export function FieldParityFromJSONTyped(json: any, ignoreDiscriminator: boolean): FieldParity {
+    return json as FieldParity;
+}
+
+export function FieldParityToJSON(value?: FieldParity | null): any {
+    return value as any;
+}
+
+export function FieldParityToJSONTyped(value: any, ignoreDiscriminator: boolean): FieldParity {
+    return value as FieldParity;
+}
+
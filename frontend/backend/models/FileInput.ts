@@ -0,0 +1,75 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * live-cmaf-transcoder
+ * API for the Live CMAF Transcoder
+ *
+ * The version of the OpenAPI document: 0.1.57
+ * 
+ *
+ * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
+ * https://openapi-generator.tech
+ * Do not edit the class manually.
+ */
+
+import { mapValues } from '../runtime';
+/**
+ * 
+ * @export
+ * @interface FileInput
+ */
+export interface FileInput {
+    /**
+     * 
+     * @type {boolean}
+     * @memberof FileInput
+     */
+    loopInput: boolean;
+    /**
+     * 
+     * @type {string}
+     * @memberof FileInput
+     */
+    path: string;
+}
+
+/**
+ * Check if a given object implements the FileInput interface.
+ */
+export function instanceOfFileInput(value: object): value is FileInput {
+    if (!('loopInput' in value) || value['loopInput'] === undefined) return false;
+    if (!('path' in value) || value['path'] === undefined) return false;
+    return true;
+}
+
+export function FileInputFromJSON(json: any): FileInput {
+    return FileInputFromJSONTyped(json, false);
+}
+
+export function FileInputFromJSONTyped(json: any, ignoreDiscriminator: boolean): FileInput {
+    if (json == null) {
+        return json;
+    }
+    return {
+        
+        'loopInput': json['loop_input'],
+        'path': json['path'],
+    };
+}
+
+  export function FileInputToJSON(json: any): FileInput {
+      return FileInputToJSONTyped(json, false);
+  }
+
+  export function FileInputToJSONTyped(value?: FileInput | null, ignoreDiscriminator: boolean = false): any {
+    if (value == null) {
+        return value;
+    }
+
+    return {
+        
+        'loop_input': value['loopInput'],
+        'path': value['path'],
+    };
+}
+
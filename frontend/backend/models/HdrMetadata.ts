@@ -0,0 +1,81 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * live-cmaf-transcoder
+ * API for the Live CMAF Transcoder
+ *
+ * The version of the OpenAPI document: 0.1.57
+ * 
+ *
+ * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
+ * https://openapi-generator.tech
+ * Do not edit the class manually.
+ */
+
+import { mapValues } from '../runtime';
+/**
+ * HDR static metadata, the metadata of the source is passed through if not set
+ * @export
+ * @interface HdrMetadata
+ */
+export interface HdrMetadata {
+    /**
+     * SMPTE ST 2086 mastering display in the x265 syntax, chromaticities in 0.00002 units and luminance in 0.0001 cd/m², ex: G(13250,34500)B(7500,3000)R(34000,16000)WP(15635,16450)L(10000000,1)
+     * @type {string}
+     * @memberof HdrMetadata
+     */
+    masteringDisplay?: string | null;
+    /**
+     * Maximum content light level, in cd/m²
+     * @type {number}
+     * @memberof HdrMetadata
+     */
+    maxCll?: number | null;
+    /**
+     * Maximum frame-average light level, in cd/m²
+     * @type {number}
+     * @memberof HdrMetadata
+     */
+    maxFall?: number | null;
+}
+
+/**
+ * Check if a given object implements the HdrMetadata interface.
+ */
+export function instanceOfHdrMetadata(value: object): value is HdrMetadata {
+    return true;
+}
+
+export function HdrMetadataFromJSON(json: any): HdrMetadata {
+    return HdrMetadataFromJSONTyped(json, false);
+}
+
+export function HdrMetadataFromJSONTyped(json: any, ignoreDiscriminator: boolean): HdrMetadata {
+    if (json == null) {
+        return json;
+    }
+    return {
+        
+        'masteringDisplay': json['mastering_display'] == null ? undefined : json['mastering_display'],
+        'maxCll': json['max_cll'] == null ? undefined : json['max_cll'],
+        'maxFall': json['max_fall'] == null ? undefined : json['max_fall'],
+    };
+}
+
+  export function HdrMetadataToJSON(json: any): HdrMetadata {
+      return HdrMetadataToJSONTyped(json, false);
+  }
+
+  export function HdrMetadataToJSONTyped(value?: HdrMetadata | null, ignoreDiscriminator: boolean = false): any {
+    if (value == null) {
+        return value;
+    }
+
+    return {
+        
+        'mastering_display': value['masteringDisplay'],
+        'max_cll': value['maxCll'],
+        'max_fall': value['maxFall'],
+    };
+}
+
@@ -0,0 +1,94 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * live-cmaf-transcoder
+ * API for the Live CMAF Transcoder
+ *
+ * The version of the OpenAPI document: 0.1.57
+ * 
+ *
+ * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
+ * https://openapi-generator.tech
+ * Do not edit the class manually.
+ */
+
+import { mapValues } from '../runtime';
+import type { PullFormat } from './PullFormat';
+import {
+    PullFormatFromJSON,
+    PullFormatFromJSONTyped,
+    PullFormatToJSON,
+    PullFormatToJSONTyped,
+} from './PullFormat';
+
+/**
+ * 
+ * @export
+ * @interface HttpPullInput
+ */
+export interface HttpPullInput {
+    /**
+     * 
+     * @type {PullFormat}
+     * @memberof HttpPullInput
+     */
+    format: PullFormat;
+    /**
+     * Read the input at its native frame rate
+     * @type {boolean}
+     * @memberof HttpPullInput
+     */
+    realtime: boolean;
+    /**
+     * 
+     * @type {string}
+     * @memberof HttpPullInput
+     */
+    uri: string;
+}
+
+
+
+/**
+ * Check if a given object implements the HttpPullInput interface.
+ */
+export function instanceOfHttpPullInput(value: object): value is HttpPullInput {
+    if (!('format' in value) || value['format'] === undefined) return false;
+    if (!('realtime' in value) || value['realtime'] === undefined) return false;
+    if (!('uri' in value) || value['uri'] === undefined) return false;
+    return true;
+}
+
+export function HttpPullInputFromJSON(json: any): HttpPullInput {
+    return HttpPullInputFromJSONTyped(json, false);
+}
+
+export function HttpPullInputFromJSONTyped(json: any, ignoreDiscriminator: boolean): HttpPullInput {
+    if (json == null) {
+        return json;
+    }
+    return {
+        
+        'format': PullFormatFromJSON(json['format']),
+        'realtime': json['realtime'],
+        'uri': json['uri'],
+    };
+}
+
+  export function HttpPullInputToJSON(json: any): HttpPullInput {
+      return HttpPullInputToJSONTyped(json, false);
+  }
+
+  export function HttpPullInputToJSONTyped(value?: HttpPullInput | null, ignoreDiscriminator: boolean = false): any {
+    if (value == null) {
+        return value;
+    }
+
+    return {
+        
+        'format': PullFormatToJSON(value['format']),
+        'realtime': value['realtime'],
+        'uri': value['uri'],
+    };
+}
+
@@ -0,0 +1,117 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * live-cmaf-transcoder
+ * API for the Live CMAF Transcoder
+ *
+ * The version of the OpenAPI document: 0.1.57
+ * 
+ *
+ * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
+ * https://openapi-generator.tech
+ * Do not edit the class manually.
+ */
+
+import { mapValues } from '../runtime';
+import type { MarkerType } from './MarkerType';
+import {
+    MarkerTypeFromJSON,
+    MarkerTypeFromJSONTyped,
+    MarkerTypeToJSON,
+    MarkerTypeToJSONTyped,
+} from './MarkerType';
+import type { InConfigID } from './InConfigID';
+import {
+    InConfigIDFromJSON,
+    InConfigIDFromJSONTyped,
+    InConfigIDToJSON,
+    InConfigIDToJSONTyped,
+} from './InConfigID';
+
+/**
+ * 
+ * @export
+ * @interface InFFInsertMarker
+ */
+export interface InFFInsertMarker {
+    /**
+     * Duration of the ad break or of the metadata, 0 if unknown
+     * @type {number}
+     * @memberof InFFInsertMarker
+     */
+    durationMs?: number;
+    /**
+     * 
+     * @type {InConfigID}
+     * @memberof InFFInsertMarker
+     */
+    id: InConfigID;
+    /**
+     * 
+     * @type {MarkerType}
+     * @memberof InFFInsertMarker
+     */
+    markerType: MarkerType;
+    /**
+     * Hexadecimal splice_info_section for the splices, generated if empty, text of an ID3 TXXX frame for the timed metadata
+     * @type {string}
+     * @memberof InFFInsertMarker
+     */
+    payload?: string;
+    /**
+     * Time to wait for the marker to be inserted by the running encoder, 10 s at most
+     * @type {number}
+     * @memberof InFFInsertMarker
+     */
+    timeoutMs: number;
+}
+
+
+
+/**
+ * Check if a given object implements the InFFInsertMarker interface.
+ */
+export function instanceOfInFFInsertMarker(value: object): value is InFFInsertMarker {
+    if (!('id' in value) || value['id'] === undefined) return false;
+    if (!('markerType' in value) || value['markerType'] === undefined) return false;
+    if (!('timeoutMs' in value) || value['timeoutMs'] === undefined) return false;
+    return true;
+}
+
+export function InFFInsertMarkerFromJSON(json: any): InFFInsertMarker {
+    return InFFInsertMarkerFromJSONTyped(json, false);
+}
+
+export function InFFInsertMarkerFromJSONTyped(json: any, ignoreDiscriminator: boolean): InFFInsertMarker {
+    if (json == null) {
+        return json;
+    }
+    return {
+        
+        'durationMs': json['duration_ms'] == null ? undefined : json['duration_ms'],
+        'id': InConfigIDFromJSON(json['id']),
+        'markerType': MarkerTypeFromJSON(json['marker_type']),
+        'payload': json['payload'] == null ? undefined : json['payload'],
+        'timeoutMs': json['timeout_ms'],
+    };
+}
+
+  export function InFFInsertMarkerToJSON(json: any): InFFInsertMarker {
+      return InFFInsertMarkerToJSONTyped(json, false);
+  }
+
+  export function InFFInsertMarkerToJSONTyped(value?: InFFInsertMarker | null, ignoreDiscriminator: boolean = false): any {
+    if (value == null) {
+        return value;
+    }
+
+    return {
+        
+        'duration_ms': value['durationMs'],
+        'id': InConfigIDToJSON(value['id']),
+        'marker_type': MarkerTypeToJSON(value['markerType']),
+        'payload': value['payload'],
+        'timeout_ms': value['timeoutMs'],
+    };
+}
+
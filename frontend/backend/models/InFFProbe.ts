@@ -0,0 +1,100 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * live-cmaf-transcoder
+ * API for the Live CMAF Transcoder
+ *
+ * The version of the OpenAPI document: 0.1.57
+ * 
+ *
+ * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
+ * https://openapi-generator.tech
+ * Do not edit the class manually.
+ */
+
+import { mapValues } from '../runtime';
+import type { Input } from './Input';
+import {
+    InputFromJSON,
+    InputFromJSONTyped,
+    InputToJSON,
+    InputToJSONTyped,
+} from './Input';
+
+/**
+ * 
+ * @export
+ * @interface InFFProbe
+ */
+export interface InFFProbe {
+    /**
+     * Measure the black bars of the first video stream, takes up to another timeout
+     * @type {boolean}
+     * @memberof InFFProbe
+     */
+    detectCrop?: boolean;
+    /**
+     * 
+     * @type {Input}
+     * @memberof InFFProbe
+     */
+    input: Input;
+    /**
+     * Server running ffprobe, the server of the config reading the input
+     * @type {string}
+     * @memberof InFFProbe
+     */
+    serverUid: string;
+    /**
+     * 30 s at most
+     * @type {number}
+     * @memberof InFFProbe
+     */
+    timeoutMs: number;
+}
+
+/**
+ * Check if a given object implements the InFFProbe interface.
+ */
+export function instanceOfInFFProbe(value: object): value is InFFProbe {
+    if (!('input' in value) || value['input'] === undefined) return false;
+    if (!('serverUid' in value) || value['serverUid'] === undefined) return false;
+    if (!('timeoutMs' in value) || value['timeoutMs'] === undefined) return false;
+    return true;
+}
+
+export function InFFProbeFromJSON(json: any): InFFProbe {
+    return InFFProbeFromJSONTyped(json, false);
+}
+
+export function InFFProbeFromJSONTyped(json: any, ignoreDiscriminator: boolean): InFFProbe {
+    if (json == null) {
+        return json;
+    }
+    return {
+        
+        'detectCrop': json['detect_crop'] == null ? undefined : json['detect_crop'],
+        'input': InputFromJSON(json['input']),
+        'serverUid': json['server_uid'],
+        'timeoutMs': json['timeout_ms'],
+    };
+}
+
+  export function InFFProbeToJSON(json: any): InFFProbe {
+      return InFFProbeToJSONTyped(json, false);
+  }
+
+  export function InFFProbeToJSONTyped(value?: InFFProbe | null, ignoreDiscriminator: boolean = false): any {
+    if (value == null) {
+        return value;
+    }
+
+    return {
+        
+        'detect_crop': value['detectCrop'],
+        'input': InputToJSON(value['input']),
+        'server_uid': value['serverUid'],
+        'timeout_ms': value['timeoutMs'],
+    };
+}
+
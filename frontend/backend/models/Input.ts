@@ -0,0 +1,143 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * live-cmaf-transcoder
+ * API for the Live CMAF Transcoder
+ *
+ * The version of the OpenAPI document: 0.1.57
+ * 
+ *
+ * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
+ * https://openapi-generator.tech
+ * Do not edit the class manually.
+ */
+
+import type { InputOneOf } from './InputOneOf';
+import {
+    instanceOfInputOneOf,
+    InputOneOfFromJSON,
+    InputOneOfFromJSONTyped,
+    InputOneOfToJSON,
+} from './InputOneOf';
+import type { InputOneOf1 } from './InputOneOf1';
+import {
+    instanceOfInputOneOf1,
+    InputOneOf1FromJSON,
+    InputOneOf1FromJSONTyped,
+    InputOneOf1ToJSON,
+} from './InputOneOf1';
+import type { InputOneOf2 } from './InputOneOf2';
+import {
+    instanceOfInputOneOf2,
+    InputOneOf2FromJSON,
+    InputOneOf2FromJSONTyped,
+    InputOneOf2ToJSON,
+} from './InputOneOf2';
+import type { InputOneOf3 } from './InputOneOf3';
+import {
+    instanceOfInputOneOf3,
+    InputOneOf3FromJSON,
+    InputOneOf3FromJSONTyped,
+    InputOneOf3ToJSON,
+} from './InputOneOf3';
+import type { InputOneOf4 } from './InputOneOf4';
+import {
+    instanceOfInputOneOf4,
+    InputOneOf4FromJSON,
+    InputOneOf4FromJSONTyped,
+    InputOneOf4ToJSON,
+} from './InputOneOf4';
+import type { InputOneOf5 } from './InputOneOf5';
+import {
+    instanceOfInputOneOf5,
+    InputOneOf5FromJSON,
+    InputOneOf5FromJSONTyped,
+    InputOneOf5ToJSON,
+} from './InputOneOf5';
+import type { InputOneOf6 } from './InputOneOf6';
+import {
+    instanceOfInputOneOf6,
+    InputOneOf6FromJSON,
+    InputOneOf6FromJSONTyped,
+    InputOneOf6ToJSON,
+} from './InputOneOf6';
+
+/**
+ * @type Input
+ * 
+ * @export
+ */
+export type Input = InputOneOf | InputOneOf1 | InputOneOf2 | InputOneOf3 | InputOneOf4 | InputOneOf5 | InputOneOf6;
+
+export function InputFromJSON(json: any): Input {
+    return InputFromJSONTyped(json, false);
+}
+
+export function InputFromJSONTyped(json: any, ignoreDiscriminator: boolean): Input {
+    if (json == null) {
+        return json;
+    }
+    if (typeof json !== 'object') {
+        return json;
+    }
+    if ('Srt' in json) {
+        return InputOneOfFromJSONTyped(json, true);
+    }
+    if ('Rtmp' in json) {
+        return InputOneOf1FromJSONTyped(json, true);
+    }
+    if ('Rist' in json) {
+        return InputOneOf2FromJSONTyped(json, true);
+    }
+    if ('Udp' in json) {
+        return InputOneOf3FromJSONTyped(json, true);
+    }
+    if ('HttpPull' in json) {
+        return InputOneOf4FromJSONTyped(json, true);
+    }
+    if ('File' in json) {
+        return InputOneOf5FromJSONTyped(json, true);
+    }
+    if ('Url' in json) {
+        return InputOneOf6FromJSONTyped(json, true);
+    }
+
+    return {} as any;
+}
+
+export function InputToJSON(json: any): any {
+    return InputToJSONTyped(json, false);
+}
+
+export function InputToJSONTyped(value?: Input | null, ignoreDiscriminator: boolean = false): any {
+    if (value == null) {
+        return value;
+    }
+    if (typeof value !== 'object') {
+        return value;
+    }
+    if (instanceOfInputOneOf(value)) {
+        return InputOneOfToJSON(value as InputOneOf);
+    }
+    if (instanceOfInputOneOf1(value)) {
+        return InputOneOf1ToJSON(value as InputOneOf1);
+    }
+    if (instanceOfInputOneOf2(value)) {
+        return InputOneOf2ToJSON(value as InputOneOf2);
+    }
+    if (instanceOfInputOneOf3(value)) {
+        return InputOneOf3ToJSON(value as InputOneOf3);
+    }
+    if (instanceOfInputOneOf4(value)) {
+        return InputOneOf4ToJSON(value as InputOneOf4);
+    }
+    if (instanceOfInputOneOf5(value)) {
+        return InputOneOf5ToJSON(value as InputOneOf5);
+    }
+    if (instanceOfInputOneOf6(value)) {
+        return InputOneOf6ToJSON(value as InputOneOf6);
+    }
+
+    return {};
+}
+
@@ -0,0 +1,89 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * live-cmaf-transcoder
+ * API for the Live CMAF Transcoder
+ *
+ * The version of the OpenAPI document: 0.1.57
+ * 
+ *
+ * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
+ * https://openapi-generator.tech
+ * Do not edit the class manually.
+ */
+
+import { mapValues } from '../runtime';
+/**
+ * Rules used to switch between the primary input and the backup inputs. A value of 0 disables the corresponding rule.
+ * @export
+ * @interface InputFailover
+ */
+export interface InputFailover {
+    /**
+     * Switch to the next input when the encoder has not been running for this duration
+     * @type {number}
+     * @memberof InputFailover
+     */
+    inputTimeoutMs?: number;
+    /**
+     * Switch to the next input after this number of consecutive \"end of the stream\" events
+     * @type {number}
+     * @memberof InputFailover
+     */
+    maxEndOfStream?: number;
+    /**
+     * Go back to the primary input once a backup input has been running for `return_to_primary_delay_ms` and a probe of the primary input succeeds. The probe is repeated after the same delay while the primary input is down.
+     * @type {boolean}
+     * @memberof InputFailover
+     */
+    returnToPrimary?: boolean;
+    /**
+     * 
+     * @type {number}
+     * @memberof InputFailover
+     */
+    returnToPrimaryDelayMs?: number;
+}
+
+/**
+ * Check if a given object implements the InputFailover interface.
+ */
+export function instanceOfInputFailover(value: object): value is InputFailover {
+    return true;
+}
+
+export function InputFailoverFromJSON(json: any): InputFailover {
+    return InputFailoverFromJSONTyped(json, false);
+}
+
+export function InputFailoverFromJSONTyped(json: any, ignoreDiscriminator: boolean): InputFailover {
+    if (json == null) {
+        return json;
+    }
+    return {
+        
+        'inputTimeoutMs': json['input_timeout_ms'] == null ? undefined : json['input_timeout_ms'],
+        'maxEndOfStream': json['max_end_of_stream'] == null ? undefined : json['max_end_of_stream'],
+        'returnToPrimary': json['return_to_primary'] == null ? undefined : json['return_to_primary'],
+        'returnToPrimaryDelayMs': json['return_to_primary_delay_ms'] == null ? undefined : json['return_to_primary_delay_ms'],
+    };
+}
+
+  export function InputFailoverToJSON(json: any): InputFailover {
+      return InputFailoverToJSONTyped(json, false);
+  }
+
+  export function InputFailoverToJSONTyped(value?: InputFailover | null, ignoreDiscriminator: boolean = false): any {
+    if (value == null) {
+        return value;
+    }
+
+    return {
+        
+        'input_timeout_ms': value['inputTimeoutMs'],
+        'max_end_of_stream': value['maxEndOfStream'],
+        'return_to_primary': value['returnToPrimary'],
+        'return_to_primary_delay_ms': value['returnToPrimaryDelayMs'],
+    };
+}
+
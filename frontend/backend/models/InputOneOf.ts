@@ -0,0 +1,74 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * live-cmaf-transcoder
+ * API for the Live CMAF Transcoder
+ *
+ * The version of the OpenAPI document: 0.1.57
+ * 
+ *
+ * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
+ * https://openapi-generator.tech
+ * Do not edit the class manually.
+ */
+
+import { mapValues } from '../runtime';
+import type { Srt } from './Srt';
+import {
+    SrtFromJSON,
+    SrtFromJSONTyped,
+    SrtToJSON,
+    SrtToJSONTyped,
+} from './Srt';
+
+/**
+ * 
+ * @export
+ * @interface InputOneOf
+ */
+export interface InputOneOf {
+    /**
+     * 
+     * @type {Srt}
+     * @memberof InputOneOf
+     */
+    srt: Srt;
+}
+
+/**
+ * Check if a given object implements the InputOneOf interface.
+ */
+export function instanceOfInputOneOf(value: object): value is InputOneOf {
+    if (!('srt' in value) || value['srt'] === undefined) return false;
+    return true;
+}
+
+export function InputOneOfFromJSON(json: any): InputOneOf {
+    return InputOneOfFromJSONTyped(json, false);
+}
+
+export function InputOneOfFromJSONTyped(json: any, ignoreDiscriminator: boolean): InputOneOf {
+    if (json == null) {
+        return json;
+    }
+    return {
+        
+        'srt': SrtFromJSON(json['Srt']),
+    };
+}
+
+  export function InputOneOfToJSON(json: any): InputOneOf {
+      return InputOneOfToJSONTyped(json, false);
+  }
+
+  export function InputOneOfToJSONTyped(value?: InputOneOf | null, ignoreDiscriminator: boolean = false): any {
+    if (value == null) {
+        return value;
+    }
+
+    return {
+        
+        'Srt': SrtToJSON(value['srt']),
+    };
+}
+
@@ -0,0 +1,74 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * live-cmaf-transcoder
+ * API for the Live CMAF Transcoder
+ *
+ * The version of the OpenAPI document: 0.1.57
+ * 
+ *
+ * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
+ * https://openapi-generator.tech
+ * Do not edit the class manually.
+ */
+
+import { mapValues } from '../runtime';
+import type { RtmpInput } from './RtmpInput';
+import {
+    RtmpInputFromJSON,
+    RtmpInputFromJSONTyped,
+    RtmpInputToJSON,
+    RtmpInputToJSONTyped,
+} from './RtmpInput';
+
+/**
+ * 
+ * @export
+ * @interface InputOneOf1
+ */
+export interface InputOneOf1 {
+    /**
+     * 
+     * @type {RtmpInput}
+     * @memberof InputOneOf1
+     */
+    rtmp: RtmpInput;
+}
+
+/**
+ * Check if a given object implements the InputOneOf1 interface.
+ */
+export function instanceOfInputOneOf1(value: object): value is InputOneOf1 {
+    if (!('rtmp' in value) || value['rtmp'] === undefined) return false;
+    return true;
+}
+
+export function InputOneOf1FromJSON(json: any): InputOneOf1 {
+    return InputOneOf1FromJSONTyped(json, false);
+}
+
+export function InputOneOf1FromJSONTyped(json: any, ignoreDiscriminator: boolean): InputOneOf1 {
+    if (json == null) {
+        return json;
+    }
+    return {
+        
+        'rtmp': RtmpInputFromJSON(json['Rtmp']),
+    };
+}
+
+  export function InputOneOf1ToJSON(json: any): InputOneOf1 {
+      return InputOneOf1ToJSONTyped(json, false);
+  }
+
+  export function InputOneOf1ToJSONTyped(value?: InputOneOf1 | null, ignoreDiscriminator: boolean = false): any {
+    if (value == null) {
+        return value;
+    }
+
+    return {
+        
+        'Rtmp': RtmpInputToJSON(value['rtmp']),
+    };
+}
+
@@ -0,0 +1,74 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * live-cmaf-transcoder
+ * API for the Live CMAF Transcoder
+ *
+ * The version of the OpenAPI document: 0.1.57
+ * 
+ *
+ * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
+ * https://openapi-generator.tech
+ * Do not edit the class manually.
+ */
+
+import { mapValues } from '../runtime';
+import type { RistInput } from './RistInput';
+import {
+    RistInputFromJSON,
+    RistInputFromJSONTyped,
+    RistInputToJSON,
+    RistInputToJSONTyped,
+} from './RistInput';
+
+/**
+ * 
+ * @export
+ * @interface InputOneOf2
+ */
+export interface InputOneOf2 {
+    /**
+     * 
+     * @type {RistInput}
+     * @memberof InputOneOf2
+     */
+    rist: RistInput;
+}
+
+/**
+ * Check if a given object implements the InputOneOf2 interface.
+ */
+export function instanceOfInputOneOf2(value: object): value is InputOneOf2 {
+    if (!('rist' in value) || value['rist'] === undefined) return false;
+    return true;
+}
+
+export function InputOneOf2FromJSON(json: any): InputOneOf2 {
+    return InputOneOf2FromJSONTyped(json, false);
+}
+
+export function InputOneOf2FromJSONTyped(json: any, ignoreDiscriminator: boolean): InputOneOf2 {
+    if (json == null) {
+        return json;
+    }
+    return {
+        
+        'rist': RistInputFromJSON(json['Rist']),
+    };
+}
+
+  export function InputOneOf2ToJSON(json: any): InputOneOf2 {
+      return InputOneOf2ToJSONTyped(json, false);
+  }
+
+  export function InputOneOf2ToJSONTyped(value?: InputOneOf2 | null, ignoreDiscriminator: boolean = false): any {
+    if (value == null) {
+        return value;
+    }
+
+    return {
+        
+        'Rist': RistInputToJSON(value['rist']),
+    };
+}
+
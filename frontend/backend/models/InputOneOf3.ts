@@ -0,0 +1,74 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * live-cmaf-transcoder
+ * API for the Live CMAF Transcoder
+ *
+ * The version of the OpenAPI document: 0.1.57
+ * 
+ *
+ * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
+ * https://openapi-generator.tech
+ * Do not edit the class manually.
+ */
+
+import { mapValues } from '../runtime';
+import type { UdpInput } from './UdpInput';
+import {
+    UdpInputFromJSON,
+    UdpInputFromJSONTyped,
+    UdpInputToJSON,
+    UdpInputToJSONTyped,
+} from './UdpInput';
+
+/**
+ * 
+ * @export
+ * @interface InputOneOf3
+ */
+export interface InputOneOf3 {
+    /**
+     * 
+     * @type {UdpInput}
+     * @memberof InputOneOf3
+     */
+    udp: UdpInput;
+}
+
+/**
+ * Check if a given object implements the InputOneOf3 interface.
+ */
+export function instanceOfInputOneOf3(value: object): value is InputOneOf3 {
+    if (!('udp' in value) || value['udp'] === undefined) return false;
+    return true;
+}
+
+export function InputOneOf3FromJSON(json: any): InputOneOf3 {
+    return InputOneOf3FromJSONTyped(json, false);
+}
+
+export function InputOneOf3FromJSONTyped(json: any, ignoreDiscriminator: boolean): InputOneOf3 {
+    if (json == null) {
+        return json;
+    }
+    return {
+        
+        'udp': UdpInputFromJSON(json['Udp']),
+    };
+}
+
+  export function InputOneOf3ToJSON(json: any): InputOneOf3 {
+      return InputOneOf3ToJSONTyped(json, false);
+  }
+
+  export function InputOneOf3ToJSONTyped(value?: InputOneOf3 | null, ignoreDiscriminator: boolean = false): any {
+    if (value == null) {
+        return value;
+    }
+
+    return {
+        
+        'Udp': UdpInputToJSON(value['udp']),
+    };
+}
+
@@ -0,0 +1,74 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * live-cmaf-transcoder
+ * API for the Live CMAF Transcoder
+ *
+ * The version of the OpenAPI document: 0.1.57
+ * 
+ *
+ * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
+ * https://openapi-generator.tech
+ * Do not edit the class manually.
+ */
+
+import { mapValues } from '../runtime';
+import type { HttpPullInput } from './HttpPullInput';
+import {
+    HttpPullInputFromJSON,
+    HttpPullInputFromJSONTyped,
+    HttpPullInputToJSON,
+    HttpPullInputToJSONTyped,
+} from './HttpPullInput';
+
+/**
+ * 
+ * @export
+ * @interface InputOneOf4
+ */
+export interface InputOneOf4 {
+    /**
+     * 
+     * @type {HttpPullInput}
+     * @memberof InputOneOf4
+     */
+    httpPull: HttpPullInput;
+}
+
+/**
+ * Check if a given object implements the InputOneOf4 interface.
+ */
+export function instanceOfInputOneOf4(value: object): value is InputOneOf4 {
+    if (!('httpPull' in value) || value['httpPull'] === undefined) return false;
+    return true;
+}
+
+export function InputOneOf4FromJSON(json: any): InputOneOf4 {
+    return InputOneOf4FromJSONTyped(json, false);
+}
+
+export function InputOneOf4FromJSONTyped(json: any, ignoreDiscriminator: boolean): InputOneOf4 {
+    if (json == null) {
+        return json;
+    }
+    return {
+        
+        'httpPull': HttpPullInputFromJSON(json['HttpPull']),
+    };
+}
+
+  export function InputOneOf4ToJSON(json: any): InputOneOf4 {
+      return InputOneOf4ToJSONTyped(json, false);
+  }
+
+  export function InputOneOf4ToJSONTyped(value?: InputOneOf4 | null, ignoreDiscriminator: boolean = false): any {
+    if (value == null) {
+        return value;
+    }
+
+    return {
+        
+        'HttpPull': HttpPullInputToJSON(value['httpPull']),
+    };
+}
+
@@ -0,0 +1,74 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * live-cmaf-transcoder
+ * API for the Live CMAF Transcoder
+ *
+ * The version of the OpenAPI document: 0.1.57
+ * 
+ *
+ * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
+ * https://openapi-generator.tech
+ * Do not edit the class manually.
+ */
+
+import { mapValues } from '../runtime';
+import type { FileInput } from './FileInput';
+import {
+    FileInputFromJSON,
+    FileInputFromJSONTyped,
+    FileInputToJSON,
+    FileInputToJSONTyped,
+} from './FileInput';
+
+/**
+ * 
+ * @export
+ * @interface InputOneOf5
+ */
+export interface InputOneOf5 {
+    /**
+     * 
+     * @type {FileInput}
+     * @memberof InputOneOf5
+     */
+    file: FileInput;
+}
+
+/**
+ * Check if a given object implements the InputOneOf5 interface.
+ */
+export function instanceOfInputOneOf5(value: object): value is InputOneOf5 {
+    if (!('file' in value) || value['file'] === undefined) return false;
+    return true;
+}
+
+export function InputOneOf5FromJSON(json: any): InputOneOf5 {
+    return InputOneOf5FromJSONTyped(json, false);
+}
+
+export function InputOneOf5FromJSONTyped(json: any, ignoreDiscriminator: boolean): InputOneOf5 {
+    if (json == null) {
+        return json;
+    }
+    return {
+        
+        'file': FileInputFromJSON(json['File']),
+    };
+}
+
+  export function InputOneOf5ToJSON(json: any): InputOneOf5 {
+      return InputOneOf5ToJSONTyped(json, false);
+  }
+
+  export function InputOneOf5ToJSONTyped(value?: InputOneOf5 | null, ignoreDiscriminator: boolean = false): any {
+    if (value == null) {
+        return value;
+    }
+
+    return {
+        
+        'File': FileInputToJSON(value['file']),
+    };
+}
+
@@ -0,0 +1,74 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * live-cmaf-transcoder
+ * API for the Live CMAF Transcoder
+ *
+ * The version of the OpenAPI document: 0.1.57
+ * 
+ *
+ * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
+ * https://openapi-generator.tech
+ * Do not edit the class manually.
+ */
+
+import { mapValues } from '../runtime';
+import type { UrlInput } from './UrlInput';
+import {
+    UrlInputFromJSON,
+    UrlInputFromJSONTyped,
+    UrlInputToJSON,
+    UrlInputToJSONTyped,
+} from './UrlInput';
+
+/**
+ * 
+ * @export
+ * @interface InputOneOf6
+ */
+export interface InputOneOf6 {
+    /**
+     * 
+     * @type {UrlInput}
+     * @memberof InputOneOf6
+     */
+    url: UrlInput;
+}
+
+/**
+ * Check if a given object implements the InputOneOf6 interface.
+ */
+export function instanceOfInputOneOf6(value: object): value is InputOneOf6 {
+    if (!('url' in value) || value['url'] === undefined) return false;
+    return true;
+}
+
+export function InputOneOf6FromJSON(json: any): InputOneOf6 {
+    return InputOneOf6FromJSONTyped(json, false);
+}
+
+export function InputOneOf6FromJSONTyped(json: any, ignoreDiscriminator: boolean): InputOneOf6 {
+    if (json == null) {
+        return json;
+    }
+    return {
+        
+        'url': UrlInputFromJSON(json['Url']),
+    };
+}
+
+  export function InputOneOf6ToJSON(json: any): InputOneOf6 {
+      return InputOneOf6ToJSONTyped(json, false);
+  }
+
+  export function InputOneOf6ToJSONTyped(value?: InputOneOf6 | null, ignoreDiscriminator: boolean = false): any {
+    if (value == null) {
+        return value;
+    }
+
+    return {
+        
+        'Url': UrlInputToJSON(value['url']),
+    };
+}
+
@@ -0,0 +1,54 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * live-cmaf-transcoder
+ * API for the Live CMAF Transcoder
+ *
+ * The version of the OpenAPI document: 0.1.57
+ * 
+ *
+ * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
+ * https://openapi-generator.tech
+ * Do not edit the class manually.
+ */
+
+
+/**
+ * 
+ * @export
+ */
+export const InputSwitchReason = {
+    InputTimeout: 'InputTimeout',
+    EndOfStream: 'EndOfStream',
+    ReturnToPrimary: 'ReturnToPrimary'
+} as const;
+export type InputSwitchReason = typeof InputSwitchReason[keyof typeof InputSwitchReason];
+
+
+export function instanceOfInputSwitchReason(value: any): boolean {
+    for (const key in InputSwitchReason) {
+        if (Object.prototype.hasOwnProperty.call(InputSwitchReason, key)) {
+            if (InputSwitchReason[key as keyof typeof InputSwitchReason] === value) {
+                return true;
+            }
+        }
+    }
+    return false;
+}
+
+export function InputSwitchReasonFromJSON(json: any): InputSwitchReason {
+    return InputSwitchReasonFromJSONTyped(json, false);
+}
+
+export function InputSwitchReasonFromJSONTyped(json: any, ignoreDiscriminator: boolean): InputSwitchReason {
+    return json as InputSwitchReason;
+}
+
+export function InputSwitchReasonToJSON(value?: InputSwitchReason | null): any {
+    return value as any;
+}
+
+export function InputSwitchReasonToJSONTyped(value: any, ignoreDiscriminator: boolean): InputSwitchReason {
+    return value as InputSwitchReason;
+}
+
@@ -0,0 +1,93 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * live-cmaf-transcoder
+ * API for the Live CMAF Transcoder
+ *
+ * The version of the OpenAPI document: 0.1.57
+ * 
+ *
+ * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
+ * https://openapi-generator.tech
+ * Do not edit the class manually.
+ */
+
+import { mapValues } from '../runtime';
+/**
+ * EBU R128 loudness measured on the output
+ * @export
+ * @interface LoudnessValue
+ */
+export interface LoudnessValue {
+    /**
+     * Integrated loudness, in LUFS
+     * @type {number}
+     * @memberof LoudnessValue
+     */
+    integrated: number;
+    /**
+     * Loudness range, in LU
+     * @type {number}
+     * @memberof LoudnessValue
+     */
+    lra: number;
+    /**
+     * Momentary loudness, in LUFS
+     * @type {number}
+     * @memberof LoudnessValue
+     */
+    momentary: number;
+    /**
+     * Short-term loudness, in LUFS
+     * @type {number}
+     * @memberof LoudnessValue
+     */
+    shortTerm: number;
+}
+
+/**
+ * Check if a given object implements the LoudnessValue interface.
+ */
+export function instanceOfLoudnessValue(value: object): value is LoudnessValue {
+    if (!('integrated' in value) || value['integrated'] === undefined) return false;
+    if (!('lra' in value) || value['lra'] === undefined) return false;
+    if (!('momentary' in value) || value['momentary'] === undefined) return false;
+    if (!('shortTerm' in value) || value['shortTerm'] === undefined) return false;
+    return true;
+}
+
+export function LoudnessValueFromJSON(json: any): LoudnessValue {
+    return LoudnessValueFromJSONTyped(json, false);
+}
+
+export function LoudnessValueFromJSONTyped(json: any, ignoreDiscriminator: boolean): LoudnessValue {
+    if (json == null) {
+        return json;
+    }
+    return {
+        
+        'integrated': json['integrated'],
+        'lra': json['lra'],
+        'momentary': json['momentary'],
+        'shortTerm': json['short_term'],
+    };
+}
+
+  export function LoudnessValueToJSON(json: any): LoudnessValue {
+      return LoudnessValueToJSONTyped(json, false);
+  }
+
+  export function LoudnessValueToJSONTyped(value?: LoudnessValue | null, ignoreDiscriminator: boolean = false): any {
+    if (value == null) {
+        return value;
+    }
+
+    return {
+        
+        'integrated': value['integrated'],
+        'lra': value['lra'],
+        'momentary': value['momentary'],
+        'short_term': value['shortTerm'],
+    };
+}
+
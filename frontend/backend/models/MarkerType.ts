@@ -0,0 +1,54 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * live-cmaf-transcoder
+ * API for the Live CMAF Transcoder
+ *
+ * The version of the OpenAPI document: 0.1.57
+ * 
+ *
+ * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
+ * https://openapi-generator.tech
+ * Do not edit the class manually.
+ */
+
+
+/**
+ * 
+ * @export
+ */
+export const MarkerType = {
+    SpliceOut: 'SpliceOut',
+    SpliceIn: 'SpliceIn',
+    TimedMetadata: 'TimedMetadata'
+} as const;
+export type MarkerType = typeof MarkerType[keyof typeof MarkerType];
+
+
+export function instanceOfMarkerType(value: any): boolean {
+    for (const key in MarkerType) {
+        if (Object.prototype.hasOwnProperty.call(MarkerType, key)) {
+            if (MarkerType[key as keyof typeof MarkerType] === value) {
+                return true;
+            }
+        }
+    }
+    return false;
+}
+
+export function MarkerTypeFromJSON(json: any): MarkerType {
+    return MarkerTypeFromJSONTyped(json, false);
+}
+
+export function MarkerTypeFromJSONTyped(json: any, ignoreDiscriminator: boolean): MarkerType {
+    return json as MarkerType;
+}
+
+export function MarkerTypeToJSON(value?: MarkerType | null): any {
+    return value as any;
+}
+
+export function MarkerTypeToJSONTyped(value: any, ignoreDiscriminator: boolean): MarkerType {
+    return value as MarkerType;
+}
+
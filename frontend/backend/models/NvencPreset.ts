@@ -0,0 +1,58 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * live-cmaf-transcoder
+ * API for the Live CMAF Transcoder
+ *
+ * The version of the OpenAPI document: 0.1.57
+ * 
+ *
+ * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
+ * https://openapi-generator.tech
+ * Do not edit the class manually.
+ */
+
+
+/**
+ * 
+ * @export
+ */
+export const NvencPreset = {
+    P1: 'P1',
+    P2: 'P2',
+    P3: 'P3',
+    P4: 'P4',
+    P5: 'P5',
+    P6: 'P6',
+    P7: 'P7'
+} as const;
+export type NvencPreset = typeof NvencPreset[keyof typeof NvencPreset];
+
+
+export function instanceOfNvencPreset(value: any): boolean {
+    for (const key in NvencPreset) {
+        if (Object.prototype.hasOwnProperty.call(NvencPreset, key)) {
+            if (NvencPreset[key as keyof typeof NvencPreset] === value) {
+                return true;
+            }
+        }
+    }
+    return false;
+}
+
+export function NvencPresetFromJSON(json: any): NvencPreset {
+    return NvencPresetFromJSONTyped(json, false);
+}
+
+export function NvencPresetFromJSONTyped(json: any, ignoreDiscriminator: boolean): NvencPreset {
+    return json as NvencPreset;
+}
+
+export function NvencPresetToJSON(value?: NvencPreset | null): any {
+    return value as any;
+}
+
+export function NvencPresetToJSONTyped(value: any, ignoreDiscriminator: boolean): NvencPreset {
+    return value as NvencPreset;
+}
+
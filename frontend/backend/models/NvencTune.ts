@@ -0,0 +1,54 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * live-cmaf-transcoder
+ * API for the Live CMAF Transcoder
+ *
+ * The version of the OpenAPI document: 0.1.57
+ * 
+ *
+ * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
+ * https://openapi-generator.tech
+ * Do not edit the class manually.
+ */
+
+
+/**
+ * 
+ * @export
+ */
+export const NvencTune = {
+    HighQuality: 'HighQuality',
+    LowLatency: 'LowLatency',
+    UltraLowLatency: 'UltraLowLatency'
+} as const;
+export type NvencTune = typeof NvencTune[keyof typeof NvencTune];
+
+
+export function instanceOfNvencTune(value: any): boolean {
+    for (const key in NvencTune) {
+        if (Object.prototype.hasOwnProperty.call(NvencTune, key)) {
+            if (NvencTune[key as keyof typeof NvencTune] === value) {
+                return true;
+            }
+        }
+    }
+    return false;
+}
+
+export function NvencTuneFromJSON(json: any): NvencTune {
+    return NvencTuneFromJSONTyped(json, false);
+}
+
+export function NvencTuneFromJSONTyped(json: any, ignoreDiscriminator: boolean): NvencTune {
+    return json as NvencTune;
+}
+
+export function NvencTuneToJSON(value?: NvencTune | null): any {
+    return value as any;
+}
+
+export function NvencTuneToJSONTyped(value: any, ignoreDiscriminator: boolean): NvencTune {
+    return value as NvencTune;
+}
+
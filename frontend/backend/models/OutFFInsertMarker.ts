@@ -0,0 +1,75 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * live-cmaf-transcoder
+ * API for the Live CMAF Transcoder
+ *
+ * The version of the OpenAPI document: 0.1.57
+ * 
+ *
+ * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
+ * https://openapi-generator.tech
+ * Do not edit the class manually.
+ */
+
+import { mapValues } from '../runtime';
+/**
+ * 
+ * @export
+ * @interface OutFFInsertMarker
+ */
+export interface OutFFInsertMarker {
+    /**
+     * 
+     * @type {string}
+     * @memberof OutFFInsertMarker
+     */
+    markerUid: string;
+    /**
+     * Presentation time of the keyframe carrying the marker, in the timeline of the MPD
+     * @type {number}
+     * @memberof OutFFInsertMarker
+     */
+    mediaTimeMs: number;
+}
+
+/**
+ * Check if a given object implements the OutFFInsertMarker interface.
+ */
+export function instanceOfOutFFInsertMarker(value: object): value is OutFFInsertMarker {
+    if (!('markerUid' in value) || value['markerUid'] === undefined) return false;
+    if (!('mediaTimeMs' in value) || value['mediaTimeMs'] === undefined) return false;
+    return true;
+}
+
+export function OutFFInsertMarkerFromJSON(json: any): OutFFInsertMarker {
+    return OutFFInsertMarkerFromJSONTyped(json, false);
+}
+
+export function OutFFInsertMarkerFromJSONTyped(json: any, ignoreDiscriminator: boolean): OutFFInsertMarker {
+    if (json == null) {
+        return json;
+    }
+    return {
+        
+        'markerUid': json['marker_uid'],
+        'mediaTimeMs': json['media_time_ms'],
+    };
+}
+
+  export function OutFFInsertMarkerToJSON(json: any): OutFFInsertMarker {
+      return OutFFInsertMarkerToJSONTyped(json, false);
+  }
+
+  export function OutFFInsertMarkerToJSONTyped(value?: OutFFInsertMarker | null, ignoreDiscriminator: boolean = false): any {
+    if (value == null) {
+        return value;
+    }
+
+    return {
+        
+        'marker_uid': value['markerUid'],
+        'media_time_ms': value['mediaTimeMs'],
+    };
+}
+
@@ -0,0 +1,113 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * live-cmaf-transcoder
+ * API for the Live CMAF Transcoder
+ *
+ * The version of the OpenAPI document: 0.1.57
+ * 
+ *
+ * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
+ * https://openapi-generator.tech
+ * Do not edit the class manually.
+ */
+
+import { mapValues } from '../runtime';
+import type { ProbeProgram } from './ProbeProgram';
+import {
+    ProbeProgramFromJSON,
+    ProbeProgramFromJSONTyped,
+    ProbeProgramToJSON,
+    ProbeProgramToJSONTyped,
+} from './ProbeProgram';
+import type { VideoFilterCrop } from './VideoFilterCrop';
+import {
+    VideoFilterCropFromJSON,
+    VideoFilterCropFromJSONTyped,
+    VideoFilterCropToJSON,
+    VideoFilterCropToJSONTyped,
+} from './VideoFilterCrop';
+import type { ProbeStream } from './ProbeStream';
+import {
+    ProbeStreamFromJSON,
+    ProbeStreamFromJSONTyped,
+    ProbeStreamToJSON,
+    ProbeStreamToJSONTyped,
+} from './ProbeStream';
+
+/**
+ * 
+ * @export
+ * @interface OutFFProbe
+ */
+export interface OutFFProbe {
+    /**
+     * Crop removing the black bars of the first video stream, if requested
+     * @type {VideoFilterCrop}
+     * @memberof OutFFProbe
+     */
+    crop?: VideoFilterCrop | null;
+    /**
+     * 
+     * @type {string}
+     * @memberof OutFFProbe
+     */
+    formatName?: string | null;
+    /**
+     * 
+     * @type {Array<ProbeProgram>}
+     * @memberof OutFFProbe
+     */
+    programs: Array<ProbeProgram>;
+    /**
+     * 
+     * @type {Array<ProbeStream>}
+     * @memberof OutFFProbe
+     */
+    streams: Array<ProbeStream>;
+}
+
+/**
+ * Check if a given object implements the OutFFProbe interface.
+ */
+export function instanceOfOutFFProbe(value: object): value is OutFFProbe {
+    if (!('programs' in value) || value['programs'] === undefined) return false;
+    if (!('streams' in value) || value['streams'] === undefined) return false;
+    return true;
+}
+
+export function OutFFProbeFromJSON(json: any): OutFFProbe {
+    return OutFFProbeFromJSONTyped(json, false);
+}
+
+export function OutFFProbeFromJSONTyped(json: any, ignoreDiscriminator: boolean): OutFFProbe {
+    if (json == null) {
+        return json;
+    }
+    return {
+        
+        'crop': json['crop'] == null ? undefined : VideoFilterCropFromJSON(json['crop']),
+        'formatName': json['format_name'] == null ? undefined : json['format_name'],
+        'programs': ((json['programs'] as Array<any>).map(ProbeProgramFromJSON)),
+        'streams': ((json['streams'] as Array<any>).map(ProbeStreamFromJSON)),
+    };
+}
+
+  export function OutFFProbeToJSON(json: any): OutFFProbe {
+      return OutFFProbeToJSONTyped(json, false);
+  }
+
+  export function OutFFProbeToJSONTyped(value?: OutFFProbe | null, ignoreDiscriminator: boolean = false): any {
+    if (value == null) {
+        return value;
+    }
+
+    return {
+        
+        'crop': VideoFilterCropToJSON(value['crop']),
+        'format_name': value['formatName'],
+        'programs': ((value['programs'] as Array<any>).map(ProbeProgramToJSON)),
+        'streams': ((value['streams'] as Array<any>).map(ProbeStreamToJSON)),
+    };
+}
+
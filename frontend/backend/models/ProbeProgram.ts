@@ -0,0 +1,107 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * live-cmaf-transcoder
+ * API for the Live CMAF Transcoder
+ *
+ * The version of the OpenAPI document: 0.1.57
+ * 
+ *
+ * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
+ * https://openapi-generator.tech
+ * Do not edit the class manually.
+ */
+
+import { mapValues } from '../runtime';
+/**
+ * 
+ * @export
+ * @interface ProbeProgram
+ */
+export interface ProbeProgram {
+    /**
+     * 
+     * @type {number}
+     * @memberof ProbeProgram
+     */
+    pcrPid?: number | null;
+    /**
+     * 
+     * @type {number}
+     * @memberof ProbeProgram
+     */
+    pmtPid?: number | null;
+    /**
+     * 
+     * @type {number}
+     * @memberof ProbeProgram
+     */
+    programNum: number;
+    /**
+     * 
+     * @type {string}
+     * @memberof ProbeProgram
+     */
+    serviceName?: string | null;
+    /**
+     * 
+     * @type {string}
+     * @memberof ProbeProgram
+     */
+    serviceProvider?: string | null;
+    /**
+     * Index of the streams carried by this program
+     * @type {Array<number>}
+     * @memberof ProbeProgram
+     */
+    streams: Array<number>;
+}
+
+/**
+ * Check if a given object implements the ProbeProgram interface.
+ */
+export function instanceOfProbeProgram(value: object): value is ProbeProgram {
+    if (!('programNum' in value) || value['programNum'] === undefined) return false;
+    if (!('streams' in value) || value['streams'] === undefined) return false;
+    return true;
+}
+
+export function ProbeProgramFromJSON(json: any): ProbeProgram {
+    return ProbeProgramFromJSONTyped(json, false);
+}
+
+export function ProbeProgramFromJSONTyped(json: any, ignoreDiscriminator: boolean): ProbeProgram {
+    if (json == null) {
+        return json;
+    }
+    return {
+        
+        'pcrPid': json['pcr_pid'] == null ? undefined : json['pcr_pid'],
+        'pmtPid': json['pmt_pid'] == null ? undefined : json['pmt_pid'],
+        'programNum': json['program_num'],
+        'serviceName': json['service_name'] == null ? undefined : json['service_name'],
+        'serviceProvider': json['service_provider'] == null ? undefined : json['service_provider'],
+        'streams': json['streams'],
+    };
+}
+
+  export function ProbeProgramToJSON(json: any): ProbeProgram {
+      return ProbeProgramToJSONTyped(json, false);
+  }
+
+  export function ProbeProgramToJSONTyped(value?: ProbeProgram | null, ignoreDiscriminator: boolean = false): any {
+    if (value == null) {
+        return value;
+    }
+
+    return {
+        
+        'pcr_pid': value['pcrPid'],
+        'pmt_pid': value['pmtPid'],
+        'program_num': value['programNum'],
+        'service_name': value['serviceName'],
+        'service_provider': value['serviceProvider'],
+        'streams': value['streams'],
+    };
+}
+
@@ -0,0 +1,212 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * live-cmaf-transcoder
+ * API for the Live CMAF Transcoder
+ *
+ * The version of the OpenAPI document: 0.1.57
+ * 
+ *
+ * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
+ * https://openapi-generator.tech
+ * Do not edit the class manually.
+ */
+
+import { mapValues } from '../runtime';
+/**
+ * 
+ * @export
+ * @interface ProbeStream
+ */
+export interface ProbeStream {
+    /**
+     * 
+     * @type {number}
+     * @memberof ProbeStream
+     */
+    bitrate?: number | null;
+    /**
+     * 
+     * @type {string}
+     * @memberof ProbeStream
+     */
+    channelLayout?: string | null;
+    /**
+     * 
+     * @type {number}
+     * @memberof ProbeStream
+     */
+    channels?: number | null;
+    /**
+     * 
+     * @type {string}
+     * @memberof ProbeStream
+     */
+    codecName?: string | null;
+    /**
+     * video, audio, subtitle or data
+     * @type {string}
+     * @memberof ProbeStream
+     */
+    codecType: string;
+    /**
+     * 
+     * @type {string}
+     * @memberof ProbeStream
+     */
+    colorPrimaries?: string | null;
+    /**
+     * 
+     * @type {string}
+     * @memberof ProbeStream
+     */
+    colorTransfer?: string | null;
+    /**
+     * progressive, tt, bb, tb or bt
+     * @type {string}
+     * @memberof ProbeStream
+     */
+    fieldOrder?: string | null;
+    /**
+     * 
+     * @type {number}
+     * @memberof ProbeStream
+     */
+    framerateDen?: number | null;
+    /**
+     * Frame rate of the stream, in frames per second for interlaced streams
+     * @type {number}
+     * @memberof ProbeStream
+     */
+    framerateNum?: number | null;
+    /**
+     * 
+     * @type {number}
+     * @memberof ProbeStream
+     */
+    height?: number | null;
+    /**
+     * 
+     * @type {number}
+     * @memberof ProbeStream
+     */
+    index: number;
+    /**
+     * 
+     * @type {boolean}
+     * @memberof ProbeStream
+     */
+    interlaced: boolean;
+    /**
+     * 
+     * @type {string}
+     * @memberof ProbeStream
+     */
+    language?: string | null;
+    /**
+     * MPEG-TS PID
+     * @type {number}
+     * @memberof ProbeStream
+     */
+    pid?: number | null;
+    /**
+     * 
+     * @type {string}
+     * @memberof ProbeStream
+     */
+    pixFmt?: string | null;
+    /**
+     * 
+     * @type {string}
+     * @memberof ProbeStream
+     */
+    profile?: string | null;
+    /**
+     * 
+     * @type {number}
+     * @memberof ProbeStream
+     */
+    sampleRate?: number | null;
+    /**
+     * 
+     * @type {number}
+     * @memberof ProbeStream
+     */
+    width?: number | null;
+}
+
+/**
+ * Check if a given object implements the ProbeStream interface.
+ */
+export function instanceOfProbeStream(value: object): value is ProbeStream {
+    if (!('codecType' in value) || value['codecType'] === undefined) return false;
+    if (!('index' in value) || value['index'] === undefined) return false;
+    if (!('interlaced' in value) || value['interlaced'] === undefined) return false;
+    return true;
+}
+
+export function ProbeStreamFromJSON(json: any): ProbeStream {
+    return ProbeStreamFromJSONTyped(json, false);
+}
+
+export function ProbeStreamFromJSONTyped(json: any, ignoreDiscriminator: boolean): ProbeStream {
+    if (json == null) {
+        return json;
+    }
+    return {
+        
+        'bitrate': json['bitrate'] == null ? undefined : json['bitrate'],
+        'channelLayout': json['channel_layout'] == null ? undefined : json['channel_layout'],
+        'channels': json['channels'] == null ? undefined : json['channels'],
+        'codecName': json['codec_name'] == null ? undefined : json['codec_name'],
+        'codecType': json['codec_type'],
+        'colorPrimaries': json['color_primaries'] == null ? undefined : json['color_primaries'],
+        'colorTransfer': json['color_transfer'] == null ? undefined : json['color_transfer'],
+        'fieldOrder': json['field_order'] == null ? undefined : json['field_order'],
+        'framerateDen': json['framerate_den'] == null ? undefined : json['framerate_den'],
+        'framerateNum': json['framerate_num'] == null ? undefined : json['framerate_num'],
+        'height': json['height'] == null ? undefined : json['height'],
+        'index': json['index'],
+        'interlaced': json['interlaced'],
+        'language': json['language'] == null ? undefined : json['language'],
+        'pid': json['pid'] == null ? undefined : json['pid'],
+        'pixFmt': json['pix_fmt'] == null ? undefined : json['pix_fmt'],
+        'profile': json['profile'] == null ? undefined : json['profile'],
+        'sampleRate': json['sample_rate'] == null ? undefined : json['sample_rate'],
+        'width': json['width'] == null ? undefined : json['width'],
+    };
+}
+
+  export function ProbeStreamToJSON(json: any): ProbeStream {
+      return ProbeStreamToJSONTyped(json, false);
+  }
+
+  export function ProbeStreamToJSONTyped(value?: ProbeStream | null, ignoreDiscriminator: boolean = false): any {
+    if (value == null) {
+        return value;
+    }
+
+    return {
+        
+        'bitrate': value['bitrate'],
+        'channel_layout': value['channelLayout'],
+        'channels': value['channels'],
+        'codec_name': value['codecName'],
+        'codec_type': value['codecType'],
+        'color_primaries': value['colorPrimaries'],
+        'color_transfer': value['colorTransfer'],
+        'field_order': value['fieldOrder'],
+        'framerate_den': value['framerateDen'],
+        'framerate_num': value['framerateNum'],
+        'height': value['height'],
+        'index': value['index'],
+        'interlaced': value['interlaced'],
+        'language': value['language'],
+        'pid': value['pid'],
+        'pix_fmt': value['pixFmt'],
+        'profile': value['profile'],
+        'sample_rate': value['sampleRate'],
+        'width': value['width'],
+    };
+}
+
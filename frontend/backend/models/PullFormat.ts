@@ -0,0 +1,54 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * live-cmaf-transcoder
+ * API for the Live CMAF Transcoder
+ *
+ * The version of the OpenAPI document: 0.1.57
+ * 
+ *
+ * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
+ * https://openapi-generator.tech
+ * Do not edit the class manually.
+ */
+
+
+/**
+ * 
+ * @export
+ */
+export const PullFormat = {
+    Auto: 'Auto',
+    Hls: 'Hls',
+    Dash: 'Dash'
+} as const;
+export type PullFormat = typeof PullFormat[keyof typeof PullFormat];
+
+
+export function instanceOfPullFormat(value: any): boolean {
+    for (const key in PullFormat) {
+        if (Object.prototype.hasOwnProperty.call(PullFormat, key)) {
+            if (PullFormat[key as keyof typeof PullFormat] === value) {
+                return true;
+            }
+        }
+    }
+    return false;
+}
+
+export function PullFormatFromJSON(json: any): PullFormat {
+    return PullFormatFromJSONTyped(json, false);
+}
+
+export function PullFormatFromJSONTyped(json: any, ignoreDiscriminator: boolean): PullFormat {
+    return json as PullFormat;
+}
+
+export function PullFormatToJSON(value?: PullFormat | null): any {
+    return value as any;
+}
+
+export function PullFormatToJSONTyped(value: any, ignoreDiscriminator: boolean): PullFormat {
+    return value as PullFormat;
+}
+
@@ -0,0 +1,117 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * live-cmaf-transcoder
+ * API for the Live CMAF Transcoder
+ *
+ * The version of the OpenAPI document: 0.1.57
+ * 
+ *
+ * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
+ * https://openapi-generator.tech
+ * Do not edit the class manually.
+ */
+
+import type { RateControlOneOf2 } from './RateControlOneOf2';
+import {
+    instanceOfRateControlOneOf2,
+    RateControlOneOf2FromJSON,
+    RateControlOneOf2FromJSONTyped,
+    RateControlOneOf2ToJSON,
+} from './RateControlOneOf2';
+import type { RateControlOneOf3 } from './RateControlOneOf3';
+import {
+    instanceOfRateControlOneOf3,
+    RateControlOneOf3FromJSON,
+    RateControlOneOf3FromJSONTyped,
+    RateControlOneOf3ToJSON,
+} from './RateControlOneOf3';
+import type { RateControlOneOf4 } from './RateControlOneOf4';
+import {
+    instanceOfRateControlOneOf4,
+    RateControlOneOf4FromJSON,
+    RateControlOneOf4FromJSONTyped,
+    RateControlOneOf4ToJSON,
+} from './RateControlOneOf4';
+import type { RateControlOneOf5 } from './RateControlOneOf5';
+import {
+    instanceOfRateControlOneOf5,
+    RateControlOneOf5FromJSON,
+    RateControlOneOf5FromJSONTyped,
+    RateControlOneOf5ToJSON,
+} from './RateControlOneOf5';
+import type { RateControlOneOf6 } from './RateControlOneOf6';
+import {
+    instanceOfRateControlOneOf6,
+    RateControlOneOf6FromJSON,
+    RateControlOneOf6FromJSONTyped,
+    RateControlOneOf6ToJSON,
+} from './RateControlOneOf6';
+
+/**
+ * @type RateControl
+ * 
+ * @export
+ */
+export type RateControl = RateControlOneOf2 | RateControlOneOf3 | RateControlOneOf4 | RateControlOneOf5 | RateControlOneOf6 | string;
+
+export function RateControlFromJSON(json: any): RateControl {
+    return RateControlFromJSONTyped(json, false);
+}
+
+export function RateControlFromJSONTyped(json: any, ignoreDiscriminator: boolean): RateControl {
+    if (json == null) {
+        return json;
+    }
+    if (typeof json !== 'object') {
+        return json;
+    }
+    if ('Crf' in json) {
+        return RateControlOneOf2FromJSONTyped(json, true);
+    }
+    if ('Cqp' in json) {
+        return RateControlOneOf3FromJSONTyped(json, true);
+    }
+    if ('Icq' in json) {
+        return RateControlOneOf4FromJSONTyped(json, true);
+    }
+    if ('Qvbr' in json) {
+        return RateControlOneOf5FromJSONTyped(json, true);
+    }
+    if ('Cq' in json) {
+        return RateControlOneOf6FromJSONTyped(json, true);
+    }
+
+    return {} as any;
+}
+
+export function RateControlToJSON(json: any): any {
+    return RateControlToJSONTyped(json, false);
+}
+
+export function RateControlToJSONTyped(value?: RateControl | null, ignoreDiscriminator: boolean = false): any {
+    if (value == null) {
+        return value;
+    }
+    if (typeof value !== 'object') {
+        return value;
+    }
+    if (instanceOfRateControlOneOf2(value)) {
+        return RateControlOneOf2ToJSON(value as RateControlOneOf2);
+    }
+    if (instanceOfRateControlOneOf3(value)) {
+        return RateControlOneOf3ToJSON(value as RateControlOneOf3);
+    }
+    if (instanceOfRateControlOneOf4(value)) {
+        return RateControlOneOf4ToJSON(value as RateControlOneOf4);
+    }
+    if (instanceOfRateControlOneOf5(value)) {
+        return RateControlOneOf5ToJSON(value as RateControlOneOf5);
+    }
+    if (instanceOfRateControlOneOf6(value)) {
+        return RateControlOneOf6ToJSON(value as RateControlOneOf6);
+    }
+
+    return {};
+}
+
@@ -0,0 +1,74 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * live-cmaf-transcoder
+ * API for the Live CMAF Transcoder
+ *
+ * The version of the OpenAPI document: 0.1.57
+ * 
+ *
+ * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
+ * https://openapi-generator.tech
+ * Do not edit the class manually.
+ */
+
+import { mapValues } from '../runtime';
+import type { RateControlOneOf2Crf } from './RateControlOneOf2Crf';
+import {
+    RateControlOneOf2CrfFromJSON,
+    RateControlOneOf2CrfFromJSONTyped,
+    RateControlOneOf2CrfToJSON,
+    RateControlOneOf2CrfToJSONTyped,
+} from './RateControlOneOf2Crf';
+
+/**
+ * Software only, constant rate factor capped by the max bitrate
+ * @export
+ * @interface RateControlOneOf2
+ */
+export interface RateControlOneOf2 {
+    /**
+     * 
+     * @type {RateControlOneOf2Crf}
+     * @memberof RateControlOneOf2
+     */
+    crf: RateControlOneOf2Crf;
+}
+
+/**
+ * Check if a given object implements the RateControlOneOf2 interface.
+ */
+export function instanceOfRateControlOneOf2(value: object): value is RateControlOneOf2 {
+    if (!('crf' in value) || value['crf'] === undefined) return false;
+    return true;
+}
+
+export function RateControlOneOf2FromJSON(json: any): RateControlOneOf2 {
+    return RateControlOneOf2FromJSONTyped(json, false);
+}
+
+export function RateControlOneOf2FromJSONTyped(json: any, ignoreDiscriminator: boolean): RateControlOneOf2 {
+    if (json == null) {
+        return json;
+    }
+    return {
+        
+        'crf': RateControlOneOf2CrfFromJSON(json['Crf']),
+    };
+}
+
+  export function RateControlOneOf2ToJSON(json: any): RateControlOneOf2 {
+      return RateControlOneOf2ToJSONTyped(json, false);
+  }
+
+  export function RateControlOneOf2ToJSONTyped(value?: RateControlOneOf2 | null, ignoreDiscriminator: boolean = false): any {
+    if (value == null) {
+        return value;
+    }
+
+    return {
+        
+        'Crf': RateControlOneOf2CrfToJSON(value['crf']),
+    };
+}
+
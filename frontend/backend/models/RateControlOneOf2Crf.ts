@@ -0,0 +1,66 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * live-cmaf-transcoder
+ * API for the Live CMAF Transcoder
+ *
+ * The version of the OpenAPI document: 0.1.57
+ * 
+ *
+ * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
+ * https://openapi-generator.tech
+ * Do not edit the class manually.
+ */
+
+import { mapValues } from '../runtime';
+/**
+ * Software only, constant rate factor capped by the max bitrate
+ * @export
+ * @interface RateControlOneOf2Crf
+ */
+export interface RateControlOneOf2Crf {
+    /**
+     * 
+     * @type {number}
+     * @memberof RateControlOneOf2Crf
+     */
+    crf: number;
+}
+
+/**
+ * Check if a given object implements the RateControlOneOf2Crf interface.
+ */
+export function instanceOfRateControlOneOf2Crf(value: object): value is RateControlOneOf2Crf {
+    if (!('crf' in value) || value['crf'] === undefined) return false;
+    return true;
+}
+
+export function RateControlOneOf2CrfFromJSON(json: any): RateControlOneOf2Crf {
+    return RateControlOneOf2CrfFromJSONTyped(json, false);
+}
+
+export function RateControlOneOf2CrfFromJSONTyped(json: any, ignoreDiscriminator: boolean): RateControlOneOf2Crf {
+    if (json == null) {
+        return json;
+    }
+    return {
+        
+        'crf': json['crf'],
+    };
+}
+
+  export function RateControlOneOf2CrfToJSON(json: any): RateControlOneOf2Crf {
+      return RateControlOneOf2CrfToJSONTyped(json, false);
+  }
+
+  export function RateControlOneOf2CrfToJSONTyped(value?: RateControlOneOf2Crf | null, ignoreDiscriminator: boolean = false): any {
+    if (value == null) {
+        return value;
+    }
+
+    return {
+        
+        'crf': value['crf'],
+    };
+}
+
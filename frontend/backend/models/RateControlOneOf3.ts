@@ -0,0 +1,74 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * live-cmaf-transcoder
+ * API for the Live CMAF Transcoder
+ *
+ * The version of the OpenAPI document: 0.1.57
+ * 
+ *
+ * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
+ * https://openapi-generator.tech
+ * Do not edit the class manually.
+ */
+
+import { mapValues } from '../runtime';
+import type { RateControlOneOf3Cqp } from './RateControlOneOf3Cqp';
+import {
+    RateControlOneOf3CqpFromJSON,
+    RateControlOneOf3CqpFromJSONTyped,
+    RateControlOneOf3CqpToJSON,
+    RateControlOneOf3CqpToJSONTyped,
+} from './RateControlOneOf3Cqp';
+
+/**
+ * VAAPI and NVENC only, constant quantizer, up to 51 for H264 and HEVC, 255 for AV1
+ * @export
+ * @interface RateControlOneOf3
+ */
+export interface RateControlOneOf3 {
+    /**
+     * 
+     * @type {RateControlOneOf3Cqp}
+     * @memberof RateControlOneOf3
+     */
+    cqp: RateControlOneOf3Cqp;
+}
+
+/**
+ * Check if a given object implements the RateControlOneOf3 interface.
+ */
+export function instanceOfRateControlOneOf3(value: object): value is RateControlOneOf3 {
+    if (!('cqp' in value) || value['cqp'] === undefined) return false;
+    return true;
+}
+
+export function RateControlOneOf3FromJSON(json: any): RateControlOneOf3 {
+    return RateControlOneOf3FromJSONTyped(json, false);
+}
+
+export function RateControlOneOf3FromJSONTyped(json: any, ignoreDiscriminator: boolean): RateControlOneOf3 {
+    if (json == null) {
+        return json;
+    }
+    return {
+        
+        'cqp': RateControlOneOf3CqpFromJSON(json['Cqp']),
+    };
+}
+
+  export function RateControlOneOf3ToJSON(json: any): RateControlOneOf3 {
+      return RateControlOneOf3ToJSONTyped(json, false);
+  }
+
+  export function RateControlOneOf3ToJSONTyped(value?: RateControlOneOf3 | null, ignoreDiscriminator: boolean = false): any {
+    if (value == null) {
+        return value;
+    }
+
+    return {
+        
+        'Cqp': RateControlOneOf3CqpToJSON(value['cqp']),
+    };
+}
+
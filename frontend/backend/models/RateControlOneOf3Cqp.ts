@@ -0,0 +1,66 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * live-cmaf-transcoder
+ * API for the Live CMAF Transcoder
+ *
+ * The version of the OpenAPI document: 0.1.57
+ * 
+ *
+ * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
+ * https://openapi-generator.tech
+ * Do not edit the class manually.
+ */
+
+import { mapValues } from '../runtime';
+/**
+ * VAAPI and NVENC only, constant quantizer, up to 51 for H264 and HEVC, 255 for AV1
+ * @export
+ * @interface RateControlOneOf3Cqp
+ */
+export interface RateControlOneOf3Cqp {
+    /**
+     * 
+     * @type {number}
+     * @memberof RateControlOneOf3Cqp
+     */
+    qp: number;
+}
+
+/**
+ * Check if a given object implements the RateControlOneOf3Cqp interface.
+ */
+export function instanceOfRateControlOneOf3Cqp(value: object): value is RateControlOneOf3Cqp {
+    if (!('qp' in value) || value['qp'] === undefined) return false;
+    return true;
+}
+
+export function RateControlOneOf3CqpFromJSON(json: any): RateControlOneOf3Cqp {
+    return RateControlOneOf3CqpFromJSONTyped(json, false);
+}
+
+export function RateControlOneOf3CqpFromJSONTyped(json: any, ignoreDiscriminator: boolean): RateControlOneOf3Cqp {
+    if (json == null) {
+        return json;
+    }
+    return {
+        
+        'qp': json['qp'],
+    };
+}
+
+  export function RateControlOneOf3CqpToJSON(json: any): RateControlOneOf3Cqp {
+      return RateControlOneOf3CqpToJSONTyped(json, false);
+  }
+
+  export function RateControlOneOf3CqpToJSONTyped(value?: RateControlOneOf3Cqp | null, ignoreDiscriminator: boolean = false): any {
+    if (value == null) {
+        return value;
+    }
+
+    return {
+        
+        'qp': value['qp'],
+    };
+}
+
@@ -0,0 +1,74 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * live-cmaf-transcoder
+ * API for the Live CMAF Transcoder
+ *
+ * The version of the OpenAPI document: 0.1.57
+ * 
+ *
+ * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
+ * https://openapi-generator.tech
+ * Do not edit the class manually.
+ */
+
+import { mapValues } from '../runtime';
+import type { RateControlOneOf4Icq } from './RateControlOneOf4Icq';
+import {
+    RateControlOneOf4IcqFromJSON,
+    RateControlOneOf4IcqFromJSONTyped,
+    RateControlOneOf4IcqToJSON,
+    RateControlOneOf4IcqToJSONTyped,
+} from './RateControlOneOf4Icq';
+
+/**
+ * VAAPI only, intelligent constant quality
+ * @export
+ * @interface RateControlOneOf4
+ */
+export interface RateControlOneOf4 {
+    /**
+     * 
+     * @type {RateControlOneOf4Icq}
+     * @memberof RateControlOneOf4
+     */
+    icq: RateControlOneOf4Icq;
+}
+
+/**
+ * Check if a given object implements the RateControlOneOf4 interface.
+ */
+export function instanceOfRateControlOneOf4(value: object): value is RateControlOneOf4 {
+    if (!('icq' in value) || value['icq'] === undefined) return false;
+    return true;
+}
+
+export function RateControlOneOf4FromJSON(json: any): RateControlOneOf4 {
+    return RateControlOneOf4FromJSONTyped(json, false);
+}
+
+export function RateControlOneOf4FromJSONTyped(json: any, ignoreDiscriminator: boolean): RateControlOneOf4 {
+    if (json == null) {
+        return json;
+    }
+    return {
+        
+        'icq': RateControlOneOf4IcqFromJSON(json['Icq']),
+    };
+}
+
+  export function RateControlOneOf4ToJSON(json: any): RateControlOneOf4 {
+      return RateControlOneOf4ToJSONTyped(json, false);
+  }
+
+  export function RateControlOneOf4ToJSONTyped(value?: RateControlOneOf4 | null, ignoreDiscriminator: boolean = false): any {
+    if (value == null) {
+        return value;
+    }
+
+    return {
+        
+        'Icq': RateControlOneOf4IcqToJSON(value['icq']),
+    };
+}
+
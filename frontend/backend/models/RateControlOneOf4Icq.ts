@@ -0,0 +1,66 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * live-cmaf-transcoder
+ * API for the Live CMAF Transcoder
+ *
+ * The version of the OpenAPI document: 0.1.57
+ * 
+ *
+ * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
+ * https://openapi-generator.tech
+ * Do not edit the class manually.
+ */
+
+import { mapValues } from '../runtime';
+/**
+ * VAAPI only, intelligent constant quality
+ * @export
+ * @interface RateControlOneOf4Icq
+ */
+export interface RateControlOneOf4Icq {
+    /**
+     * 
+     * @type {number}
+     * @memberof RateControlOneOf4Icq
+     */
+    quality: number;
+}
+
+/**
+ * Check if a given object implements the RateControlOneOf4Icq interface.
+ */
+export function instanceOfRateControlOneOf4Icq(value: object): value is RateControlOneOf4Icq {
+    if (!('quality' in value) || value['quality'] === undefined) return false;
+    return true;
+}
+
+export function RateControlOneOf4IcqFromJSON(json: any): RateControlOneOf4Icq {
+    return RateControlOneOf4IcqFromJSONTyped(json, false);
+}
+
+export function RateControlOneOf4IcqFromJSONTyped(json: any, ignoreDiscriminator: boolean): RateControlOneOf4Icq {
+    if (json == null) {
+        return json;
+    }
+    return {
+        
+        'quality': json['quality'],
+    };
+}
+
+  export function RateControlOneOf4IcqToJSON(json: any): RateControlOneOf4Icq {
+      return RateControlOneOf4IcqToJSONTyped(json, false);
+  }
+
+  export function RateControlOneOf4IcqToJSONTyped(value?: RateControlOneOf4Icq | null, ignoreDiscriminator: boolean = false): any {
+    if (value == null) {
+        return value;
+    }
+
+    return {
+        
+        'quality': value['quality'],
+    };
+}
+
@@ -0,0 +1,74 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * live-cmaf-transcoder
+ * API for the Live CMAF Transcoder
+ *
+ * The version of the OpenAPI document: 0.1.57
+ * 
+ *
+ * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
+ * https://openapi-generator.tech
+ * Do not edit the class manually.
+ */
+
+import { mapValues } from '../runtime';
+import type { RateControlOneOf5Qvbr } from './RateControlOneOf5Qvbr';
+import {
+    RateControlOneOf5QvbrFromJSON,
+    RateControlOneOf5QvbrFromJSONTyped,
+    RateControlOneOf5QvbrToJSON,
+    RateControlOneOf5QvbrToJSONTyped,
+} from './RateControlOneOf5Qvbr';
+
+/**
+ * VAAPI only, quality-defined variable bitrate capped by the max bitrate
+ * @export
+ * @interface RateControlOneOf5
+ */
+export interface RateControlOneOf5 {
+    /**
+     * 
+     * @type {RateControlOneOf5Qvbr}
+     * @memberof RateControlOneOf5
+     */
+    qvbr: RateControlOneOf5Qvbr;
+}
+
+/**
+ * Check if a given object implements the RateControlOneOf5 interface.
+ */
+export function instanceOfRateControlOneOf5(value: object): value is RateControlOneOf5 {
+    if (!('qvbr' in value) || value['qvbr'] === undefined) return false;
+    return true;
+}
+
+export function RateControlOneOf5FromJSON(json: any): RateControlOneOf5 {
+    return RateControlOneOf5FromJSONTyped(json, false);
+}
+
+export function RateControlOneOf5FromJSONTyped(json: any, ignoreDiscriminator: boolean): RateControlOneOf5 {
+    if (json == null) {
+        return json;
+    }
+    return {
+        
+        'qvbr': RateControlOneOf5QvbrFromJSON(json['Qvbr']),
+    };
+}
+
+  export function RateControlOneOf5ToJSON(json: any): RateControlOneOf5 {
+      return RateControlOneOf5ToJSONTyped(json, false);
+  }
+
+  export function RateControlOneOf5ToJSONTyped(value?: RateControlOneOf5 | null, ignoreDiscriminator: boolean = false): any {
+    if (value == null) {
+        return value;
+    }
+
+    return {
+        
+        'Qvbr': RateControlOneOf5QvbrToJSON(value['qvbr']),
+    };
+}
+
@@ -0,0 +1,66 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * live-cmaf-transcoder
+ * API for the Live CMAF Transcoder
+ *
+ * The version of the OpenAPI document: 0.1.57
+ * 
+ *
+ * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
+ * https://openapi-generator.tech
+ * Do not edit the class manually.
+ */
+
+import { mapValues } from '../runtime';
+/**
+ * VAAPI only, quality-defined variable bitrate capped by the max bitrate
+ * @export
+ * @interface RateControlOneOf5Qvbr
+ */
+export interface RateControlOneOf5Qvbr {
+    /**
+     * 
+     * @type {number}
+     * @memberof RateControlOneOf5Qvbr
+     */
+    quality: number;
+}
+
+/**
+ * Check if a given object implements the RateControlOneOf5Qvbr interface.
+ */
+export function instanceOfRateControlOneOf5Qvbr(value: object): value is RateControlOneOf5Qvbr {
+    if (!('quality' in value) || value['quality'] === undefined) return false;
+    return true;
+}
+
+export function RateControlOneOf5QvbrFromJSON(json: any): RateControlOneOf5Qvbr {
+    return RateControlOneOf5QvbrFromJSONTyped(json, false);
+}
+
+export function RateControlOneOf5QvbrFromJSONTyped(json: any, ignoreDiscriminator: boolean): RateControlOneOf5Qvbr {
+    if (json == null) {
+        return json;
+    }
+    return {
+        
+        'quality': json['quality'],
+    };
+}
+
+  export function RateControlOneOf5QvbrToJSON(json: any): RateControlOneOf5Qvbr {
+      return RateControlOneOf5QvbrToJSONTyped(json, false);
+  }
+
+  export function RateControlOneOf5QvbrToJSONTyped(value?: RateControlOneOf5Qvbr | null, ignoreDiscriminator: boolean = false): any {
+    if (value == null) {
+        return value;
+    }
+
+    return {
+        
+        'quality': value['quality'],
+    };
+}
+
@@ -0,0 +1,74 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * live-cmaf-transcoder
+ * API for the Live CMAF Transcoder
+ *
+ * The version of the OpenAPI document: 0.1.57
+ * 
+ *
+ * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
+ * https://openapi-generator.tech
+ * Do not edit the class manually.
+ */
+
+import { mapValues } from '../runtime';
+import type { RateControlOneOf6Cq } from './RateControlOneOf6Cq';
+import {
+    RateControlOneOf6CqFromJSON,
+    RateControlOneOf6CqFromJSONTyped,
+    RateControlOneOf6CqToJSON,
+    RateControlOneOf6CqToJSONTyped,
+} from './RateControlOneOf6Cq';
+
+/**
+ * NVENC only, constant quality capped by the max bitrate
+ * @export
+ * @interface RateControlOneOf6
+ */
+export interface RateControlOneOf6 {
+    /**
+     * 
+     * @type {RateControlOneOf6Cq}
+     * @memberof RateControlOneOf6
+     */
+    cq: RateControlOneOf6Cq;
+}
+
+/**
+ * Check if a given object implements the RateControlOneOf6 interface.
+ */
+export function instanceOfRateControlOneOf6(value: object): value is RateControlOneOf6 {
+    if (!('cq' in value) || value['cq'] === undefined) return false;
+    return true;
+}
+
+export function RateControlOneOf6FromJSON(json: any): RateControlOneOf6 {
+    return RateControlOneOf6FromJSONTyped(json, false);
+}
+
+export function RateControlOneOf6FromJSONTyped(json: any, ignoreDiscriminator: boolean): RateControlOneOf6 {
+    if (json == null) {
+        return json;
+    }
+    return {
+        
+        'cq': RateControlOneOf6CqFromJSON(json['Cq']),
+    };
+}
+
+  export function RateControlOneOf6ToJSON(json: any): RateControlOneOf6 {
+      return RateControlOneOf6ToJSONTyped(json, false);
+  }
+
+  export function RateControlOneOf6ToJSONTyped(value?: RateControlOneOf6 | null, ignoreDiscriminator: boolean = false): any {
+    if (value == null) {
+        return value;
+    }
+
+    return {
+        
+        'Cq': RateControlOneOf6CqToJSON(value['cq']),
+    };
+}
+
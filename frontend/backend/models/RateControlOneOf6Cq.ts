@@ -0,0 +1,66 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * live-cmaf-transcoder
+ * API for the Live CMAF Transcoder
+ *
+ * The version of the OpenAPI document: 0.1.57
+ * 
+ *
+ * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
+ * https://openapi-generator.tech
+ * Do not edit the class manually.
+ */
+
+import { mapValues } from '../runtime';
+/**
+ * NVENC only, constant quality capped by the max bitrate
+ * @export
+ * @interface RateControlOneOf6Cq
+ */
+export interface RateControlOneOf6Cq {
+    /**
+     * 
+     * @type {number}
+     * @memberof RateControlOneOf6Cq
+     */
+    cq: number;
+}
+
+/**
+ * Check if a given object implements the RateControlOneOf6Cq interface.
+ */
+export function instanceOfRateControlOneOf6Cq(value: object): value is RateControlOneOf6Cq {
+    if (!('cq' in value) || value['cq'] === undefined) return false;
+    return true;
+}
+
+export function RateControlOneOf6CqFromJSON(json: any): RateControlOneOf6Cq {
+    return RateControlOneOf6CqFromJSONTyped(json, false);
+}
+
+export function RateControlOneOf6CqFromJSONTyped(json: any, ignoreDiscriminator: boolean): RateControlOneOf6Cq {
+    if (json == null) {
+        return json;
+    }
+    return {
+        
+        'cq': json['cq'],
+    };
+}
+
+  export function RateControlOneOf6CqToJSON(json: any): RateControlOneOf6Cq {
+      return RateControlOneOf6CqToJSONTyped(json, false);
+  }
+
+  export function RateControlOneOf6CqToJSONTyped(value?: RateControlOneOf6Cq | null, ignoreDiscriminator: boolean = false): any {
+    if (value == null) {
+        return value;
+    }
+
+    return {
+        
+        'cq': value['cq'],
+    };
+}
+
@@ -0,0 +1,53 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * live-cmaf-transcoder
+ * API for the Live CMAF Transcoder
+ *
+ * The version of the OpenAPI document: 0.1.57
+ * 
+ *
+ * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
+ * https://openapi-generator.tech
+ * Do not edit the class manually.
+ */
+
+
+/**
+ * 
+ * @export
+ */
+export const RistEncryption = {
+    Aes128: 'Aes128',
+    Aes256: 'Aes256'
+} as const;
+export type RistEncryption = typeof RistEncryption[keyof typeof RistEncryption];
+
+
+export function instanceOfRistEncryption(value: any): boolean {
+    for (const key in RistEncryption) {
+        if (Object.prototype.hasOwnProperty.call(RistEncryption, key)) {
+            if (RistEncryption[key as keyof typeof RistEncryption] === value) {
+                return true;
+            }
+        }
+    }
+    return false;
+}
+
+export function RistEncryptionFromJSON(json: any): RistEncryption {
+    return RistEncryptionFromJSONTyped(json, false);
+}
+
+export function RistEncryptionFromJSONTyped(json: any, ignoreDiscriminator: boolean): RistEncryption {
+    return json as RistEncryption;
+}
+
+export function RistEncryptionToJSON(value?: RistEncryption | null): any {
+    return value as any;
+}
+
+export function RistEncryptionToJSONTyped(value: any, ignoreDiscriminator: boolean): RistEncryption {
+    return value as RistEncryption;
+}
+
@@ -0,0 +1,116 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * live-cmaf-transcoder
+ * API for the Live CMAF Transcoder
+ *
+ * The version of the OpenAPI document: 0.1.57
+ * 
+ *
+ * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
+ * https://openapi-generator.tech
+ * Do not edit the class manually.
+ */
+
+import { mapValues } from '../runtime';
+import type { RistEncryption } from './RistEncryption';
+import {
+    RistEncryptionFromJSON,
+    RistEncryptionFromJSONTyped,
+    RistEncryptionToJSON,
+    RistEncryptionToJSONTyped,
+} from './RistEncryption';
+import type { RistProfile } from './RistProfile';
+import {
+    RistProfileFromJSON,
+    RistProfileFromJSONTyped,
+    RistProfileToJSON,
+    RistProfileToJSONTyped,
+} from './RistProfile';
+
+/**
+ * 
+ * @export
+ * @interface RistInput
+ */
+export interface RistInput {
+    /**
+     * Retransmission buffer in milliseconds
+     * @type {number}
+     * @memberof RistInput
+     */
+    bufferSizeMs?: number | null;
+    /**
+     * 
+     * @type {RistEncryption}
+     * @memberof RistInput
+     */
+    encryption?: RistEncryption | null;
+    /**
+     * 
+     * @type {RistProfile}
+     * @memberof RistInput
+     */
+    profile: RistProfile;
+    /**
+     * 
+     * @type {string}
+     * @memberof RistInput
+     */
+    secret?: string | null;
+    /**
+     * 
+     * @type {string}
+     * @memberof RistInput
+     */
+    uri: string;
+}
+
+
+
+/**
+ * Check if a given object implements the RistInput interface.
+ */
+export function instanceOfRistInput(value: object): value is RistInput {
+    if (!('profile' in value) || value['profile'] === undefined) return false;
+    if (!('uri' in value) || value['uri'] === undefined) return false;
+    return true;
+}
+
+export function RistInputFromJSON(json: any): RistInput {
+    return RistInputFromJSONTyped(json, false);
+}
+
+export function RistInputFromJSONTyped(json: any, ignoreDiscriminator: boolean): RistInput {
+    if (json == null) {
+        return json;
+    }
+    return {
+        
+        'bufferSizeMs': json['buffer_size_ms'] == null ? undefined : json['buffer_size_ms'],
+        'encryption': json['encryption'] == null ? undefined : RistEncryptionFromJSON(json['encryption']),
+        'profile': RistProfileFromJSON(json['profile']),
+        'secret': json['secret'] == null ? undefined : json['secret'],
+        'uri': json['uri'],
+    };
+}
+
+  export function RistInputToJSON(json: any): RistInput {
+      return RistInputToJSONTyped(json, false);
+  }
+
+  export function RistInputToJSONTyped(value?: RistInput | null, ignoreDiscriminator: boolean = false): any {
+    if (value == null) {
+        return value;
+    }
+
+    return {
+        
+        'buffer_size_ms': value['bufferSizeMs'],
+        'encryption': RistEncryptionToJSON(value['encryption']),
+        'profile': RistProfileToJSON(value['profile']),
+        'secret': value['secret'],
+        'uri': value['uri'],
+    };
+}
+
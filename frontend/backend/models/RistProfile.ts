@@ -0,0 +1,54 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * live-cmaf-transcoder
+ * API for the Live CMAF Transcoder
+ *
+ * The version of the OpenAPI document: 0.1.57
+ * 
+ *
+ * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
+ * https://openapi-generator.tech
+ * Do not edit the class manually.
+ */
+
+
+/**
+ * 
+ * @export
+ */
+export const RistProfile = {
+    Simple: 'Simple',
+    Main: 'Main',
+    Advanced: 'Advanced'
+} as const;
+export type RistProfile = typeof RistProfile[keyof typeof RistProfile];
+
+
+export function instanceOfRistProfile(value: any): boolean {
+    for (const key in RistProfile) {
+        if (Object.prototype.hasOwnProperty.call(RistProfile, key)) {
+            if (RistProfile[key as keyof typeof RistProfile] === value) {
+                return true;
+            }
+        }
+    }
+    return false;
+}
+
+export function RistProfileFromJSON(json: any): RistProfile {
+    return RistProfileFromJSONTyped(json, false);
+}
+
+export function RistProfileFromJSONTyped(json: any, ignoreDiscriminator: boolean): RistProfile {
+    return json as RistProfile;
+}
+
+export function RistProfileToJSON(value?: RistProfile | null): any {
+    return value as any;
+}
+
+export function RistProfileToJSONTyped(value: any, ignoreDiscriminator: boolean): RistProfile {
+    return value as RistProfile;
+}
+
@@ -4,7 +4,7 @@
  * live-cmaf-transcoder
  * API for the Live CMAF Transcoder
  *
- * The version of the OpenAPI document: 0.1.52
+ * The version of the OpenAPI document: 0.1.57
  * 
  *
  * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
@@ -14,11 +14,16 @@
 
 
 /**
- * 
+ * DASH role, urn:mpeg:dash:role:2011
  * @export
  */
 export const Role = {
-    Main: 'Main'
+    Main: 'Main',
+    Alternate: 'Alternate',
+    Supplementary: 'Supplementary',
+    Commentary: 'Commentary',
+    Dub: 'Dub',
+    Description: 'Description'
 } as const;
 export type Role = typeof Role[keyof typeof Role];
 
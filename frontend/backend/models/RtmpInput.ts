@@ -0,0 +1,94 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * live-cmaf-transcoder
+ * API for the Live CMAF Transcoder
+ *
+ * The version of the OpenAPI document: 0.1.57
+ * 
+ *
+ * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
+ * https://openapi-generator.tech
+ * Do not edit the class manually.
+ */
+
+import { mapValues } from '../runtime';
+import type { RtmpMode } from './RtmpMode';
+import {
+    RtmpModeFromJSON,
+    RtmpModeFromJSONTyped,
+    RtmpModeToJSON,
+    RtmpModeToJSONTyped,
+} from './RtmpMode';
+
+/**
+ * 
+ * @export
+ * @interface RtmpInput
+ */
+export interface RtmpInput {
+    /**
+     * 
+     * @type {RtmpMode}
+     * @memberof RtmpInput
+     */
+    mode: RtmpMode;
+    /**
+     * Pull: maximum time without data, Listen: maximum time to wait for the publisher
+     * @type {number}
+     * @memberof RtmpInput
+     */
+    timeoutMs: number;
+    /**
+     * 
+     * @type {string}
+     * @memberof RtmpInput
+     */
+    uri: string;
+}
+
+
+
+/**
+ * Check if a given object implements the RtmpInput interface.
+ */
+export function instanceOfRtmpInput(value: object): value is RtmpInput {
+    if (!('mode' in value) || value['mode'] === undefined) return false;
+    if (!('timeoutMs' in value) || value['timeoutMs'] === undefined) return false;
+    if (!('uri' in value) || value['uri'] === undefined) return false;
+    return true;
+}
+
+export function RtmpInputFromJSON(json: any): RtmpInput {
+    return RtmpInputFromJSONTyped(json, false);
+}
+
+export function RtmpInputFromJSONTyped(json: any, ignoreDiscriminator: boolean): RtmpInput {
+    if (json == null) {
+        return json;
+    }
+    return {
+        
+        'mode': RtmpModeFromJSON(json['mode']),
+        'timeoutMs': json['timeout_ms'],
+        'uri': json['uri'],
+    };
+}
+
+  export function RtmpInputToJSON(json: any): RtmpInput {
+      return RtmpInputToJSONTyped(json, false);
+  }
+
+  export function RtmpInputToJSONTyped(value?: RtmpInput | null, ignoreDiscriminator: boolean = false): any {
+    if (value == null) {
+        return value;
+    }
+
+    return {
+        
+        'mode': RtmpModeToJSON(value['mode']),
+        'timeout_ms': value['timeoutMs'],
+        'uri': value['uri'],
+    };
+}
+
@@ -0,0 +1,53 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * live-cmaf-transcoder
+ * API for the Live CMAF Transcoder
+ *
+ * The version of the OpenAPI document: 0.1.57
+ * 
+ *
+ * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
+ * https://openapi-generator.tech
+ * Do not edit the class manually.
+ */
+
+
+/**
+ * 
+ * @export
+ */
+export const RtmpMode = {
+    Pull: 'Pull',
+    Listen: 'Listen'
+} as const;
+export type RtmpMode = typeof RtmpMode[keyof typeof RtmpMode];
+
+
+export function instanceOfRtmpMode(value: any): boolean {
+    for (const key in RtmpMode) {
+        if (Object.prototype.hasOwnProperty.call(RtmpMode, key)) {
+            if (RtmpMode[key as keyof typeof RtmpMode] === value) {
+                return true;
+            }
+        }
+    }
+    return false;
+}
+
+export function RtmpModeFromJSON(json: any): RtmpMode {
+    return RtmpModeFromJSONTyped(json, false);
+}
+
+export function RtmpModeFromJSONTyped(json: any, ignoreDiscriminator: boolean): RtmpMode {
+    return json as RtmpMode;
+}
+
+export function RtmpModeToJSON(value?: RtmpMode | null): any {
+    return value as any;
+}
+
+export function RtmpModeToJSONTyped(value: any, ignoreDiscriminator: boolean): RtmpMode {
+    return value as RtmpMode;
+}
+
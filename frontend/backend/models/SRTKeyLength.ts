@@ -0,0 +1,54 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * live-cmaf-transcoder
+ * API for the Live CMAF Transcoder
+ *
+ * The version of the OpenAPI document: 0.1.57
+ * 
+ *
+ * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
+ * https://openapi-generator.tech
+ * Do not edit the class manually.
+ */
+
+
+/**
+ * 
+ * @export
+ */
+export const SRTKeyLength = {
+    Aes128: 'Aes128',
+    Aes192: 'Aes192',
+    Aes256: 'Aes256'
+} as const;
+export type SRTKeyLength = typeof SRTKeyLength[keyof typeof SRTKeyLength];
+
+
+export function instanceOfSRTKeyLength(value: any): boolean {
+    for (const key in SRTKeyLength) {
+        if (Object.prototype.hasOwnProperty.call(SRTKeyLength, key)) {
+            if (SRTKeyLength[key as keyof typeof SRTKeyLength] === value) {
+                return true;
+            }
+        }
+    }
+    return false;
+}
+
+export function SRTKeyLengthFromJSON(json: any): SRTKeyLength {
+    return SRTKeyLengthFromJSONTyped(json, false);
+}
+
+export function SRTKeyLengthFromJSONTyped(json: any, ignoreDiscriminator: boolean): SRTKeyLength {
+    return json as SRTKeyLength;
+}
+
+export function SRTKeyLengthToJSON(value?: SRTKeyLength | null): any {
+    return value as any;
+}
+
+export function SRTKeyLengthToJSONTyped(value: any, ignoreDiscriminator: boolean): SRTKeyLength {
+    return value as SRTKeyLength;
+}
+
@@ -0,0 +1,55 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * live-cmaf-transcoder
+ * API for the Live CMAF Transcoder
+ *
+ * The version of the OpenAPI document: 0.1.57
+ * 
+ *
+ * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
+ * https://openapi-generator.tech
+ * Do not edit the class manually.
+ */
+
+
+/**
+ * Scaling of the source to the size of a representation
+ * @export
+ */
+export const ScaleMode = {
+    Stretch: 'Stretch',
+    Fit: 'Fit',
+    Pad: 'Pad',
+    Fill: 'Fill'
+} as const;
+export type ScaleMode = typeof ScaleMode[keyof typeof ScaleMode];
+
+
+export function instanceOfScaleMode(value: any): boolean {
+    for (const key in ScaleMode) {
+        if (Object.prototype.hasOwnProperty.call(ScaleMode, key)) {
+            if (ScaleMode[key as keyof typeof ScaleMode] === value) {
+                return true;
+            }
+        }
+    }
+    return false;
+}
+
+export function ScaleModeFromJSON(json: any): ScaleMode {
+    return ScaleModeFromJSONTyped(json, false);
+}
+
+export function ScaleModeFromJSONTyped(json: any, ignoreDiscriminator: boolean): ScaleMode {
+    return json as ScaleMode;
+}
+
+export function ScaleModeToJSON(value?: ScaleMode | null): any {
+    return value as any;
+}
+
+export function ScaleModeToJSONTyped(value: any, ignoreDiscriminator: boolean): ScaleMode {
+    return value as ScaleMode;
+}
+
@@ -0,0 +1,90 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * live-cmaf-transcoder
+ * API for the Live CMAF Transcoder
+ *
+ * The version of the OpenAPI document: 0.1.57
+ * 
+ *
+ * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
+ * https://openapi-generator.tech
+ * Do not edit the class manually.
+ */
+
+import { mapValues } from '../runtime';
+import type { StreamSpecifier } from './StreamSpecifier';
+import {
+    StreamSpecifierFromJSON,
+    StreamSpecifierFromJSONTyped,
+    StreamSpecifierToJSON,
+    StreamSpecifierToJSONTyped,
+} from './StreamSpecifier';
+import type { Scte35HlsTags } from './Scte35HlsTags';
+import {
+    Scte35HlsTagsFromJSON,
+    Scte35HlsTagsFromJSONTyped,
+    Scte35HlsTagsToJSON,
+    Scte35HlsTagsToJSONTyped,
+} from './Scte35HlsTags';
+
+/**
+ * Passthrough of the SCTE-35 cues of the MPEG-TS input, written as an `EventStream` of the MPD and as tags of the HLS media playlists. A keyframe is forced at every splice point in all video representations, so that a new segment starts at the splice point.
+ * @export
+ * @interface Scte35
+ */
+export interface Scte35 {
+    /**
+     * Tags of the splice points in the HLS media playlists
+     * @type {Scte35HlsTags}
+     * @memberof Scte35
+     */
+    hlsTags?: Scte35HlsTags;
+    /**
+     * Data stream carrying the cues, the first data stream by default
+     * @type {StreamSpecifier}
+     * @memberof Scte35
+     */
+    source?: StreamSpecifier | null;
+}
+
+
+
+/**
+ * Check if a given object implements the Scte35 interface.
+ */
+export function instanceOfScte35(value: object): value is Scte35 {
+    return true;
+}
+
+export function Scte35FromJSON(json: any): Scte35 {
+    return Scte35FromJSONTyped(json, false);
+}
+
+export function Scte35FromJSONTyped(json: any, ignoreDiscriminator: boolean): Scte35 {
+    if (json == null) {
+        return json;
+    }
+    return {
+        
+        'hlsTags': json['hls_tags'] == null ? undefined : Scte35HlsTagsFromJSON(json['hls_tags']),
+        'source': json['source'] == null ? undefined : StreamSpecifierFromJSON(json['source']),
+    };
+}
+
+  export function Scte35ToJSON(json: any): Scte35 {
+      return Scte35ToJSONTyped(json, false);
+  }
+
+  export function Scte35ToJSONTyped(value?: Scte35 | null, ignoreDiscriminator: boolean = false): any {
+    if (value == null) {
+        return value;
+    }
+
+    return {
+        
+        'hls_tags': Scte35HlsTagsToJSON(value['hlsTags']),
+        'source': StreamSpecifierToJSON(value['source']),
+    };
+}
+
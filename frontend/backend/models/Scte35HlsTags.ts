@@ -0,0 +1,53 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * live-cmaf-transcoder
+ * API for the Live CMAF Transcoder
+ *
+ * The version of the OpenAPI document: 0.1.57
+ * 
+ *
+ * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
+ * https://openapi-generator.tech
+ * Do not edit the class manually.
+ */
+
+
+/**
+ * Tags of the splice points in the HLS media playlists
+ * @export
+ */
+export const Scte35HlsTags = {
+    DateRange: 'DateRange',
+    CueOut: 'CueOut'
+} as const;
+export type Scte35HlsTags = typeof Scte35HlsTags[keyof typeof Scte35HlsTags];
+
+
+export function instanceOfScte35HlsTags(value: any): boolean {
+    for (const key in Scte35HlsTags) {
+        if (Object.prototype.hasOwnProperty.call(Scte35HlsTags, key)) {
+            if (Scte35HlsTags[key as keyof typeof Scte35HlsTags] === value) {
+                return true;
+            }
+        }
+    }
+    return false;
+}
+
+export function Scte35HlsTagsFromJSON(json: any): Scte35HlsTags {
+    return Scte35HlsTagsFromJSONTyped(json, false);
+}
+
+export function Scte35HlsTagsFromJSONTyped(json: any, ignoreDiscriminator: boolean): Scte35HlsTags {
+    return json as Scte35HlsTags;
+}
+
+export function Scte35HlsTagsToJSON(value?: Scte35HlsTags | null): any {
+    return value as any;
+}
+
+export function Scte35HlsTagsToJSONTyped(value: any, ignoreDiscriminator: boolean): Scte35HlsTags {
+    return value as Scte35HlsTags;
+}
+
@@ -0,0 +1,78 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * live-cmaf-transcoder
+ * API for the Live CMAF Transcoder
+ *
+ * The version of the OpenAPI document: 0.1.57
+ * 
+ *
+ * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
+ * https://openapi-generator.tech
+ * Do not edit the class manually.
+ */
+
+import type { SharpenOneOf } from './SharpenOneOf';
+import {
+    instanceOfSharpenOneOf,
+    SharpenOneOfFromJSON,
+    SharpenOneOfFromJSONTyped,
+    SharpenOneOfToJSON,
+} from './SharpenOneOf';
+import type { SharpenOneOf1 } from './SharpenOneOf1';
+import {
+    instanceOfSharpenOneOf1,
+    SharpenOneOf1FromJSON,
+    SharpenOneOf1FromJSONTyped,
+    SharpenOneOf1ToJSON,
+} from './SharpenOneOf1';
+
+/**
+ * @type Sharpen
+ * 
+ * @export
+ */
+export type Sharpen = SharpenOneOf | SharpenOneOf1;
+
+export function SharpenFromJSON(json: any): Sharpen {
+    return SharpenFromJSONTyped(json, false);
+}
+
+export function SharpenFromJSONTyped(json: any, ignoreDiscriminator: boolean): Sharpen {
+    if (json == null) {
+        return json;
+    }
+    if (typeof json !== 'object') {
+        return json;
+    }
+    if ('Unsharp' in json) {
+        return SharpenOneOfFromJSONTyped(json, true);
+    }
+    if ('Vaapi' in json) {
+        return SharpenOneOf1FromJSONTyped(json, true);
+    }
+
+    return {} as any;
+}
+
+export function SharpenToJSON(json: any): any {
+    return SharpenToJSONTyped(json, false);
+}
+
+export function SharpenToJSONTyped(value?: Sharpen | null, ignoreDiscriminator: boolean = false): any {
+    if (value == null) {
+        return value;
+    }
+    if (typeof value !== 'object') {
+        return value;
+    }
+    if (instanceOfSharpenOneOf(value)) {
+        return SharpenOneOfToJSON(value as SharpenOneOf);
+    }
+    if (instanceOfSharpenOneOf1(value)) {
+        return SharpenOneOf1ToJSON(value as SharpenOneOf1);
+    }
+
+    return {};
+}
+
@@ -0,0 +1,74 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * live-cmaf-transcoder
+ * API for the Live CMAF Transcoder
+ *
+ * The version of the OpenAPI document: 0.1.57
+ * 
+ *
+ * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
+ * https://openapi-generator.tech
+ * Do not edit the class manually.
+ */
+
+import { mapValues } from '../runtime';
+import type { SharpenOneOfUnsharp } from './SharpenOneOfUnsharp';
+import {
+    SharpenOneOfUnsharpFromJSON,
+    SharpenOneOfUnsharpFromJSONTyped,
+    SharpenOneOfUnsharpToJSON,
+    SharpenOneOfUnsharpToJSONTyped,
+} from './SharpenOneOfUnsharp';
+
+/**
+ * Unsharp mask, luma amount from 0 to 5
+ * @export
+ * @interface SharpenOneOf
+ */
+export interface SharpenOneOf {
+    /**
+     * 
+     * @type {SharpenOneOfUnsharp}
+     * @memberof SharpenOneOf
+     */
+    unsharp: SharpenOneOfUnsharp;
+}
+
+/**
+ * Check if a given object implements the SharpenOneOf interface.
+ */
+export function instanceOfSharpenOneOf(value: object): value is SharpenOneOf {
+    if (!('unsharp' in value) || value['unsharp'] === undefined) return false;
+    return true;
+}
+
+export function SharpenOneOfFromJSON(json: any): SharpenOneOf {
+    return SharpenOneOfFromJSONTyped(json, false);
+}
+
+export function SharpenOneOfFromJSONTyped(json: any, ignoreDiscriminator: boolean): SharpenOneOf {
+    if (json == null) {
+        return json;
+    }
+    return {
+        
+        'unsharp': SharpenOneOfUnsharpFromJSON(json['Unsharp']),
+    };
+}
+
+  export function SharpenOneOfToJSON(json: any): SharpenOneOf {
+      return SharpenOneOfToJSONTyped(json, false);
+  }
+
+  export function SharpenOneOfToJSONTyped(value?: SharpenOneOf | null, ignoreDiscriminator: boolean = false): any {
+    if (value == null) {
+        return value;
+    }
+
+    return {
+        
+        'Unsharp': SharpenOneOfUnsharpToJSON(value['unsharp']),
+    };
+}
+
@@ -0,0 +1,74 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * live-cmaf-transcoder
+ * API for the Live CMAF Transcoder
+ *
+ * The version of the OpenAPI document: 0.1.57
+ * 
+ *
+ * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
+ * https://openapi-generator.tech
+ * Do not edit the class manually.
+ */
+
+import { mapValues } from '../runtime';
+import type { SharpenOneOf1Vaapi } from './SharpenOneOf1Vaapi';
+import {
+    SharpenOneOf1VaapiFromJSON,
+    SharpenOneOf1VaapiFromJSONTyped,
+    SharpenOneOf1VaapiToJSON,
+    SharpenOneOf1VaapiToJSONTyped,
+} from './SharpenOneOf1Vaapi';
+
+/**
+ * VAAPI sharpening, level from 0 to 64
+ * @export
+ * @interface SharpenOneOf1
+ */
+export interface SharpenOneOf1 {
+    /**
+     * 
+     * @type {SharpenOneOf1Vaapi}
+     * @memberof SharpenOneOf1
+     */
+    vaapi: SharpenOneOf1Vaapi;
+}
+
+/**
+ * Check if a given object implements the SharpenOneOf1 interface.
+ */
+export function instanceOfSharpenOneOf1(value: object): value is SharpenOneOf1 {
+    if (!('vaapi' in value) || value['vaapi'] === undefined) return false;
+    return true;
+}
+
+export function SharpenOneOf1FromJSON(json: any): SharpenOneOf1 {
+    return SharpenOneOf1FromJSONTyped(json, false);
+}
+
+export function SharpenOneOf1FromJSONTyped(json: any, ignoreDiscriminator: boolean): SharpenOneOf1 {
+    if (json == null) {
+        return json;
+    }
+    return {
+        
+        'vaapi': SharpenOneOf1VaapiFromJSON(json['Vaapi']),
+    };
+}
+
+  export function SharpenOneOf1ToJSON(json: any): SharpenOneOf1 {
+      return SharpenOneOf1ToJSONTyped(json, false);
+  }
+
+  export function SharpenOneOf1ToJSONTyped(value?: SharpenOneOf1 | null, ignoreDiscriminator: boolean = false): any {
+    if (value == null) {
+        return value;
+    }
+
+    return {
+        
+        'Vaapi': SharpenOneOf1VaapiToJSON(value['vaapi']),
+    };
+}
+
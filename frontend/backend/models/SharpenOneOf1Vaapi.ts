@@ -0,0 +1,66 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * live-cmaf-transcoder
+ * API for the Live CMAF Transcoder
+ *
+ * The version of the OpenAPI document: 0.1.57
+ * 
+ *
+ * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
+ * https://openapi-generator.tech
+ * Do not edit the class manually.
+ */
+
+import { mapValues } from '../runtime';
+/**
+ * VAAPI sharpening, level from 0 to 64
+ * @export
+ * @interface SharpenOneOf1Vaapi
+ */
+export interface SharpenOneOf1Vaapi {
+    /**
+     * 
+     * @type {number}
+     * @memberof SharpenOneOf1Vaapi
+     */
+    level: number;
+}
+
+/**
+ * Check if a given object implements the SharpenOneOf1Vaapi interface.
+ */
+export function instanceOfSharpenOneOf1Vaapi(value: object): value is SharpenOneOf1Vaapi {
+    if (!('level' in value) || value['level'] === undefined) return false;
+    return true;
+}
+
+export function SharpenOneOf1VaapiFromJSON(json: any): SharpenOneOf1Vaapi {
+    return SharpenOneOf1VaapiFromJSONTyped(json, false);
+}
+
+export function SharpenOneOf1VaapiFromJSONTyped(json: any, ignoreDiscriminator: boolean): SharpenOneOf1Vaapi {
+    if (json == null) {
+        return json;
+    }
+    return {
+        
+        'level': json['level'],
+    };
+}
+
+  export function SharpenOneOf1VaapiToJSON(json: any): SharpenOneOf1Vaapi {
+      return SharpenOneOf1VaapiToJSONTyped(json, false);
+  }
+
+  export function SharpenOneOf1VaapiToJSONTyped(value?: SharpenOneOf1Vaapi | null, ignoreDiscriminator: boolean = false): any {
+    if (value == null) {
+        return value;
+    }
+
+    return {
+        
+        'level': value['level'],
+    };
+}
+
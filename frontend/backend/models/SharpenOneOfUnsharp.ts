@@ -0,0 +1,66 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * live-cmaf-transcoder
+ * API for the Live CMAF Transcoder
+ *
+ * The version of the OpenAPI document: 0.1.57
+ * 
+ *
+ * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
+ * https://openapi-generator.tech
+ * Do not edit the class manually.
+ */
+
+import { mapValues } from '../runtime';
+/**
+ * Unsharp mask, luma amount from 0 to 5
+ * @export
+ * @interface SharpenOneOfUnsharp
+ */
+export interface SharpenOneOfUnsharp {
+    /**
+     * 
+     * @type {number}
+     * @memberof SharpenOneOfUnsharp
+     */
+    amount: number;
+}
+
+/**
+ * Check if a given object implements the SharpenOneOfUnsharp interface.
+ */
+export function instanceOfSharpenOneOfUnsharp(value: object): value is SharpenOneOfUnsharp {
+    if (!('amount' in value) || value['amount'] === undefined) return false;
+    return true;
+}
+
+export function SharpenOneOfUnsharpFromJSON(json: any): SharpenOneOfUnsharp {
+    return SharpenOneOfUnsharpFromJSONTyped(json, false);
+}
+
+export function SharpenOneOfUnsharpFromJSONTyped(json: any, ignoreDiscriminator: boolean): SharpenOneOfUnsharp {
+    if (json == null) {
+        return json;
+    }
+    return {
+        
+        'amount': json['amount'],
+    };
+}
+
+  export function SharpenOneOfUnsharpToJSON(json: any): SharpenOneOfUnsharp {
+      return SharpenOneOfUnsharpToJSONTyped(json, false);
+  }
+
+  export function SharpenOneOfUnsharpToJSONTyped(value?: SharpenOneOfUnsharp | null, ignoreDiscriminator: boolean = false): any {
+    if (value == null) {
+        return value;
+    }
+
+    return {
+        
+        'amount': value['amount'],
+    };
+}
+
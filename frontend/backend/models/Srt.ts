@@ -4,7 +4,7 @@
  * live-cmaf-transcoder
  * API for the Live CMAF Transcoder
  *
- * The version of the OpenAPI document: 0.1.52
+ * The version of the OpenAPI document: 0.1.57
  * 
  *
  * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
@@ -13,6 +13,13 @@
  */
 
 import { mapValues } from '../runtime';
+import type { SRTKeyLength } from './SRTKeyLength';
+import {
+    SRTKeyLengthFromJSON,
+    SRTKeyLengthFromJSONTyped,
+    SRTKeyLengthToJSON,
+    SRTKeyLengthToJSONTyped,
+} from './SRTKeyLength';
 import type { SRTMode } from './SRTMode';
 import {
     SRTModeFromJSON,
@@ -33,12 +40,48 @@ export interface Srt {
      * @memberof Srt
      */
     connectTimeoutMs: number;
+    /**
+     * 
+     * @type {number}
+     * @memberof Srt
+     */
+    latencyMs?: number | null;
+    /**
+     * Maximum bandwidth in bytes per second
+     * @type {number}
+     * @memberof Srt
+     */
+    maxbw?: number | null;
     /**
      * 
      * @type {SRTMode}
      * @memberof Srt
      */
     mode: SRTMode;
+    /**
+     * Encryption passphrase, 10 to 79 characters
+     * @type {string}
+     * @memberof Srt
+     */
+    passphrase?: string | null;
+    /**
+     * 
+     * @type {SRTKeyLength}
+     * @memberof Srt
+     */
+    pbkeylen?: SRTKeyLength | null;
+    /**
+     * 
+     * @type {number}
+     * @memberof Srt
+     */
+    rcvlatencyMs?: number | null;
+    /**
+     * 
+     * @type {string}
+     * @memberof Srt
+     */
+    streamid?: string | null;
     /**
      * 
      * @type {string}
@@ -70,7 +113,13 @@ export function SrtFromJSONTyped(json: any, ignoreDiscriminator: boolean): Srt {
     return {
         
         'connectTimeoutMs': json['connect_timeout_ms'],
+        'latencyMs': json['latency_ms'] == null ? undefined : json['latency_ms'],
+        'maxbw': json['maxbw'] == null ? undefined : json['maxbw'],
         'mode': SRTModeFromJSON(json['mode']),
+        'passphrase': json['passphrase'] == null ? undefined : json['passphrase'],
+        'pbkeylen': json['pbkeylen'] == null ? undefined : SRTKeyLengthFromJSON(json['pbkeylen']),
+        'rcvlatencyMs': json['rcvlatency_ms'] == null ? undefined : json['rcvlatency_ms'],
+        'streamid': json['streamid'] == null ? undefined : json['streamid'],
         'uri': json['uri'],
     };
 }
@@ -87,7 +136,13 @@ export function SrtFromJSONTyped(json: any, ignoreDiscriminator: boolean): Srt {
     return {
         
         'connect_timeout_ms': value['connectTimeoutMs'],
+        'latency_ms': value['latencyMs'],
+        'maxbw': value['maxbw'],
         'mode': SRTModeToJSON(value['mode']),
+        'passphrase': value['passphrase'],
+        'pbkeylen': SRTKeyLengthToJSON(value['pbkeylen']),
+        'rcvlatency_ms': value['rcvlatencyMs'],
+        'streamid': value['streamid'],
         'uri': value['uri'],
     };
 }
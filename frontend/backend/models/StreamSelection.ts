@@ -0,0 +1,89 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * live-cmaf-transcoder
+ * API for the Live CMAF Transcoder
+ *
+ * The version of the OpenAPI document: 0.1.57
+ * 
+ *
+ * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
+ * https://openapi-generator.tech
+ * Do not edit the class manually.
+ */
+
+import { mapValues } from '../runtime';
+import type { StreamSpecifier } from './StreamSpecifier';
+import {
+    StreamSpecifierFromJSON,
+    StreamSpecifierFromJSONTyped,
+    StreamSpecifierToJSON,
+    StreamSpecifierToJSONTyped,
+} from './StreamSpecifier';
+
+/**
+ * Streams of the input used by the encoder, the first video and audio streams of the input are used by default
+ * @export
+ * @interface StreamSelection
+ */
+export interface StreamSelection {
+    /**
+     * 
+     * @type {StreamSpecifier}
+     * @memberof StreamSelection
+     */
+    audio?: StreamSpecifier | null;
+    /**
+     * Program number of a multi-program transport stream
+     * @type {number}
+     * @memberof StreamSelection
+     */
+    program?: number | null;
+    /**
+     * 
+     * @type {StreamSpecifier}
+     * @memberof StreamSelection
+     */
+    video?: StreamSpecifier | null;
+}
+
+/**
+ * Check if a given object implements the StreamSelection interface.
+ */
+export function instanceOfStreamSelection(value: object): value is StreamSelection {
+    return true;
+}
+
+export function StreamSelectionFromJSON(json: any): StreamSelection {
+    return StreamSelectionFromJSONTyped(json, false);
+}
+
+export function StreamSelectionFromJSONTyped(json: any, ignoreDiscriminator: boolean): StreamSelection {
+    if (json == null) {
+        return json;
+    }
+    return {
+        
+        'audio': json['audio'] == null ? undefined : StreamSpecifierFromJSON(json['audio']),
+        'program': json['program'] == null ? undefined : json['program'],
+        'video': json['video'] == null ? undefined : StreamSpecifierFromJSON(json['video']),
+    };
+}
+
+  export function StreamSelectionToJSON(json: any): StreamSelection {
+      return StreamSelectionToJSONTyped(json, false);
+  }
+
+  export function StreamSelectionToJSONTyped(value?: StreamSelection | null, ignoreDiscriminator: boolean = false): any {
+    if (value == null) {
+        return value;
+    }
+
+    return {
+        
+        'audio': StreamSpecifierToJSON(value['audio']),
+        'program': value['program'],
+        'video': StreamSpecifierToJSON(value['video']),
+    };
+}
+
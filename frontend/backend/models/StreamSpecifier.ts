@@ -0,0 +1,91 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * live-cmaf-transcoder
+ * API for the Live CMAF Transcoder
+ *
+ * The version of the OpenAPI document: 0.1.57
+ * 
+ *
+ * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
+ * https://openapi-generator.tech
+ * Do not edit the class manually.
+ */
+
+import type { StreamSpecifierOneOf } from './StreamSpecifierOneOf';
+import {
+    instanceOfStreamSpecifierOneOf,
+    StreamSpecifierOneOfFromJSON,
+    StreamSpecifierOneOfFromJSONTyped,
+    StreamSpecifierOneOfToJSON,
+} from './StreamSpecifierOneOf';
+import type { StreamSpecifierOneOf1 } from './StreamSpecifierOneOf1';
+import {
+    instanceOfStreamSpecifierOneOf1,
+    StreamSpecifierOneOf1FromJSON,
+    StreamSpecifierOneOf1FromJSONTyped,
+    StreamSpecifierOneOf1ToJSON,
+} from './StreamSpecifierOneOf1';
+import type { StreamSpecifierOneOf2 } from './StreamSpecifierOneOf2';
+import {
+    instanceOfStreamSpecifierOneOf2,
+    StreamSpecifierOneOf2FromJSON,
+    StreamSpecifierOneOf2FromJSONTyped,
+    StreamSpecifierOneOf2ToJSON,
+} from './StreamSpecifierOneOf2';
+
+/**
+ * @type StreamSpecifier
+ * 
+ * @export
+ */
+export type StreamSpecifier = StreamSpecifierOneOf | StreamSpecifierOneOf1 | StreamSpecifierOneOf2;
+
+export function StreamSpecifierFromJSON(json: any): StreamSpecifier {
+    return StreamSpecifierFromJSONTyped(json, false);
+}
+
+export function StreamSpecifierFromJSONTyped(json: any, ignoreDiscriminator: boolean): StreamSpecifier {
+    if (json == null) {
+        return json;
+    }
+    if (typeof json !== 'object') {
+        return json;
+    }
+    if ('Index' in json) {
+        return StreamSpecifierOneOfFromJSONTyped(json, true);
+    }
+    if ('Pid' in json) {
+        return StreamSpecifierOneOf1FromJSONTyped(json, true);
+    }
+    if ('Language' in json) {
+        return StreamSpecifierOneOf2FromJSONTyped(json, true);
+    }
+
+    return {} as any;
+}
+
+export function StreamSpecifierToJSON(json: any): any {
+    return StreamSpecifierToJSONTyped(json, false);
+}
+
+export function StreamSpecifierToJSONTyped(value?: StreamSpecifier | null, ignoreDiscriminator: boolean = false): any {
+    if (value == null) {
+        return value;
+    }
+    if (typeof value !== 'object') {
+        return value;
+    }
+    if (instanceOfStreamSpecifierOneOf(value)) {
+        return StreamSpecifierOneOfToJSON(value as StreamSpecifierOneOf);
+    }
+    if (instanceOfStreamSpecifierOneOf1(value)) {
+        return StreamSpecifierOneOf1ToJSON(value as StreamSpecifierOneOf1);
+    }
+    if (instanceOfStreamSpecifierOneOf2(value)) {
+        return StreamSpecifierOneOf2ToJSON(value as StreamSpecifierOneOf2);
+    }
+
+    return {};
+}
+
@@ -0,0 +1,66 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * live-cmaf-transcoder
+ * API for the Live CMAF Transcoder
+ *
+ * The version of the OpenAPI document: 0.1.57
+ * 
+ *
+ * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
+ * https://openapi-generator.tech
+ * Do not edit the class manually.
+ */
+
+import { mapValues } from '../runtime';
+/**
+ * Nth stream of this type, starting at 0
+ * @export
+ * @interface StreamSpecifierOneOf
+ */
+export interface StreamSpecifierOneOf {
+    /**
+     * Nth stream of this type, starting at 0
+     * @type {number}
+     * @memberof StreamSpecifierOneOf
+     */
+    index: number;
+}
+
+/**
+ * Check if a given object implements the StreamSpecifierOneOf interface.
+ */
+export function instanceOfStreamSpecifierOneOf(value: object): value is StreamSpecifierOneOf {
+    if (!('index' in value) || value['index'] === undefined) return false;
+    return true;
+}
+
+export function StreamSpecifierOneOfFromJSON(json: any): StreamSpecifierOneOf {
+    return StreamSpecifierOneOfFromJSONTyped(json, false);
+}
+
+export function StreamSpecifierOneOfFromJSONTyped(json: any, ignoreDiscriminator: boolean): StreamSpecifierOneOf {
+    if (json == null) {
+        return json;
+    }
+    return {
+        
+        'index': json['Index'],
+    };
+}
+
+  export function StreamSpecifierOneOfToJSON(json: any): StreamSpecifierOneOf {
+      return StreamSpecifierOneOfToJSONTyped(json, false);
+  }
+
+  export function StreamSpecifierOneOfToJSONTyped(value?: StreamSpecifierOneOf | null, ignoreDiscriminator: boolean = false): any {
+    if (value == null) {
+        return value;
+    }
+
+    return {
+        
+        'Index': value['index'],
+    };
+}
+
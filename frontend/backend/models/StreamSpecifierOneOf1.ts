@@ -0,0 +1,66 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * live-cmaf-transcoder
+ * API for the Live CMAF Transcoder
+ *
+ * The version of the OpenAPI document: 0.1.57
+ * 
+ *
+ * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
+ * https://openapi-generator.tech
+ * Do not edit the class manually.
+ */
+
+import { mapValues } from '../runtime';
+/**
+ * MPEG-TS PID
+ * @export
+ * @interface StreamSpecifierOneOf1
+ */
+export interface StreamSpecifierOneOf1 {
+    /**
+     * MPEG-TS PID
+     * @type {number}
+     * @memberof StreamSpecifierOneOf1
+     */
+    pid: number;
+}
+
+/**
+ * Check if a given object implements the StreamSpecifierOneOf1 interface.
+ */
+export function instanceOfStreamSpecifierOneOf1(value: object): value is StreamSpecifierOneOf1 {
+    if (!('pid' in value) || value['pid'] === undefined) return false;
+    return true;
+}
+
+export function StreamSpecifierOneOf1FromJSON(json: any): StreamSpecifierOneOf1 {
+    return StreamSpecifierOneOf1FromJSONTyped(json, false);
+}
+
+export function StreamSpecifierOneOf1FromJSONTyped(json: any, ignoreDiscriminator: boolean): StreamSpecifierOneOf1 {
+    if (json == null) {
+        return json;
+    }
+    return {
+        
+        'pid': json['Pid'],
+    };
+}
+
+  export function StreamSpecifierOneOf1ToJSON(json: any): StreamSpecifierOneOf1 {
+      return StreamSpecifierOneOf1ToJSONTyped(json, false);
+  }
+
+  export function StreamSpecifierOneOf1ToJSONTyped(value?: StreamSpecifierOneOf1 | null, ignoreDiscriminator: boolean = false): any {
+    if (value == null) {
+        return value;
+    }
+
+    return {
+        
+        'Pid': value['pid'],
+    };
+}
+
@@ -0,0 +1,66 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * live-cmaf-transcoder
+ * API for the Live CMAF Transcoder
+ *
+ * The version of the OpenAPI document: 0.1.57
+ * 
+ *
+ * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
+ * https://openapi-generator.tech
+ * Do not edit the class manually.
+ */
+
+import { mapValues } from '../runtime';
+/**
+ * ISO-639 language code. The filter graph uses the first matching stream, `-map` maps every matching stream.
+ * @export
+ * @interface StreamSpecifierOneOf2
+ */
+export interface StreamSpecifierOneOf2 {
+    /**
+     * ISO-639 language code. The filter graph uses the first matching stream, `-map` maps every matching stream.
+     * @type {string}
+     * @memberof StreamSpecifierOneOf2
+     */
+    language: string;
+}
+
+/**
+ * Check if a given object implements the StreamSpecifierOneOf2 interface.
+ */
+export function instanceOfStreamSpecifierOneOf2(value: object): value is StreamSpecifierOneOf2 {
+    if (!('language' in value) || value['language'] === undefined) return false;
+    return true;
+}
+
+export function StreamSpecifierOneOf2FromJSON(json: any): StreamSpecifierOneOf2 {
+    return StreamSpecifierOneOf2FromJSONTyped(json, false);
+}
+
+export function StreamSpecifierOneOf2FromJSONTyped(json: any, ignoreDiscriminator: boolean): StreamSpecifierOneOf2 {
+    if (json == null) {
+        return json;
+    }
+    return {
+        
+        'language': json['Language'],
+    };
+}
+
+  export function StreamSpecifierOneOf2ToJSON(json: any): StreamSpecifierOneOf2 {
+      return StreamSpecifierOneOf2ToJSONTyped(json, false);
+  }
+
+  export function StreamSpecifierOneOf2ToJSONTyped(value?: StreamSpecifierOneOf2 | null, ignoreDiscriminator: boolean = false): any {
+    if (value == null) {
+        return value;
+    }
+
+    return {
+        
+        'Language': value['language'],
+    };
+}
+
@@ -0,0 +1,54 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * live-cmaf-transcoder
+ * API for the Live CMAF Transcoder
+ *
+ * The version of the OpenAPI document: 0.1.57
+ * 
+ *
+ * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
+ * https://openapi-generator.tech
+ * Do not edit the class manually.
+ */
+
+
+/**
+ * 
+ * @export
+ */
+export const SubtitleFormat = {
+    WebVtt: 'WebVtt',
+    Imsc1: 'Imsc1',
+    BurnIn: 'BurnIn'
+} as const;
+export type SubtitleFormat = typeof SubtitleFormat[keyof typeof SubtitleFormat];
+
+
+export function instanceOfSubtitleFormat(value: any): boolean {
+    for (const key in SubtitleFormat) {
+        if (Object.prototype.hasOwnProperty.call(SubtitleFormat, key)) {
+            if (SubtitleFormat[key as keyof typeof SubtitleFormat] === value) {
+                return true;
+            }
+        }
+    }
+    return false;
+}
+
+export function SubtitleFormatFromJSON(json: any): SubtitleFormat {
+    return SubtitleFormatFromJSONTyped(json, false);
+}
+
+export function SubtitleFormatFromJSONTyped(json: any, ignoreDiscriminator: boolean): SubtitleFormat {
+    return json as SubtitleFormat;
+}
+
+export function SubtitleFormatToJSON(value?: SubtitleFormat | null): any {
+    return value as any;
+}
+
+export function SubtitleFormatToJSONTyped(value: any, ignoreDiscriminator: boolean): SubtitleFormat {
+    return value as SubtitleFormat;
+}
+
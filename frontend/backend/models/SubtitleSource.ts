@@ -0,0 +1,65 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * live-cmaf-transcoder
+ * API for the Live CMAF Transcoder
+ *
+ * The version of the OpenAPI document: 0.1.57
+ * 
+ *
+ * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
+ * https://openapi-generator.tech
+ * Do not edit the class manually.
+ */
+
+import type { SubtitleSourceOneOf } from './SubtitleSourceOneOf';
+import {
+    instanceOfSubtitleSourceOneOf,
+    SubtitleSourceOneOfFromJSON,
+    SubtitleSourceOneOfFromJSONTyped,
+    SubtitleSourceOneOfToJSON,
+} from './SubtitleSourceOneOf';
+
+/**
+ * @type SubtitleSource
+ * 
+ * @export
+ */
+export type SubtitleSource = SubtitleSourceOneOf | string;
+
+export function SubtitleSourceFromJSON(json: any): SubtitleSource {
+    return SubtitleSourceFromJSONTyped(json, false);
+}
+
+export function SubtitleSourceFromJSONTyped(json: any, ignoreDiscriminator: boolean): SubtitleSource {
+    if (json == null) {
+        return json;
+    }
+    if (typeof json !== 'object') {
+        return json;
+    }
+    if ('Teletext' in json) {
+        return SubtitleSourceOneOfFromJSONTyped(json, true);
+    }
+
+    return {} as any;
+}
+
+export function SubtitleSourceToJSON(json: any): any {
+    return SubtitleSourceToJSONTyped(json, false);
+}
+
+export function SubtitleSourceToJSONTyped(value?: SubtitleSource | null, ignoreDiscriminator: boolean = false): any {
+    if (value == null) {
+        return value;
+    }
+    if (typeof value !== 'object') {
+        return value;
+    }
+    if (instanceOfSubtitleSourceOneOf(value)) {
+        return SubtitleSourceOneOfToJSON(value as SubtitleSourceOneOf);
+    }
+
+    return {};
+}
+
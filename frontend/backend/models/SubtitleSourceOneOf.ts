@@ -0,0 +1,74 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * live-cmaf-transcoder
+ * API for the Live CMAF Transcoder
+ *
+ * The version of the OpenAPI document: 0.1.57
+ * 
+ *
+ * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
+ * https://openapi-generator.tech
+ * Do not edit the class manually.
+ */
+
+import { mapValues } from '../runtime';
+import type { SubtitleSourceOneOfTeletext } from './SubtitleSourceOneOfTeletext';
+import {
+    SubtitleSourceOneOfTeletextFromJSON,
+    SubtitleSourceOneOfTeletextFromJSONTyped,
+    SubtitleSourceOneOfTeletextToJSON,
+    SubtitleSourceOneOfTeletextToJSONTyped,
+} from './SubtitleSourceOneOfTeletext';
+
+/**
+ * DVB teletext, decoded with libzvbi
+ * @export
+ * @interface SubtitleSourceOneOf
+ */
+export interface SubtitleSourceOneOf {
+    /**
+     * 
+     * @type {SubtitleSourceOneOfTeletext}
+     * @memberof SubtitleSourceOneOf
+     */
+    teletext: SubtitleSourceOneOfTeletext;
+}
+
+/**
+ * Check if a given object implements the SubtitleSourceOneOf interface.
+ */
+export function instanceOfSubtitleSourceOneOf(value: object): value is SubtitleSourceOneOf {
+    if (!('teletext' in value) || value['teletext'] === undefined) return false;
+    return true;
+}
+
+export function SubtitleSourceOneOfFromJSON(json: any): SubtitleSourceOneOf {
+    return SubtitleSourceOneOfFromJSONTyped(json, false);
+}
+
+export function SubtitleSourceOneOfFromJSONTyped(json: any, ignoreDiscriminator: boolean): SubtitleSourceOneOf {
+    if (json == null) {
+        return json;
+    }
+    return {
+        
+        'teletext': SubtitleSourceOneOfTeletextFromJSON(json['Teletext']),
+    };
+}
+
+  export function SubtitleSourceOneOfToJSON(json: any): SubtitleSourceOneOf {
+      return SubtitleSourceOneOfToJSONTyped(json, false);
+  }
+
+  export function SubtitleSourceOneOfToJSONTyped(value?: SubtitleSourceOneOf | null, ignoreDiscriminator: boolean = false): any {
+    if (value == null) {
+        return value;
+    }
+
+    return {
+        
+        'Teletext': SubtitleSourceOneOfTeletextToJSON(value['teletext']),
+    };
+}
+
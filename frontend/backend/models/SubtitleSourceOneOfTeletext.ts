@@ -0,0 +1,66 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * live-cmaf-transcoder
+ * API for the Live CMAF Transcoder
+ *
+ * The version of the OpenAPI document: 0.1.57
+ * 
+ *
+ * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
+ * https://openapi-generator.tech
+ * Do not edit the class manually.
+ */
+
+import { mapValues } from '../runtime';
+/**
+ * DVB teletext, decoded with libzvbi
+ * @export
+ * @interface SubtitleSourceOneOfTeletext
+ */
+export interface SubtitleSourceOneOfTeletext {
+    /**
+     * 
+     * @type {number}
+     * @memberof SubtitleSourceOneOfTeletext
+     */
+    page: number;
+}
+
+/**
+ * Check if a given object implements the SubtitleSourceOneOfTeletext interface.
+ */
+export function instanceOfSubtitleSourceOneOfTeletext(value: object): value is SubtitleSourceOneOfTeletext {
+    if (!('page' in value) || value['page'] === undefined) return false;
+    return true;
+}
+
+export function SubtitleSourceOneOfTeletextFromJSON(json: any): SubtitleSourceOneOfTeletext {
+    return SubtitleSourceOneOfTeletextFromJSONTyped(json, false);
+}
+
+export function SubtitleSourceOneOfTeletextFromJSONTyped(json: any, ignoreDiscriminator: boolean): SubtitleSourceOneOfTeletext {
+    if (json == null) {
+        return json;
+    }
+    return {
+        
+        'page': json['page'],
+    };
+}
+
+  export function SubtitleSourceOneOfTeletextToJSON(json: any): SubtitleSourceOneOfTeletext {
+      return SubtitleSourceOneOfTeletextToJSONTyped(json, false);
+  }
+
+  export function SubtitleSourceOneOfTeletextToJSONTyped(value?: SubtitleSourceOneOfTeletext | null, ignoreDiscriminator: boolean = false): any {
+    if (value == null) {
+        return value;
+    }
+
+    return {
+        
+        'page': value['page'],
+    };
+}
+
@@ -0,0 +1,117 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * live-cmaf-transcoder
+ * API for the Live CMAF Transcoder
+ *
+ * The version of the OpenAPI document: 0.1.57
+ * 
+ *
+ * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
+ * https://openapi-generator.tech
+ * Do not edit the class manually.
+ */
+
+import { mapValues } from '../runtime';
+import type { StreamSpecifier } from './StreamSpecifier';
+import {
+    StreamSpecifierFromJSON,
+    StreamSpecifierFromJSONTyped,
+    StreamSpecifierToJSON,
+    StreamSpecifierToJSONTyped,
+} from './StreamSpecifier';
+import type { SubtitleSource } from './SubtitleSource';
+import {
+    SubtitleSourceFromJSON,
+    SubtitleSourceFromJSONTyped,
+    SubtitleSourceToJSON,
+    SubtitleSourceToJSONTyped,
+} from './SubtitleSource';
+import type { SubtitleFormat } from './SubtitleFormat';
+import {
+    SubtitleFormatFromJSON,
+    SubtitleFormatFromJSONTyped,
+    SubtitleFormatToJSON,
+    SubtitleFormatToJSONTyped,
+} from './SubtitleFormat';
+
+/**
+ * Subtitles of the source, each text track has its own adaptation set in the MPD and its own rendition in the SUBTITLES group of the HLS playlist
+ * @export
+ * @interface SubtitleTrack
+ */
+export interface SubtitleTrack {
+    /**
+     * 
+     * @type {SubtitleFormat}
+     * @memberof SubtitleTrack
+     */
+    format: SubtitleFormat;
+    /**
+     * 
+     * @type {SubtitleSource}
+     * @memberof SubtitleTrack
+     */
+    kind: SubtitleSource;
+    /**
+     * ISO-639 language code
+     * @type {string}
+     * @memberof SubtitleTrack
+     */
+    language: string;
+    /**
+     * 
+     * @type {StreamSpecifier}
+     * @memberof SubtitleTrack
+     */
+    source: StreamSpecifier;
+}
+
+
+
+/**
+ * Check if a given object implements the SubtitleTrack interface.
+ */
+export function instanceOfSubtitleTrack(value: object): value is SubtitleTrack {
+    if (!('format' in value) || value['format'] === undefined) return false;
+    if (!('kind' in value) || value['kind'] === undefined) return false;
+    if (!('language' in value) || value['language'] === undefined) return false;
+    if (!('source' in value) || value['source'] === undefined) return false;
+    return true;
+}
+
+export function SubtitleTrackFromJSON(json: any): SubtitleTrack {
+    return SubtitleTrackFromJSONTyped(json, false);
+}
+
+export function SubtitleTrackFromJSONTyped(json: any, ignoreDiscriminator: boolean): SubtitleTrack {
+    if (json == null) {
+        return json;
+    }
+    return {
+        
+        'format': SubtitleFormatFromJSON(json['format']),
+        'kind': SubtitleSourceFromJSON(json['kind']),
+        'language': json['language'],
+        'source': StreamSpecifierFromJSON(json['source']),
+    };
+}
+
+  export function SubtitleTrackToJSON(json: any): SubtitleTrack {
+      return SubtitleTrackToJSONTyped(json, false);
+  }
+
+  export function SubtitleTrackToJSONTyped(value?: SubtitleTrack | null, ignoreDiscriminator: boolean = false): any {
+    if (value == null) {
+        return value;
+    }
+
+    return {
+        
+        'format': SubtitleFormatToJSON(value['format']),
+        'kind': SubtitleSourceToJSON(value['kind']),
+        'language': value['language'],
+        'source': StreamSpecifierToJSON(value['source']),
+    };
+}
+
@@ -0,0 +1,102 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * live-cmaf-transcoder
+ * API for the Live CMAF Transcoder
+ *
+ * The version of the OpenAPI document: 0.1.57
+ * 
+ *
+ * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
+ * https://openapi-generator.tech
+ * Do not edit the class manually.
+ */
+
+import { mapValues } from '../runtime';
+/**
+ * Trick-play thumbnails, written next to the segments as JPEG tiles of `columns` x `rows` thumbnails and signalled in the MPD and the HLS playlist
+ * @export
+ * @interface Thumbnails
+ */
+export interface Thumbnails {
+    /**
+     * Thumbnails per row of a tile, from 1 to 20
+     * @type {number}
+     * @memberof Thumbnails
+     */
+    columns: number;
+    /**
+     * Height of a thumbnail, in pixels, even
+     * @type {number}
+     * @memberof Thumbnails
+     */
+    height: number;
+    /**
+     * Interval between two thumbnails, in ms
+     * @type {number}
+     * @memberof Thumbnails
+     */
+    intervalMs: number;
+    /**
+     * Thumbnails per column of a tile, from 1 to 20
+     * @type {number}
+     * @memberof Thumbnails
+     */
+    rows: number;
+    /**
+     * Width of a thumbnail, in pixels, even
+     * @type {number}
+     * @memberof Thumbnails
+     */
+    width: number;
+}
+
+/**
+ * Check if a given object implements the Thumbnails interface.
+ */
+export function instanceOfThumbnails(value: object): value is Thumbnails {
+    if (!('columns' in value) || value['columns'] === undefined) return false;
+    if (!('height' in value) || value['height'] === undefined) return false;
+    if (!('intervalMs' in value) || value['intervalMs'] === undefined) return false;
+    if (!('rows' in value) || value['rows'] === undefined) return false;
+    if (!('width' in value) || value['width'] === undefined) return false;
+    return true;
+}
+
+export function ThumbnailsFromJSON(json: any): Thumbnails {
+    return ThumbnailsFromJSONTyped(json, false);
+}
+
+export function ThumbnailsFromJSONTyped(json: any, ignoreDiscriminator: boolean): Thumbnails {
+    if (json == null) {
+        return json;
+    }
+    return {
+        
+        'columns': json['columns'],
+        'height': json['height'],
+        'intervalMs': json['interval_ms'],
+        'rows': json['rows'],
+        'width': json['width'],
+    };
+}
+
+  export function ThumbnailsToJSON(json: any): Thumbnails {
+      return ThumbnailsToJSONTyped(json, false);
+  }
+
+  export function ThumbnailsToJSONTyped(value?: Thumbnails | null, ignoreDiscriminator: boolean = false): any {
+    if (value == null) {
+        return value;
+    }
+
+    return {
+        
+        'columns': value['columns'],
+        'height': value['height'],
+        'interval_ms': value['intervalMs'],
+        'rows': value['rows'],
+        'width': value['width'],
+    };
+}
+
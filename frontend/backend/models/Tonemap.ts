@@ -0,0 +1,55 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * live-cmaf-transcoder
+ * API for the Live CMAF Transcoder
+ *
+ * The version of the OpenAPI document: 0.1.57
+ * 
+ *
+ * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
+ * https://openapi-generator.tech
+ * Do not edit the class manually.
+ */
+
+
+/**
+ * Tone-mapping of HDR frames to BT.709 SDR
+ * @export
+ */
+export const Tonemap = {
+    Hable: 'Hable',
+    Mobius: 'Mobius',
+    Reinhard: 'Reinhard',
+    Clip: 'Clip'
+} as const;
+export type Tonemap = typeof Tonemap[keyof typeof Tonemap];
+
+
+export function instanceOfTonemap(value: any): boolean {
+    for (const key in Tonemap) {
+        if (Object.prototype.hasOwnProperty.call(Tonemap, key)) {
+            if (Tonemap[key as keyof typeof Tonemap] === value) {
+                return true;
+            }
+        }
+    }
+    return false;
+}
+
+export function TonemapFromJSON(json: any): Tonemap {
+    return TonemapFromJSONTyped(json, false);
+}
+
+export function TonemapFromJSONTyped(json: any, ignoreDiscriminator: boolean): Tonemap {
+    return json as Tonemap;
+}
+
+export function TonemapToJSON(value?: Tonemap | null): any {
+    return value as any;
+}
+
+export function TonemapToJSONTyped(value: any, ignoreDiscriminator: boolean): Tonemap {
+    return value as Tonemap;
+}
+
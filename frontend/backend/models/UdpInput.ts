@@ -0,0 +1,119 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * live-cmaf-transcoder
+ * API for the Live CMAF Transcoder
+ *
+ * The version of the OpenAPI document: 0.1.57
+ * 
+ *
+ * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
+ * https://openapi-generator.tech
+ * Do not edit the class manually.
+ */
+
+import { mapValues } from '../runtime';
+import type { UdpProtocol } from './UdpProtocol';
+import {
+    UdpProtocolFromJSON,
+    UdpProtocolFromJSONTyped,
+    UdpProtocolToJSON,
+    UdpProtocolToJSONTyped,
+} from './UdpProtocol';
+
+/**
+ * 
+ * @export
+ * @interface UdpInput
+ */
+export interface UdpInput {
+    /**
+     * Multicast group or local unicast address
+     * @type {string}
+     * @memberof UdpInput
+     */
+    address: string;
+    /**
+     * Socket receive buffer size in bytes
+     * @type {number}
+     * @memberof UdpInput
+     */
+    bufferSize?: number | null;
+    /**
+     * IP address of the network interface used to join the multicast group
+     * @type {string}
+     * @memberof UdpInput
+     */
+    interface?: string | null;
+    /**
+     * 
+     * @type {number}
+     * @memberof UdpInput
+     */
+    port: number;
+    /**
+     * 
+     * @type {UdpProtocol}
+     * @memberof UdpInput
+     */
+    protocol: UdpProtocol;
+    /**
+     * Source addresses for source-specific multicast
+     * @type {Array<string>}
+     * @memberof UdpInput
+     */
+    sources: Array<string>;
+}
+
+
+
+/**
+ * Check if a given object implements the UdpInput interface.
+ */
+export function instanceOfUdpInput(value: object): value is UdpInput {
+    if (!('address' in value) || value['address'] === undefined) return false;
+    if (!('port' in value) || value['port'] === undefined) return false;
+    if (!('protocol' in value) || value['protocol'] === undefined) return false;
+    if (!('sources' in value) || value['sources'] === undefined) return false;
+    return true;
+}
+
+export function UdpInputFromJSON(json: any): UdpInput {
+    return UdpInputFromJSONTyped(json, false);
+}
+
+export function UdpInputFromJSONTyped(json: any, ignoreDiscriminator: boolean): UdpInput {
+    if (json == null) {
+        return json;
+    }
+    return {
+        
+        'address': json['address'],
+        'bufferSize': json['buffer_size'] == null ? undefined : json['buffer_size'],
+        'interface': json['interface'] == null ? undefined : json['interface'],
+        'port': json['port'],
+        'protocol': UdpProtocolFromJSON(json['protocol']),
+        'sources': json['sources'],
+    };
+}
+
+  export function UdpInputToJSON(json: any): UdpInput {
+      return UdpInputToJSONTyped(json, false);
+  }
+
+  export function UdpInputToJSONTyped(value?: UdpInput | null, ignoreDiscriminator: boolean = false): any {
+    if (value == null) {
+        return value;
+    }
+
+    return {
+        
+        'address': value['address'],
+        'buffer_size': value['bufferSize'],
+        'interface': value['interface'],
+        'port': value['port'],
+        'protocol': UdpProtocolToJSON(value['protocol']),
+        'sources': value['sources'],
+    };
+}
+
@@ -0,0 +1,53 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * live-cmaf-transcoder
+ * API for the Live CMAF Transcoder
+ *
+ * The version of the OpenAPI document: 0.1.57
+ * 
+ *
+ * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
+ * https://openapi-generator.tech
+ * Do not edit the class manually.
+ */
+
+
+/**
+ * 
+ * @export
+ */
+export const UdpProtocol = {
+    Udp: 'Udp',
+    Rtp: 'Rtp'
+} as const;
+export type UdpProtocol = typeof UdpProtocol[keyof typeof UdpProtocol];
+
+
+export function instanceOfUdpProtocol(value: any): boolean {
+    for (const key in UdpProtocol) {
+        if (Object.prototype.hasOwnProperty.call(UdpProtocol, key)) {
+            if (UdpProtocol[key as keyof typeof UdpProtocol] === value) {
+                return true;
+            }
+        }
+    }
+    return false;
+}
+
+export function UdpProtocolFromJSON(json: any): UdpProtocol {
+    return UdpProtocolFromJSONTyped(json, false);
+}
+
+export function UdpProtocolFromJSONTyped(json: any, ignoreDiscriminator: boolean): UdpProtocol {
+    return json as UdpProtocol;
+}
+
+export function UdpProtocolToJSON(value?: UdpProtocol | null): any {
+    return value as any;
+}
+
+export function UdpProtocolToJSONTyped(value: any, ignoreDiscriminator: boolean): UdpProtocol {
+    return value as UdpProtocol;
+}
+
@@ -0,0 +1,84 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * live-cmaf-transcoder
+ * API for the Live CMAF Transcoder
+ *
+ * The version of the OpenAPI document: 0.1.57
+ * 
+ *
+ * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
+ * https://openapi-generator.tech
+ * Do not edit the class manually.
+ */
+
+import { mapValues } from '../runtime';
+/**
+ * URL or path passed as is to ffmpeg, the legacy inputs which have no dedicated type
+ * @export
+ * @interface UrlInput
+ */
+export interface UrlInput {
+    /**
+     * 
+     * @type {boolean}
+     * @memberof UrlInput
+     */
+    loopInput: boolean;
+    /**
+     * Read the input at its native frame rate
+     * @type {boolean}
+     * @memberof UrlInput
+     */
+    realtime: boolean;
+    /**
+     * 
+     * @type {string}
+     * @memberof UrlInput
+     */
+    uri: string;
+}
+
+/**
+ * Check if a given object implements the UrlInput interface.
+ */
+export function instanceOfUrlInput(value: object): value is UrlInput {
+    if (!('loopInput' in value) || value['loopInput'] === undefined) return false;
+    if (!('realtime' in value) || value['realtime'] === undefined) return false;
+    if (!('uri' in value) || value['uri'] === undefined) return false;
+    return true;
+}
+
+export function UrlInputFromJSON(json: any): UrlInput {
+    return UrlInputFromJSONTyped(json, false);
+}
+
+export function UrlInputFromJSONTyped(json: any, ignoreDiscriminator: boolean): UrlInput {
+    if (json == null) {
+        return json;
+    }
+    return {
+        
+        'loopInput': json['loop_input'],
+        'realtime': json['realtime'],
+        'uri': json['uri'],
+    };
+}
+
+  export function UrlInputToJSON(json: any): UrlInput {
+      return UrlInputToJSONTyped(json, false);
+  }
+
+  export function UrlInputToJSONTyped(value?: UrlInput | null, ignoreDiscriminator: boolean = false): any {
+    if (value == null) {
+        return value;
+    }
+
+    return {
+        
+        'loop_input': value['loopInput'],
+        'realtime': value['realtime'],
+        'uri': value['uri'],
+    };
+}
+
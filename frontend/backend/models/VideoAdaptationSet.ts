@@ -4,7 +4,7 @@
  * live-cmaf-transcoder
  * API for the Live CMAF Transcoder
  *
- * The version of the OpenAPI document: 0.1.52
+ * The version of the OpenAPI document: 0.1.57
  * 
  *
  * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
@@ -20,6 +20,20 @@ import {
     VideoEncoderToJSON,
     VideoEncoderToJSONTyped,
 } from './VideoEncoder';
+import type { VideoFilterCrop } from './VideoFilterCrop';
+import {
+    VideoFilterCropFromJSON,
+    VideoFilterCropFromJSONTyped,
+    VideoFilterCropToJSON,
+    VideoFilterCropToJSONTyped,
+} from './VideoFilterCrop';
+import type { Tonemap } from './Tonemap';
+import {
+    TonemapFromJSON,
+    TonemapFromJSONTyped,
+    TonemapToJSON,
+    TonemapToJSONTyped,
+} from './Tonemap';
 import type { VideoRepresentation } from './VideoRepresentation';
 import {
     VideoRepresentationFromJSON,
@@ -27,6 +41,13 @@ import {
     VideoRepresentationToJSON,
     VideoRepresentationToJSONTyped,
 } from './VideoRepresentation';
+import type { ScaleMode } from './ScaleMode';
+import {
+    ScaleModeFromJSON,
+    ScaleModeFromJSONTyped,
+    ScaleModeToJSON,
+    ScaleModeToJSONTyped,
+} from './ScaleMode';
 
 /**
  * 
@@ -46,6 +67,12 @@ export interface VideoAdaptationSet {
      * @memberof VideoAdaptationSet
      */
     aspectRatioNum?: number | null;
+    /**
+     * Crop of the source, before the scaling
+     * @type {VideoFilterCrop}
+     * @memberof VideoAdaptationSet
+     */
+    crop?: VideoFilterCrop | null;
     /**
      * 
      * @type {VideoEncoder}
@@ -70,6 +97,18 @@ export interface VideoAdaptationSet {
      * @memberof VideoAdaptationSet
      */
     representations: Array<VideoRepresentation>;
+    /**
+     * 
+     * @type {ScaleMode}
+     * @memberof VideoAdaptationSet
+     */
+    scaleMode?: ScaleMode;
+    /**
+     * Tone-mapping of the HDR input to BT.709 SDR
+     * @type {Tonemap}
+     * @memberof VideoAdaptationSet
+     */
+    tonemap?: Tonemap | null;
 }
 
 
@@ -97,10 +136,13 @@ export function VideoAdaptationSetFromJSONTyped(json: any, ignoreDiscriminator:
         
         'aspectRatioDen': json['aspect_ratio_den'] == null ? undefined : json['aspect_ratio_den'],
         'aspectRatioNum': json['aspect_ratio_num'] == null ? undefined : json['aspect_ratio_num'],
+        'crop': json['crop'] == null ? undefined : VideoFilterCropFromJSON(json['crop']),
         'encoder': VideoEncoderFromJSON(json['encoder']),
         'framerateDen': json['framerate_den'],
         'framerateNum': json['framerate_num'],
         'representations': ((json['representations'] as Array<any>).map(VideoRepresentationFromJSON)),
+        'scaleMode': json['scale_mode'] == null ? undefined : ScaleModeFromJSON(json['scale_mode']),
+        'tonemap': json['tonemap'] == null ? undefined : TonemapFromJSON(json['tonemap']),
     };
 }
 
@@ -117,10 +159,13 @@ export function VideoAdaptationSetFromJSONTyped(json: any, ignoreDiscriminator:
         
         'aspect_ratio_den': value['aspectRatioDen'],
         'aspect_ratio_num': value['aspectRatioNum'],
+        'crop': VideoFilterCropToJSON(value['crop']),
         'encoder': VideoEncoderToJSON(value['encoder']),
         'framerate_den': value['framerateDen'],
         'framerate_num': value['framerateNum'],
         'representations': ((value['representations'] as Array<any>).map(VideoRepresentationToJSON)),
+        'scale_mode': ScaleModeToJSON(value['scaleMode']),
+        'tonemap': TonemapToJSON(value['tonemap']),
     };
 }
 
@@ -4,7 +4,7 @@
  * live-cmaf-transcoder
  * API for the Live CMAF Transcoder
  *
- * The version of the OpenAPI document: 0.1.52
+ * The version of the OpenAPI document: 0.1.57
  * 
  *
  * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
@@ -19,7 +19,8 @@
  */
 export const VideoEncoder = {
     H264: 'H264',
-    Hevc: 'HEVC'
+    Hevc: 'HEVC',
+    Av1: 'AV1'
 } as const;
 export type VideoEncoder = typeof VideoEncoder[keyof typeof VideoEncoder];
 
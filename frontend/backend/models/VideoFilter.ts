@@ -0,0 +1,104 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * live-cmaf-transcoder
+ * API for the Live CMAF Transcoder
+ *
+ * The version of the OpenAPI document: 0.1.57
+ * 
+ *
+ * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
+ * https://openapi-generator.tech
+ * Do not edit the class manually.
+ */
+
+import type { VideoFilterOneOf } from './VideoFilterOneOf';
+import {
+    instanceOfVideoFilterOneOf,
+    VideoFilterOneOfFromJSON,
+    VideoFilterOneOfFromJSONTyped,
+    VideoFilterOneOfToJSON,
+} from './VideoFilterOneOf';
+import type { VideoFilterOneOf1 } from './VideoFilterOneOf1';
+import {
+    instanceOfVideoFilterOneOf1,
+    VideoFilterOneOf1FromJSON,
+    VideoFilterOneOf1FromJSONTyped,
+    VideoFilterOneOf1ToJSON,
+} from './VideoFilterOneOf1';
+import type { VideoFilterOneOf2 } from './VideoFilterOneOf2';
+import {
+    instanceOfVideoFilterOneOf2,
+    VideoFilterOneOf2FromJSON,
+    VideoFilterOneOf2FromJSONTyped,
+    VideoFilterOneOf2ToJSON,
+} from './VideoFilterOneOf2';
+import type { VideoFilterOneOf3 } from './VideoFilterOneOf3';
+import {
+    instanceOfVideoFilterOneOf3,
+    VideoFilterOneOf3FromJSON,
+    VideoFilterOneOf3FromJSONTyped,
+    VideoFilterOneOf3ToJSON,
+} from './VideoFilterOneOf3';
+
+/**
+ * @type VideoFilter
+ * Filter applied to the source before the split into representations
+ * @export
+ */
+export type VideoFilter = VideoFilterOneOf | VideoFilterOneOf1 | VideoFilterOneOf2 | VideoFilterOneOf3;
+
+export function VideoFilterFromJSON(json: any): VideoFilter {
+    return VideoFilterFromJSONTyped(json, false);
+}
+
+export function VideoFilterFromJSONTyped(json: any, ignoreDiscriminator: boolean): VideoFilter {
+    if (json == null) {
+        return json;
+    }
+    if (typeof json !== 'object') {
+        return json;
+    }
+    if ('Deinterlace' in json) {
+        return VideoFilterOneOfFromJSONTyped(json, true);
+    }
+    if ('Denoise' in json) {
+        return VideoFilterOneOf1FromJSONTyped(json, true);
+    }
+    if ('Sharpen' in json) {
+        return VideoFilterOneOf2FromJSONTyped(json, true);
+    }
+    if ('FieldOrder' in json) {
+        return VideoFilterOneOf3FromJSONTyped(json, true);
+    }
+
+    return {} as any;
+}
+
+export function VideoFilterToJSON(json: any): any {
+    return VideoFilterToJSONTyped(json, false);
+}
+
+export function VideoFilterToJSONTyped(value?: VideoFilter | null, ignoreDiscriminator: boolean = false): any {
+    if (value == null) {
+        return value;
+    }
+    if (typeof value !== 'object') {
+        return value;
+    }
+    if (instanceOfVideoFilterOneOf(value)) {
+        return VideoFilterOneOfToJSON(value as VideoFilterOneOf);
+    }
+    if (instanceOfVideoFilterOneOf1(value)) {
+        return VideoFilterOneOf1ToJSON(value as VideoFilterOneOf1);
+    }
+    if (instanceOfVideoFilterOneOf2(value)) {
+        return VideoFilterOneOf2ToJSON(value as VideoFilterOneOf2);
+    }
+    if (instanceOfVideoFilterOneOf3(value)) {
+        return VideoFilterOneOf3ToJSON(value as VideoFilterOneOf3);
+    }
+
+    return {};
+}
+
@@ -0,0 +1,93 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * live-cmaf-transcoder
+ * API for the Live CMAF Transcoder
+ *
+ * The version of the OpenAPI document: 0.1.57
+ * 
+ *
+ * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
+ * https://openapi-generator.tech
+ * Do not edit the class manually.
+ */
+
+import { mapValues } from '../runtime';
+/**
+ * Pixels removed from the edges of the source, ex: burned-in black bars
+ * @export
+ * @interface VideoFilterCrop
+ */
+export interface VideoFilterCrop {
+    /**
+     * 
+     * @type {number}
+     * @memberof VideoFilterCrop
+     */
+    bottom: number;
+    /**
+     * 
+     * @type {number}
+     * @memberof VideoFilterCrop
+     */
+    left: number;
+    /**
+     * 
+     * @type {number}
+     * @memberof VideoFilterCrop
+     */
+    right: number;
+    /**
+     * 
+     * @type {number}
+     * @memberof VideoFilterCrop
+     */
+    top: number;
+}
+
+/**
+ * Check if a given object implements the VideoFilterCrop interface.
+ */
+export function instanceOfVideoFilterCrop(value: object): value is VideoFilterCrop {
+    if (!('bottom' in value) || value['bottom'] === undefined) return false;
+    if (!('left' in value) || value['left'] === undefined) return false;
+    if (!('right' in value) || value['right'] === undefined) return false;
+    if (!('top' in value) || value['top'] === undefined) return false;
+    return true;
+}
+
+export function VideoFilterCropFromJSON(json: any): VideoFilterCrop {
+    return VideoFilterCropFromJSONTyped(json, false);
+}
+
+export function VideoFilterCropFromJSONTyped(json: any, ignoreDiscriminator: boolean): VideoFilterCrop {
+    if (json == null) {
+        return json;
+    }
+    return {
+        
+        'bottom': json['bottom'],
+        'left': json['left'],
+        'right': json['right'],
+        'top': json['top'],
+    };
+}
+
+  export function VideoFilterCropToJSON(json: any): VideoFilterCrop {
+      return VideoFilterCropToJSONTyped(json, false);
+  }
+
+  export function VideoFilterCropToJSONTyped(value?: VideoFilterCrop | null, ignoreDiscriminator: boolean = false): any {
+    if (value == null) {
+        return value;
+    }
+
+    return {
+        
+        'bottom': value['bottom'],
+        'left': value['left'],
+        'right': value['right'],
+        'top': value['top'],
+    };
+}
+
@@ -4,7 +4,7 @@
  * live-cmaf-transcoder
  * API for the Live CMAF Transcoder
  *
- * The version of the OpenAPI document: 0.1.52
+ * The version of the OpenAPI document: 0.1.57
  * 
  *
  * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
@@ -19,6 +19,12 @@ import { mapValues } from '../runtime';
  * @interface VideoFilterDeinterlace
  */
 export interface VideoFilterDeinterlace {
+    /**
+     * Only deinterlace the content detected as interlaced
+     * @type {boolean}
+     * @memberof VideoFilterDeinterlace
+     */
+    auto?: boolean;
     /**
      * 
      * @type {boolean}
@@ -45,6 +51,7 @@ export function VideoFilterDeinterlaceFromJSONTyped(json: any, ignoreDiscriminat
     }
     return {
         
+        'auto': json['auto'] == null ? undefined : json['auto'],
         'enable': json['enable'],
     };
 }
@@ -60,6 +67,7 @@ export function VideoFilterDeinterlaceFromJSONTyped(json: any, ignoreDiscriminat
 
     return {
         
+        'auto': value['auto'],
         'enable': value['enable'],
     };
 }
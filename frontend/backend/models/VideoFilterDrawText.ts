@@ -4,7 +4,7 @@
  * live-cmaf-transcoder
  * API for the Live CMAF Transcoder
  *
- * The version of the OpenAPI document: 0.1.52
+ * The version of the OpenAPI document: 0.1.57
  * 
  *
  * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
@@ -13,6 +13,13 @@
  */
 
 import { mapValues } from '../runtime';
+import type { DrawTextShadow } from './DrawTextShadow';
+import {
+    DrawTextShadowFromJSON,
+    DrawTextShadowFromJSONTyped,
+    DrawTextShadowToJSON,
+    DrawTextShadowToJSONTyped,
+} from './DrawTextShadow';
 import type { TextPosition } from './TextPosition';
 import {
     TextPositionFromJSON,
@@ -20,13 +27,26 @@ import {
     TextPositionToJSON,
     TextPositionToJSONTyped,
 } from './TextPosition';
+import type { DrawTextBox } from './DrawTextBox';
+import {
+    DrawTextBoxFromJSON,
+    DrawTextBoxFromJSONTyped,
+    DrawTextBoxToJSON,
+    DrawTextBoxToJSONTyped,
+} from './DrawTextBox';
 
 /**
- * 
+ * The text accepts the variables: {localtime} {utctime}: wall clock, HH:MM:SS {timecode}: PTS of the frame, HH:MM:SS.mmm {frame}: frame counter {config_name} {server_name}
  * @export
  * @interface VideoFilterDrawText
  */
 export interface VideoFilterDrawText {
+    /**
+     * 
+     * @type {DrawTextBox}
+     * @memberof VideoFilterDrawText
+     */
+    background?: DrawTextBox | null;
     /**
      * 
      * @type {string}
@@ -39,6 +59,12 @@ export interface VideoFilterDrawText {
      * @memberof VideoFilterDrawText
      */
     enabled: boolean;
+    /**
+     * Path of a TrueType font, the default font of fontconfig if not set
+     * @type {string}
+     * @memberof VideoFilterDrawText
+     */
+    fontfile?: string | null;
     /**
      * 
      * @type {number}
@@ -51,6 +77,12 @@ export interface VideoFilterDrawText {
      * @memberof VideoFilterDrawText
      */
     position: TextPosition;
+    /**
+     * 
+     * @type {DrawTextShadow}
+     * @memberof VideoFilterDrawText
+     */
+    shadow?: DrawTextShadow | null;
     /**
      * 
      * @type {string}
@@ -83,10 +115,13 @@ export function VideoFilterDrawTextFromJSONTyped(json: any, ignoreDiscriminator:
     }
     return {
         
+        'background': json['background'] == null ? undefined : DrawTextBoxFromJSON(json['background']),
         'color': json['color'],
         'enabled': json['enabled'],
+        'fontfile': json['fontfile'] == null ? undefined : json['fontfile'],
         'fontsize': json['fontsize'],
         'position': TextPositionFromJSON(json['position']),
+        'shadow': json['shadow'] == null ? undefined : DrawTextShadowFromJSON(json['shadow']),
         'text': json['text'],
     };
 }
@@ -102,10 +137,13 @@ export function VideoFilterDrawTextFromJSONTyped(json: any, ignoreDiscriminator:
 
     return {
         
+        'background': DrawTextBoxToJSON(value['background']),
         'color': value['color'],
         'enabled': value['enabled'],
+        'fontfile': value['fontfile'],
         'fontsize': value['fontsize'],
         'position': TextPositionToJSON(value['position']),
+        'shadow': DrawTextShadowToJSON(value['shadow']),
         'text': value['text'],
     };
 }
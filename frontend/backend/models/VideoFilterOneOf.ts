@@ -0,0 +1,74 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * live-cmaf-transcoder
+ * API for the Live CMAF Transcoder
+ *
+ * The version of the OpenAPI document: 0.1.57
+ * 
+ *
+ * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
+ * https://openapi-generator.tech
+ * Do not edit the class manually.
+ */
+
+import { mapValues } from '../runtime';
+import type { Deinterlace } from './Deinterlace';
+import {
+    DeinterlaceFromJSON,
+    DeinterlaceFromJSONTyped,
+    DeinterlaceToJSON,
+    DeinterlaceToJSONTyped,
+} from './Deinterlace';
+
+/**
+ * 
+ * @export
+ * @interface VideoFilterOneOf
+ */
+export interface VideoFilterOneOf {
+    /**
+     * 
+     * @type {Deinterlace}
+     * @memberof VideoFilterOneOf
+     */
+    deinterlace: Deinterlace;
+}
+
+/**
+ * Check if a given object implements the VideoFilterOneOf interface.
+ */
+export function instanceOfVideoFilterOneOf(value: object): value is VideoFilterOneOf {
+    if (!('deinterlace' in value) || value['deinterlace'] === undefined) return false;
+    return true;
+}
+
+export function VideoFilterOneOfFromJSON(json: any): VideoFilterOneOf {
+    return VideoFilterOneOfFromJSONTyped(json, false);
+}
+
+export function VideoFilterOneOfFromJSONTyped(json: any, ignoreDiscriminator: boolean): VideoFilterOneOf {
+    if (json == null) {
+        return json;
+    }
+    return {
+        
+        'deinterlace': DeinterlaceFromJSON(json['Deinterlace']),
+    };
+}
+
+  export function VideoFilterOneOfToJSON(json: any): VideoFilterOneOf {
+      return VideoFilterOneOfToJSONTyped(json, false);
+  }
+
+  export function VideoFilterOneOfToJSONTyped(value?: VideoFilterOneOf | null, ignoreDiscriminator: boolean = false): any {
+    if (value == null) {
+        return value;
+    }
+
+    return {
+        
+        'Deinterlace': DeinterlaceToJSON(value['deinterlace']),
+    };
+}
+
@@ -0,0 +1,74 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * live-cmaf-transcoder
+ * API for the Live CMAF Transcoder
+ *
+ * The version of the OpenAPI document: 0.1.57
+ * 
+ *
+ * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
+ * https://openapi-generator.tech
+ * Do not edit the class manually.
+ */
+
+import { mapValues } from '../runtime';
+import type { Denoise } from './Denoise';
+import {
+    DenoiseFromJSON,
+    DenoiseFromJSONTyped,
+    DenoiseToJSON,
+    DenoiseToJSONTyped,
+} from './Denoise';
+
+/**
+ * 
+ * @export
+ * @interface VideoFilterOneOf1
+ */
+export interface VideoFilterOneOf1 {
+    /**
+     * 
+     * @type {Denoise}
+     * @memberof VideoFilterOneOf1
+     */
+    denoise: Denoise;
+}
+
+/**
+ * Check if a given object implements the VideoFilterOneOf1 interface.
+ */
+export function instanceOfVideoFilterOneOf1(value: object): value is VideoFilterOneOf1 {
+    if (!('denoise' in value) || value['denoise'] === undefined) return false;
+    return true;
+}
+
+export function VideoFilterOneOf1FromJSON(json: any): VideoFilterOneOf1 {
+    return VideoFilterOneOf1FromJSONTyped(json, false);
+}
+
+export function VideoFilterOneOf1FromJSONTyped(json: any, ignoreDiscriminator: boolean): VideoFilterOneOf1 {
+    if (json == null) {
+        return json;
+    }
+    return {
+        
+        'denoise': DenoiseFromJSON(json['Denoise']),
+    };
+}
+
+  export function VideoFilterOneOf1ToJSON(json: any): VideoFilterOneOf1 {
+      return VideoFilterOneOf1ToJSONTyped(json, false);
+  }
+
+  export function VideoFilterOneOf1ToJSONTyped(value?: VideoFilterOneOf1 | null, ignoreDiscriminator: boolean = false): any {
+    if (value == null) {
+        return value;
+    }
+
+    return {
+        
+        'Denoise': DenoiseToJSON(value['denoise']),
+    };
+}
+
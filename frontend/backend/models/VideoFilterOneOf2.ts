@@ -0,0 +1,74 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * live-cmaf-transcoder
+ * API for the Live CMAF Transcoder
+ *
+ * The version of the OpenAPI document: 0.1.57
+ * 
+ *
+ * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
+ * https://openapi-generator.tech
+ * Do not edit the class manually.
+ */
+
+import { mapValues } from '../runtime';
+import type { Sharpen } from './Sharpen';
+import {
+    SharpenFromJSON,
+    SharpenFromJSONTyped,
+    SharpenToJSON,
+    SharpenToJSONTyped,
+} from './Sharpen';
+
+/**
+ * 
+ * @export
+ * @interface VideoFilterOneOf2
+ */
+export interface VideoFilterOneOf2 {
+    /**
+     * 
+     * @type {Sharpen}
+     * @memberof VideoFilterOneOf2
+     */
+    sharpen: Sharpen;
+}
+
+/**
+ * Check if a given object implements the VideoFilterOneOf2 interface.
+ */
+export function instanceOfVideoFilterOneOf2(value: object): value is VideoFilterOneOf2 {
+    if (!('sharpen' in value) || value['sharpen'] === undefined) return false;
+    return true;
+}
+
+export function VideoFilterOneOf2FromJSON(json: any): VideoFilterOneOf2 {
+    return VideoFilterOneOf2FromJSONTyped(json, false);
+}
+
+export function VideoFilterOneOf2FromJSONTyped(json: any, ignoreDiscriminator: boolean): VideoFilterOneOf2 {
+    if (json == null) {
+        return json;
+    }
+    return {
+        
+        'sharpen': SharpenFromJSON(json['Sharpen']),
+    };
+}
+
+  export function VideoFilterOneOf2ToJSON(json: any): VideoFilterOneOf2 {
+      return VideoFilterOneOf2ToJSONTyped(json, false);
+  }
+
+  export function VideoFilterOneOf2ToJSONTyped(value?: VideoFilterOneOf2 | null, ignoreDiscriminator: boolean = false): any {
+    if (value == null) {
+        return value;
+    }
+
+    return {
+        
+        'Sharpen': SharpenToJSON(value['sharpen']),
+    };
+}
+
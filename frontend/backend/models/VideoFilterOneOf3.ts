@@ -0,0 +1,76 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * live-cmaf-transcoder
+ * API for the Live CMAF Transcoder
+ *
+ * The version of the OpenAPI document: 0.1.57
+ * 
+ *
+ * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
+ * https://openapi-generator.tech
+ * Do not edit the class manually.
+ */
+
+import { mapValues } from '../runtime';
+import type { FieldParity } from './FieldParity';
+import {
+    FieldParityFromJSON,
+    FieldParityFromJSONTyped,
+    FieldParityToJSON,
+    FieldParityToJSONTyped,
+} from './FieldParity';
+
+/**
+ * Fix the field order of a source signalling a wrong one, before the deinterlacing
+ * @export
+ * @interface VideoFilterOneOf3
+ */
+export interface VideoFilterOneOf3 {
+    /**
+     * Fix the field order of a source signalling a wrong one, before the deinterlacing
+     * @type {FieldParity}
+     * @memberof VideoFilterOneOf3
+     */
+    fieldOrder: FieldParity;
+}
+
+
+
+/**
+ * Check if a given object implements the VideoFilterOneOf3 interface.
+ */
+export function instanceOfVideoFilterOneOf3(value: object): value is VideoFilterOneOf3 {
+    if (!('fieldOrder' in value) || value['fieldOrder'] === undefined) return false;
+    return true;
+}
+
+export function VideoFilterOneOf3FromJSON(json: any): VideoFilterOneOf3 {
+    return VideoFilterOneOf3FromJSONTyped(json, false);
+}
+
+export function VideoFilterOneOf3FromJSONTyped(json: any, ignoreDiscriminator: boolean): VideoFilterOneOf3 {
+    if (json == null) {
+        return json;
+    }
+    return {
+        
+        'fieldOrder': FieldParityFromJSON(json['FieldOrder']),
+    };
+}
+
+  export function VideoFilterOneOf3ToJSON(json: any): VideoFilterOneOf3 {
+      return VideoFilterOneOf3ToJSONTyped(json, false);
+  }
+
+  export function VideoFilterOneOf3ToJSONTyped(value?: VideoFilterOneOf3 | null, ignoreDiscriminator: boolean = false): any {
+    if (value == null) {
+        return value;
+    }
+
+    return {
+        
+        'FieldOrder': FieldParityToJSON(value['fieldOrder']),
+    };
+}
+
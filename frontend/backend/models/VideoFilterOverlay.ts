@@ -0,0 +1,121 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * live-cmaf-transcoder
+ * API for the Live CMAF Transcoder
+ *
+ * The version of the OpenAPI document: 0.1.57
+ * 
+ *
+ * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
+ * https://openapi-generator.tech
+ * Do not edit the class manually.
+ */
+
+import { mapValues } from '../runtime';
+import type { TextPosition } from './TextPosition';
+import {
+    TextPositionFromJSON,
+    TextPositionFromJSONTyped,
+    TextPositionToJSON,
+    TextPositionToJSONTyped,
+} from './TextPosition';
+
+/**
+ * Image overlay, ex: a station logo
+ * @export
+ * @interface VideoFilterOverlay
+ */
+export interface VideoFilterOverlay {
+    /**
+     * 
+     * @type {boolean}
+     * @memberof VideoFilterOverlay
+     */
+    enabled: boolean;
+    /**
+     * Height of the image relative to the height of the representation, from 0 to 1
+     * @type {number}
+     * @memberof VideoFilterOverlay
+     */
+    heightRatio: number;
+    /**
+     * Path or URL of the image, PNG with alpha
+     * @type {string}
+     * @memberof VideoFilterOverlay
+     */
+    image: string;
+    /**
+     * Margin to the edges, in pixels
+     * @type {number}
+     * @memberof VideoFilterOverlay
+     */
+    margin: number;
+    /**
+     * From 0 (transparent) to 1 (opaque)
+     * @type {number}
+     * @memberof VideoFilterOverlay
+     */
+    opacity: number;
+    /**
+     * 
+     * @type {TextPosition}
+     * @memberof VideoFilterOverlay
+     */
+    position: TextPosition;
+}
+
+
+
+/**
+ * Check if a given object implements the VideoFilterOverlay interface.
+ */
+export function instanceOfVideoFilterOverlay(value: object): value is VideoFilterOverlay {
+    if (!('enabled' in value) || value['enabled'] === undefined) return false;
+    if (!('heightRatio' in value) || value['heightRatio'] === undefined) return false;
+    if (!('image' in value) || value['image'] === undefined) return false;
+    if (!('margin' in value) || value['margin'] === undefined) return false;
+    if (!('opacity' in value) || value['opacity'] === undefined) return false;
+    if (!('position' in value) || value['position'] === undefined) return false;
+    return true;
+}
+
+export function VideoFilterOverlayFromJSON(json: any): VideoFilterOverlay {
+    return VideoFilterOverlayFromJSONTyped(json, false);
+}
+
+export function VideoFilterOverlayFromJSONTyped(json: any, ignoreDiscriminator: boolean): VideoFilterOverlay {
+    if (json == null) {
+        return json;
+    }
+    return {
+        
+        'enabled': json['enabled'],
+        'heightRatio': json['height_ratio'],
+        'image': json['image'],
+        'margin': json['margin'],
+        'opacity': json['opacity'],
+        'position': TextPositionFromJSON(json['position']),
+    };
+}
+
+  export function VideoFilterOverlayToJSON(json: any): VideoFilterOverlay {
+      return VideoFilterOverlayToJSONTyped(json, false);
+  }
+
+  export function VideoFilterOverlayToJSONTyped(value?: VideoFilterOverlay | null, ignoreDiscriminator: boolean = false): any {
+    if (value == null) {
+        return value;
+    }
+
+    return {
+        
+        'enabled': value['enabled'],
+        'height_ratio': value['heightRatio'],
+        'image': value['image'],
+        'margin': value['margin'],
+        'opacity': value['opacity'],
+        'position': TextPositionToJSON(value['position']),
+    };
+}
+
@@ -4,7 +4,7 @@
  * live-cmaf-transcoder
  * API for the Live CMAF Transcoder
  *
- * The version of the OpenAPI document: 0.1.52
+ * The version of the OpenAPI document: 0.1.57
  * 
  *
  * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
@@ -20,6 +20,27 @@ import {
     VideoFilterDrawTextToJSON,
     VideoFilterDrawTextToJSONTyped,
 } from './VideoFilterDrawText';
+import type { RateControl } from './RateControl';
+import {
+    RateControlFromJSON,
+    RateControlFromJSONTyped,
+    RateControlToJSON,
+    RateControlToJSONTyped,
+} from './RateControl';
+import type { EncoderTuning } from './EncoderTuning';
+import {
+    EncoderTuningFromJSON,
+    EncoderTuningFromJSONTyped,
+    EncoderTuningToJSON,
+    EncoderTuningToJSONTyped,
+} from './EncoderTuning';
+import type { VideoFilterOverlay } from './VideoFilterOverlay';
+import {
+    VideoFilterOverlayFromJSON,
+    VideoFilterOverlayFromJSONTyped,
+    VideoFilterOverlayToJSON,
+    VideoFilterOverlayToJSONTyped,
+} from './VideoFilterOverlay';
 import type { Preset } from './Preset';
 import {
     PresetFromJSON,
@@ -27,6 +48,13 @@ import {
     PresetToJSON,
     PresetToJSONTyped,
 } from './Preset';
+import type { Av1Options } from './Av1Options';
+import {
+    Av1OptionsFromJSON,
+    Av1OptionsFromJSONTyped,
+    Av1OptionsToJSON,
+    Av1OptionsToJSONTyped,
+} from './Av1Options';
 import type { Profile } from './Profile';
 import {
     ProfileFromJSON,
@@ -41,6 +69,12 @@ import {
  * @interface VideoRepresentation
  */
 export interface VideoRepresentation {
+    /**
+     * 
+     * @type {Av1Options}
+     * @memberof VideoRepresentation
+     */
+    av1?: Av1Options;
     /**
      * 
      * @type {number}
@@ -53,6 +87,18 @@ export interface VideoRepresentation {
      * @memberof VideoRepresentation
      */
     bufSize?: number | null;
+    /**
+     * 
+     * @type {number}
+     * @memberof VideoRepresentation
+     */
+    framerateDen?: number | null;
+    /**
+     * Frame rate of the representation, an integer decimation of the frame rate of the adaptation set. The frame rate of the adaptation set if not set.
+     * @type {number}
+     * @memberof VideoRepresentation
+     */
+    framerateNum?: number | null;
     /**
      * 
      * @type {number}
@@ -65,6 +111,12 @@ export interface VideoRepresentation {
      * @memberof VideoRepresentation
      */
     maxBitrate: number;
+    /**
+     * 
+     * @type {VideoFilterOverlay}
+     * @memberof VideoRepresentation
+     */
+    overlay?: VideoFilterOverlay;
     /**
      * 
      * @type {Preset}
@@ -72,17 +124,29 @@ export interface VideoRepresentation {
      */
     preset: Preset;
     /**
-     * 
+     * Ignored by AV1, always encoded with the main profile
      * @type {Profile}
      * @memberof VideoRepresentation
      */
     profile: Profile;
+    /**
+     * 
+     * @type {RateControl}
+     * @memberof VideoRepresentation
+     */
+    rateControl?: RateControl;
     /**
      * 
      * @type {VideoFilterDrawText}
      * @memberof VideoRepresentation
      */
     text: VideoFilterDrawText;
+    /**
+     * 
+     * @type {EncoderTuning}
+     * @memberof VideoRepresentation
+     */
+    tuning?: EncoderTuning;
     /**
      * 
      * @type {number}
@@ -117,13 +181,19 @@ export function VideoRepresentationFromJSONTyped(json: any, ignoreDiscriminator:
     }
     return {
         
+        'av1': json['av1'] == null ? undefined : Av1OptionsFromJSON(json['av1']),
         'bitrate': json['bitrate'],
         'bufSize': json['buf_size'] == null ? undefined : json['buf_size'],
+        'framerateDen': json['framerate_den'] == null ? undefined : json['framerate_den'],
+        'framerateNum': json['framerate_num'] == null ? undefined : json['framerate_num'],
         'height': json['height'],
         'maxBitrate': json['max_bitrate'],
+        'overlay': json['overlay'] == null ? undefined : VideoFilterOverlayFromJSON(json['overlay']),
         'preset': PresetFromJSON(json['preset']),
         'profile': ProfileFromJSON(json['profile']),
+        'rateControl': json['rate_control'] == null ? undefined : RateControlFromJSON(json['rate_control']),
         'text': VideoFilterDrawTextFromJSON(json['text']),
+        'tuning': json['tuning'] == null ? undefined : EncoderTuningFromJSON(json['tuning']),
         'width': json['width'],
     };
 }
@@ -139,13 +209,19 @@ export function VideoRepresentationFromJSONTyped(json: any, ignoreDiscriminator:
 
     return {
         
+        'av1': Av1OptionsToJSON(value['av1']),
         'bitrate': value['bitrate'],
         'buf_size': value['bufSize'],
+        'framerate_den': value['framerateDen'],
+        'framerate_num': value['framerateNum'],
         'height': value['height'],
         'max_bitrate': value['maxBitrate'],
+        'overlay': VideoFilterOverlayToJSON(value['overlay']),
         'preset': PresetToJSON(value['preset']),
         'profile': ProfileToJSON(value['profile']),
+        'rate_control': RateControlToJSON(value['rateControl']),
         'text': VideoFilterDrawTextToJSON(value['text']),
+        'tuning': EncoderTuningToJSON(value['tuning']),
         'width': value['width'],
     };
 }
@@ -3,37 +3,131 @@
 export * from './Acceleration';
 export * from './AudioAdaptationSet';
 export * from './AudioEncoder';
+export * from './AudioFilter';
+export * from './AudioFilterLoudnorm';
+export * from './AudioRepresentation';
 export * from './Audioprofile';
+export * from './Av1Options';
+export * from './CaptionChannel';
+export * from './CaptionStandard';
+export * from './ChannelLayout';
+export * from './ClosedCaptions';
 export * from './Colorspace';
 export * from './CoreState';
+export * from './Deinterlace';
+export * from './DeinterlaceMode';
+export * from './DeinterlaceRate';
+export * from './Denoise';
+export * from './DenoiseOneOf';
+export * from './DenoiseOneOf1';
+export * from './DenoiseOneOf1Nlmeans';
+export * from './DenoiseOneOf2';
+export * from './DenoiseOneOf2Vaapi';
+export * from './DenoiseOneOfHqdn3d';
+export * from './DetectedFieldOrder';
+export * from './DrawTextBox';
+export * from './DrawTextShadow';
+export * from './EncoderTuning';
 export * from './FFConfig';
 export * from './FFStatusValue';
+export * from './FieldParity';
+export * from './FileInput';
 export * from './Gpu';
+export * from './HdrMetadata';
+export * from './HttpPullInput';
 export * from './InConfigID';
 export * from './InFFCreate';
+export * from './InFFInsertMarker';
+export * from './InFFProbe';
 export * from './InFFUpdate';
 export * from './InServerID';
 export * from './InSetState';
+export * from './Input';
+export * from './InputFailover';
+export * from './InputOneOf';
+export * from './InputOneOf1';
+export * from './InputOneOf2';
+export * from './InputOneOf3';
+export * from './InputOneOf4';
+export * from './InputOneOf5';
+export * from './InputOneOf6';
+export * from './InputSwitchReason';
 export * from './Log';
 export * from './LogLevel';
+export * from './LoudnessValue';
 export * from './MPDType';
+export * from './MarkerType';
+export * from './NvencPreset';
+export * from './NvencTune';
+export * from './OutFFInsertMarker';
+export * from './OutFFProbe';
 export * from './OutGetAllFFConfig';
 export * from './OutGetAllServerStatus';
 export * from './OutGetAllServers';
 export * from './OutGetFFStatus';
 export * from './OutGetLogs';
 export * from './Preset';
+export * from './ProbeProgram';
+export * from './ProbeStream';
 export * from './Profile';
+export * from './PullFormat';
+export * from './RateControl';
+export * from './RateControlOneOf2';
+export * from './RateControlOneOf2Crf';
+export * from './RateControlOneOf3';
+export * from './RateControlOneOf3Cqp';
+export * from './RateControlOneOf4';
+export * from './RateControlOneOf4Icq';
+export * from './RateControlOneOf5';
+export * from './RateControlOneOf5Qvbr';
+export * from './RateControlOneOf6';
+export * from './RateControlOneOf6Cq';
+export * from './RistEncryption';
+export * from './RistInput';
+export * from './RistProfile';
 export * from './Role';
+export * from './RtmpInput';
+export * from './RtmpMode';
+export * from './SRTKeyLength';
 export * from './SRTMode';
+export * from './ScaleMode';
+export * from './Scte35';
+export * from './Scte35HlsTags';
 export * from './Server';
 export * from './ServerCapability';
 export * from './ServerStatus';
+export * from './Sharpen';
+export * from './SharpenOneOf';
+export * from './SharpenOneOf1';
+export * from './SharpenOneOf1Vaapi';
+export * from './SharpenOneOfUnsharp';
 export * from './Srt';
 export * from './State';
+export * from './StreamSelection';
+export * from './StreamSpecifier';
+export * from './StreamSpecifierOneOf';
+export * from './StreamSpecifierOneOf1';
+export * from './StreamSpecifierOneOf2';
+export * from './SubtitleFormat';
+export * from './SubtitleSource';
+export * from './SubtitleSourceOneOf';
+export * from './SubtitleSourceOneOfTeletext';
+export * from './SubtitleTrack';
 export * from './TextPosition';
+export * from './Thumbnails';
+export * from './Tonemap';
+export * from './UdpInput';
+export * from './UdpProtocol';
+export * from './UrlInput';
 export * from './VideoAdaptationSet';
 export * from './VideoEncoder';
+export * from './VideoFilter';
+export * from './VideoFilterCrop';
 export * from './VideoFilterDeinterlace';
 export * from './VideoFilterDrawText';
+export * from './VideoFilterOneOf';
+export * from './VideoFilterOneOf1';
+export * from './VideoFilterOneOf2';
+export * from './VideoFilterOneOf3';
+export * from './VideoFilterOverlay';
 export * from './VideoRepresentation';